@@ -1,39 +1,215 @@
-use soroban_sdk::{vec, Address, Env, IntoVal, String, Val, Vec};
+use soroban_sdk::{vec, Address, BytesN, Env, IntoVal, String, Val, Vec};
+
+use crate::types::NftRecipients;
 
 pub enum EscrowEvent {
-    Initialized(Address, Address, Address),
+    Initialized(Address, Address, Address, Address, u32, Address, u32),
+    Upgraded(BytesN<32>),
+    Migrated(u32, u32),
+    AdminTransferStarted(Address, Address),
+    AdminTransferred(Address, Address),
+    Paused(Address),
+    Unpaused(Address),
+    OracleUpdated(Address),
+    NFTNotesUpdated(Address),
+    FeeUpdated(u32),
+    TreasuryUpdated(Address),
+    AssetAllowed(Address),
+    AssetDisallowed(Address),
     NewProposal(String, Address),
+    ProposalCanceled(String, Address),
+    ProposalExpired(String, Address, u32),
+    ProposalUpdated(String, u32, i128),
     RegisterEscrow(String, String, u32, Address, i128),
-    SignedCompleted(String, String, Address, Address, i128, u32),
+    SignedCompleted(String, String, Address, Address, i128, i128, i128, Vec<u32>),
     SignedFailed(String, String, Address),
+    CancelRequested(String, String, u32, Address),
+    CancelRejected(String, String, u32, Address),
+    FundsReclaimed(String, String, Address, i128),
+    ContributionAdded(String, Address, i128, i128),
+    ContributionRefunded(String, Address, i128),
+    ContributorNoteMinted(String, Address, u32),
+    PooledCompleted(String, String, Address, i128, i128, i128, Vec<u32>),
+    MilestonesSet(String, u32, i128),
+    MilestonesFunded(String, Address, i128),
+    MilestoneReleased(String, u32, String, i128, i128, i128, Vec<u32>),
+    MilestonesRefunded(String, u32, Address, i128),
+    DisputeWindowUpdated(u32),
+    ArbitratorUpdated(Address),
+    RelayerUpdated(Address),
+    RelayerRemoved(Address),
+    ReleasePending(String, String, u32),
+    DisputeOpened(String, String, Address),
+    DisputeResolved(String, String, Address, i128, i128),
+    AuctionOpened(String, i128, u32),
+    BidPlaced(String, Address, i128),
+    BidRefunded(String, Address, i128),
+    AuctionClosed(String, Address, i128),
+    AuctionOffered(String, Address, i128),
+    BeneficiariesSet(String, u32, Address),
+    BeneficiaryPaid(String, String, Address, i128),
+    VestingSet(String, u32, u32, u32),
+    VestingStarted(String, String, i128, u32),
+    VestedClaimed(String, Address, i128, i128),
+    BondPosted(String, Address, i128),
+    BondReturned(String, Address, i128),
+    BondForfeited(String, Address, i128),
+    DeliveryDeposited(String, Address, i128),
+    AssetDelivered(String, Address, i128),
+    DeliveryReturned(String, Address, i128),
+    NftRecipientsUpdated(String, NftRecipients),
+    SurplusSwept(Address, Address, i128),
 }
 
 impl EscrowEvent {
     pub fn name(&self) -> &'static str {
         match self {
             EscrowEvent::Initialized(..) => stringify!(Initialized),
+            EscrowEvent::Upgraded(..) => stringify!(Upgraded),
+            EscrowEvent::Migrated(..) => stringify!(Migrated),
+            EscrowEvent::AdminTransferStarted(..) => stringify!(AdminTransferStarted),
+            EscrowEvent::AdminTransferred(..) => stringify!(AdminTransferred),
+            EscrowEvent::Paused(..) => stringify!(Paused),
+            EscrowEvent::Unpaused(..) => stringify!(Unpaused),
+            EscrowEvent::OracleUpdated(..) => stringify!(OracleUpdated),
+            EscrowEvent::NFTNotesUpdated(..) => stringify!(NFTNotesUpdated),
+            EscrowEvent::FeeUpdated(..) => stringify!(FeeUpdated),
+            EscrowEvent::TreasuryUpdated(..) => stringify!(TreasuryUpdated),
+            EscrowEvent::AssetAllowed(..) => stringify!(AssetAllowed),
+            EscrowEvent::AssetDisallowed(..) => stringify!(AssetDisallowed),
             EscrowEvent::NewProposal(..) => stringify!(NewProposal),
+            EscrowEvent::ProposalCanceled(..) => stringify!(ProposalCanceled),
+            EscrowEvent::ProposalExpired(..) => stringify!(ProposalExpired),
+            EscrowEvent::ProposalUpdated(..) => stringify!(ProposalUpdated),
             EscrowEvent::RegisterEscrow(..) => stringify!(RegisterEscrow),
             EscrowEvent::SignedCompleted(..) => stringify!(SignedCompleted),
             EscrowEvent::SignedFailed(..) => stringify!(SignedFailed),
+            EscrowEvent::CancelRequested(..) => stringify!(CancelRequested),
+            EscrowEvent::CancelRejected(..) => stringify!(CancelRejected),
+            EscrowEvent::FundsReclaimed(..) => stringify!(FundsReclaimed),
+            EscrowEvent::ContributionAdded(..) => stringify!(ContributionAdded),
+            EscrowEvent::ContributionRefunded(..) => stringify!(ContributionRefunded),
+            EscrowEvent::ContributorNoteMinted(..) => stringify!(ContributorNoteMinted),
+            EscrowEvent::PooledCompleted(..) => stringify!(PooledCompleted),
+            EscrowEvent::MilestonesSet(..) => stringify!(MilestonesSet),
+            EscrowEvent::MilestonesFunded(..) => stringify!(MilestonesFunded),
+            EscrowEvent::MilestoneReleased(..) => stringify!(MilestoneReleased),
+            EscrowEvent::MilestonesRefunded(..) => stringify!(MilestonesRefunded),
+            EscrowEvent::DisputeWindowUpdated(..) => stringify!(DisputeWindowUpdated),
+            EscrowEvent::ArbitratorUpdated(..) => stringify!(ArbitratorUpdated),
+            EscrowEvent::RelayerUpdated(..) => stringify!(RelayerUpdated),
+            EscrowEvent::RelayerRemoved(..) => stringify!(RelayerRemoved),
+            EscrowEvent::ReleasePending(..) => stringify!(ReleasePending),
+            EscrowEvent::DisputeOpened(..) => stringify!(DisputeOpened),
+            EscrowEvent::DisputeResolved(..) => stringify!(DisputeResolved),
+            EscrowEvent::AuctionOpened(..) => stringify!(AuctionOpened),
+            EscrowEvent::BidPlaced(..) => stringify!(BidPlaced),
+            EscrowEvent::BidRefunded(..) => stringify!(BidRefunded),
+            EscrowEvent::AuctionClosed(..) => stringify!(AuctionClosed),
+            EscrowEvent::AuctionOffered(..) => stringify!(AuctionOffered),
+            EscrowEvent::BeneficiariesSet(..) => stringify!(BeneficiariesSet),
+            EscrowEvent::BeneficiaryPaid(..) => stringify!(BeneficiaryPaid),
+            EscrowEvent::VestingSet(..) => stringify!(VestingSet),
+            EscrowEvent::VestingStarted(..) => stringify!(VestingStarted),
+            EscrowEvent::VestedClaimed(..) => stringify!(VestedClaimed),
+            EscrowEvent::BondPosted(..) => stringify!(BondPosted),
+            EscrowEvent::BondReturned(..) => stringify!(BondReturned),
+            EscrowEvent::BondForfeited(..) => stringify!(BondForfeited),
+            EscrowEvent::DeliveryDeposited(..) => stringify!(DeliveryDeposited),
+            EscrowEvent::AssetDelivered(..) => stringify!(AssetDelivered),
+            EscrowEvent::DeliveryReturned(..) => stringify!(DeliveryReturned),
+            EscrowEvent::NftRecipientsUpdated(..) => stringify!(NftRecipientsUpdated),
+            EscrowEvent::SurplusSwept(..) => stringify!(SurplusSwept),
         }
     }
     pub fn publish(&self, env: &Env) {
         match self {
-            EscrowEvent::Initialized(asset_address, oracle_address, nft_notes_address) => {
+            EscrowEvent::Initialized(
+                admin,
+                asset_address,
+                oracle_address,
+                nft_notes_address,
+                signature_timeout,
+                treasury,
+                fee_bps,
+            ) => {
                 let values: Vec<Val> = vec![
                     env,
+                    admin.into_val(env),
                     asset_address.into_val(env),
                     oracle_address.into_val(env),
                     nft_notes_address.into_val(env),
+                    (*signature_timeout).into_val(env),
+                    treasury.into_val(env),
+                    (*fee_bps).into_val(env),
+                ];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::Upgraded(new_wasm_hash) => {
+                let values: Vec<Val> = vec![env, new_wasm_hash.into_val(env)];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::Migrated(from_version, to_version) => {
+                let values: Vec<Val> = vec![
+                    env,
+                    (*from_version).into_val(env),
+                    (*to_version).into_val(env),
                 ];
                 self.internal_publish(env, values);
             }
+            EscrowEvent::AdminTransferStarted(admin, new_admin)
+            | EscrowEvent::AdminTransferred(admin, new_admin) => {
+                let values: Vec<Val> = vec![env, admin.into_val(env), new_admin.into_val(env)];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::Paused(admin) | EscrowEvent::Unpaused(admin) => {
+                let values: Vec<Val> = vec![env, admin.into_val(env)];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::OracleUpdated(address) | EscrowEvent::NFTNotesUpdated(address) => {
+                let values: Vec<Val> = vec![env, address.into_val(env)];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::FeeUpdated(fee_bps) => {
+                let values: Vec<Val> = vec![env, (*fee_bps).into_val(env)];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::TreasuryUpdated(treasury) => {
+                let values: Vec<Val> = vec![env, treasury.into_val(env)];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::AssetAllowed(asset_address)
+            | EscrowEvent::AssetDisallowed(asset_address) => {
+                let values: Vec<Val> = vec![env, asset_address.into_val(env)];
+                self.internal_publish(env, values);
+            }
             EscrowEvent::NewProposal(escrow_id, proposer) => {
                 let values: Vec<Val> =
                     vec![env, (*escrow_id).into_val(env), proposer.into_val(env)];
                 self.internal_publish(env, values);
             }
+            EscrowEvent::ProposalCanceled(escrow_id, owner) => {
+                let values: Vec<Val> = vec![env, escrow_id.into_val(env), owner.into_val(env)];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::ProposalExpired(escrow_id, owner, expires_at_ledger) => {
+                let values: Vec<Val> = vec![
+                    env,
+                    escrow_id.into_val(env),
+                    owner.into_val(env),
+                    (*expires_at_ledger).into_val(env),
+                ];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::ProposalUpdated(escrow_id, version, min_funds) => {
+                let values: Vec<Val> = vec![
+                    env,
+                    escrow_id.into_val(env),
+                    (*version).into_val(env),
+                    (*min_funds).into_val(env),
+                ];
+                self.internal_publish(env, values);
+            }
             EscrowEvent::RegisterEscrow(signaturit_id, propose_id, oracle_id, buyer, funds) => {
                 let values: Vec<Val> = vec![
                     env,
@@ -51,7 +227,9 @@ impl EscrowEvent {
                 buyer,
                 receiver,
                 funds,
-                nft_id,
+                fee,
+                net_amount,
+                nft_ids,
             ) => {
                 let values: Vec<Val> = vec![
                     env,
@@ -60,7 +238,9 @@ impl EscrowEvent {
                     buyer.into_val(env),
                     receiver.into_val(env),
                     funds.into_val(env),
-                    nft_id.into_val(env),
+                    fee.into_val(env),
+                    net_amount.into_val(env),
+                    nft_ids.into_val(env),
                 ];
                 self.internal_publish(env, values);
             }
@@ -73,6 +253,285 @@ impl EscrowEvent {
                 ];
                 self.internal_publish(env, values);
             }
+            EscrowEvent::CancelRequested(signaturit_id, propose_id, oracle_id, buyer) => {
+                let values: Vec<Val> = vec![
+                    env,
+                    signaturit_id.into_val(env),
+                    propose_id.into_val(env),
+                    (*oracle_id).into_val(env),
+                    buyer.into_val(env),
+                ];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::CancelRejected(signaturit_id, propose_id, oracle_id, buyer) => {
+                let values: Vec<Val> = vec![
+                    env,
+                    signaturit_id.into_val(env),
+                    propose_id.into_val(env),
+                    (*oracle_id).into_val(env),
+                    buyer.into_val(env),
+                ];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::FundsReclaimed(signaturit_id, propose_id, buyer, funds) => {
+                let values: Vec<Val> = vec![
+                    env,
+                    signaturit_id.into_val(env),
+                    propose_id.into_val(env),
+                    buyer.into_val(env),
+                    funds.into_val(env),
+                ];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::ContributionAdded(propose_id, contributor, amount, total_funds) => {
+                let values: Vec<Val> = vec![
+                    env,
+                    propose_id.into_val(env),
+                    contributor.into_val(env),
+                    amount.into_val(env),
+                    total_funds.into_val(env),
+                ];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::ContributionRefunded(propose_id, contributor, amount) => {
+                let values: Vec<Val> = vec![
+                    env,
+                    propose_id.into_val(env),
+                    contributor.into_val(env),
+                    amount.into_val(env),
+                ];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::ContributorNoteMinted(propose_id, contributor, nft_id) => {
+                let values: Vec<Val> = vec![
+                    env,
+                    propose_id.into_val(env),
+                    contributor.into_val(env),
+                    (*nft_id).into_val(env),
+                ];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::PooledCompleted(
+                signaturit_id,
+                propose_id,
+                receiver,
+                funds,
+                fee,
+                net_amount,
+                nft_ids,
+            ) => {
+                let values: Vec<Val> = vec![
+                    env,
+                    signaturit_id.into_val(env),
+                    propose_id.into_val(env),
+                    receiver.into_val(env),
+                    funds.into_val(env),
+                    fee.into_val(env),
+                    net_amount.into_val(env),
+                    nft_ids.into_val(env),
+                ];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::MilestonesSet(propose_id, milestones, total_funds) => {
+                let values: Vec<Val> = vec![
+                    env,
+                    propose_id.into_val(env),
+                    (*milestones).into_val(env),
+                    total_funds.into_val(env),
+                ];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::MilestonesFunded(propose_id, buyer, funds) => {
+                let values: Vec<Val> = vec![
+                    env,
+                    propose_id.into_val(env),
+                    buyer.into_val(env),
+                    funds.into_val(env),
+                ];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::MilestoneReleased(
+                propose_id,
+                index,
+                signaturit_id,
+                amount,
+                fee,
+                net_amount,
+                nft_ids,
+            ) => {
+                let values: Vec<Val> = vec![
+                    env,
+                    propose_id.into_val(env),
+                    (*index).into_val(env),
+                    signaturit_id.into_val(env),
+                    amount.into_val(env),
+                    fee.into_val(env),
+                    net_amount.into_val(env),
+                    nft_ids.into_val(env),
+                ];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::MilestonesRefunded(propose_id, index, buyer, amount) => {
+                let values: Vec<Val> = vec![
+                    env,
+                    propose_id.into_val(env),
+                    (*index).into_val(env),
+                    buyer.into_val(env),
+                    amount.into_val(env),
+                ];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::DisputeWindowUpdated(dispute_window) => {
+                let values: Vec<Val> = vec![env, (*dispute_window).into_val(env)];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::ArbitratorUpdated(address)
+            | EscrowEvent::RelayerUpdated(address)
+            | EscrowEvent::RelayerRemoved(address) => {
+                let values: Vec<Val> = vec![env, address.into_val(env)];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::ReleasePending(signaturit_id, propose_id, release_ledger) => {
+                let values: Vec<Val> = vec![
+                    env,
+                    signaturit_id.into_val(env),
+                    propose_id.into_val(env),
+                    (*release_ledger).into_val(env),
+                ];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::DisputeOpened(signaturit_id, propose_id, disputed_by) => {
+                let values: Vec<Val> = vec![
+                    env,
+                    signaturit_id.into_val(env),
+                    propose_id.into_val(env),
+                    disputed_by.into_val(env),
+                ];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::DisputeResolved(
+                signaturit_id,
+                propose_id,
+                arbitrator,
+                receiver_share,
+                buyer_share,
+            ) => {
+                let values: Vec<Val> = vec![
+                    env,
+                    signaturit_id.into_val(env),
+                    propose_id.into_val(env),
+                    arbitrator.into_val(env),
+                    receiver_share.into_val(env),
+                    buyer_share.into_val(env),
+                ];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::AuctionOpened(propose_id, reserve_price, end_ledger) => {
+                let values: Vec<Val> = vec![
+                    env,
+                    propose_id.into_val(env),
+                    reserve_price.into_val(env),
+                    (*end_ledger).into_val(env),
+                ];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::BidPlaced(propose_id, bidder, amount)
+            | EscrowEvent::BidRefunded(propose_id, bidder, amount)
+            | EscrowEvent::AuctionClosed(propose_id, bidder, amount)
+            | EscrowEvent::AuctionOffered(propose_id, bidder, amount) => {
+                let values: Vec<Val> = vec![
+                    env,
+                    propose_id.into_val(env),
+                    bidder.into_val(env),
+                    amount.into_val(env),
+                ];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::BeneficiariesSet(propose_id, count, dust_beneficiary) => {
+                let values: Vec<Val> = vec![
+                    env,
+                    propose_id.into_val(env),
+                    (*count).into_val(env),
+                    dust_beneficiary.into_val(env),
+                ];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::BeneficiaryPaid(signaturit_id, propose_id, beneficiary, amount) => {
+                let values: Vec<Val> = vec![
+                    env,
+                    signaturit_id.into_val(env),
+                    propose_id.into_val(env),
+                    beneficiary.into_val(env),
+                    amount.into_val(env),
+                ];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::VestingSet(propose_id, cliff, duration, interval) => {
+                let values: Vec<Val> = vec![
+                    env,
+                    propose_id.into_val(env),
+                    (*cliff).into_val(env),
+                    (*duration).into_val(env),
+                    (*interval).into_val(env),
+                ];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::VestingStarted(signaturit_id, propose_id, total, start_ledger) => {
+                let values: Vec<Val> = vec![
+                    env,
+                    signaturit_id.into_val(env),
+                    propose_id.into_val(env),
+                    total.into_val(env),
+                    (*start_ledger).into_val(env),
+                ];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::VestedClaimed(signaturit_id, receiver, amount, claimed) => {
+                let values: Vec<Val> = vec![
+                    env,
+                    signaturit_id.into_val(env),
+                    receiver.into_val(env),
+                    amount.into_val(env),
+                    claimed.into_val(env),
+                ];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::BondPosted(propose_id, address, bond)
+            | EscrowEvent::BondReturned(propose_id, address, bond)
+            | EscrowEvent::BondForfeited(propose_id, address, bond) => {
+                let values: Vec<Val> = vec![
+                    env,
+                    propose_id.into_val(env),
+                    address.into_val(env),
+                    bond.into_val(env),
+                ];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::NftRecipientsUpdated(propose_id, recipients) => {
+                let values: Vec<Val> =
+                    vec![env, propose_id.into_val(env), recipients.into_val(env)];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::DeliveryDeposited(propose_id, address, amount)
+            | EscrowEvent::AssetDelivered(propose_id, address, amount)
+            | EscrowEvent::DeliveryReturned(propose_id, address, amount) => {
+                let values: Vec<Val> = vec![
+                    env,
+                    propose_id.into_val(env),
+                    address.into_val(env),
+                    amount.into_val(env),
+                ];
+                self.internal_publish(env, values);
+            }
+            EscrowEvent::SurplusSwept(asset_address, to, amount) => {
+                let values: Vec<Val> = vec![
+                    env,
+                    asset_address.into_val(env),
+                    to.into_val(env),
+                    amount.into_val(env),
+                ];
+                self.internal_publish(env, values);
+            }
         }
     }
 
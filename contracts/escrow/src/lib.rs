@@ -1,5 +1,6 @@
 #![no_std]
 mod events;
+mod migration;
 mod types;
 pub mod oracle {
     soroban_sdk::contractimport!(
@@ -16,39 +17,179 @@ pub mod notes_nft {
 }
 
 use events::EscrowEvent;
-use oracle_traits::OracleConsumer;
+use oracle_traits::{FailureReason, OracleConsumer};
 use soroban_sdk::{
     auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
-    contract, contractimpl, panic_with_error, token, vec, Address, Env, IntoVal, String, Symbol,
+    contract, contractimpl, panic_with_error, token, vec, Address, BytesN, Env, IntoVal, String,
+    Symbol, Vec,
 };
 use storage::Storage;
 use types::{
-    DataKey, EscrowError, EscrowProposal, NullableString, ProposalStatus, SignatureStatus,
-    SignatureTxEscrow,
+    Auction, AuctionStatus, Beneficiary, BeneficiarySplit, Bid, Checkpoint, Contribution, DataKey,
+    Delivery, DeliveryStatus, EscrowError, EscrowProposal, Lifecycle, Listing, Milestone,
+    MilestonePlan, MilestoneStatus, NftRecipients, NullableAddress, NullableCheckpoint,
+    NullableDelivery, NullableString, PendingRelease, ProposalMode, ProposalStatus, ProposalTerms,
+    Reconciliation, SignatureStatus, SignatureTxEscrow, VestingPosition, VestingSchedule,
 };
 
+// The fee is expressed in basis points, so 10_000 is the 100%
+const MAX_FEE_BPS: u32 = 10_000;
+
+// The platform fee can not take more than the 10% of the funds
+const MAX_PLATFORM_FEE_BPS: u32 = 1_000;
+
+const SCHEMA_VERSION: u32 = 1;
+
+// Maximum amount of records returned by a page of the listings
+const MAX_PAGE_SIZE: u32 = 50;
+
 fn check_initialization(env: &Env) {
-    if !DataKey::AssetAddress.has(env) || !DataKey::OracleAddress.has(env) {
+    if !DataKey::Admin.has(env) || !DataKey::OracleAddress.has(env) {
         panic_with_error!(env, EscrowError::NotInit);
     }
 }
 
+fn get_admin(env: &Env) -> Address {
+    DataKey::Admin.get(env).unwrap()
+}
+
+fn get_schema_version(env: &Env) -> u32 {
+    storage::schema_version(env, &DataKey::SchemaVersion)
+}
+
+fn is_paused(env: &Env) -> bool {
+    DataKey::Paused.get(env).unwrap_or(false)
+}
+
+fn check_not_paused(env: &Env) {
+    if is_paused(env) {
+        panic_with_error!(env, EscrowError::ContractPaused);
+    }
+}
+
 fn get_oracle(env: &Env) -> Address {
     DataKey::OracleAddress.get(env).unwrap()
 }
 
-fn get_asset(env: &Env) -> Address {
-    DataKey::AssetAddress.get(env).unwrap()
+fn is_asset_allowed(env: &Env, asset_address: &Address) -> bool {
+    DataKey::AllowedAsset(asset_address.clone())
+        .get(env)
+        .unwrap_or(false)
 }
 
 fn get_nft(env: &Env) -> Address {
     DataKey::NFTNotesAddress.get(env).unwrap()
 }
 
-fn transfer_funds(env: &Env, from: &Address, to: &Address, amount: &i128) {
-    let asset_address = get_asset(env);
-    let client = token::Client::new(env, &asset_address);
-    client.transfer(from, to, amount);
+fn get_signature_timeout(env: &Env) -> u32 {
+    DataKey::SignatureTimeout.get(env).unwrap()
+}
+
+fn get_treasury(env: &Env) -> Address {
+    DataKey::Treasury.get(env).unwrap()
+}
+
+fn get_fee(env: &Env) -> u32 {
+    DataKey::FeeBps.get(env).unwrap_or(0)
+}
+
+fn get_dispute_window(env: &Env) -> u32 {
+    // Without a window the funds are released with the signature
+    DataKey::DisputeWindow.get(env).unwrap_or(0)
+}
+
+fn get_arbitrator(env: &Env) -> Option<Address> {
+    DataKey::Arbitrator.get(env)
+}
+
+fn get_relayer(env: &Env) -> Option<Address> {
+    DataKey::Relayer.get(env)
+}
+
+fn check_fee(env: &Env, fee_bps: u32) {
+    if fee_bps > MAX_PLATFORM_FEE_BPS {
+        panic_with_error!(env, EscrowError::InvalidFee);
+    }
+}
+
+/**
+ * Move `amount` of an asset from `from` to the escrow, which owes it from then
+ * on (it is added to the liability of the asset).
+ */
+fn transfer_in(env: &Env, asset_address: &Address, from: &Address, amount: &i128) {
+    let client = token::Client::new(env, asset_address);
+    client.transfer(from, &env.current_contract_address(), amount);
+
+    add_liability(env, asset_address, *amount);
+}
+
+/**
+ * Move `amount` of an asset owed by the escrow to `to`.
+ */
+fn transfer_out(env: &Env, asset_address: &Address, to: &Address, amount: &i128) {
+    let client = token::Client::new(env, asset_address);
+    client.transfer(&env.current_contract_address(), to, amount);
+
+    add_liability(env, asset_address, -*amount);
+}
+
+fn get_liability(env: &Env, asset_address: &Address) -> i128 {
+    DataKey::Liability(asset_address.clone())
+        .get(env)
+        .unwrap_or(0)
+}
+
+fn add_liability(env: &Env, asset_address: &Address, amount: i128) {
+    let liability = get_liability(env, asset_address) + amount;
+    DataKey::Liability(asset_address.clone()).set(env, &liability);
+}
+
+fn get_reconciliation(env: &Env, asset_address: &Address) -> Reconciliation {
+    let client = token::Client::new(env, asset_address);
+
+    let mut liability = get_liability(env, asset_address);
+    let balance = client.balance(&env.current_contract_address());
+
+    // Until the migration is finished the liability may not be tracked yet, so
+    // the whole balance is taken as owed and nothing can be swept
+    if get_schema_version(env) < SCHEMA_VERSION {
+        liability = liability.max(balance);
+    }
+
+    Reconciliation {
+        liability,
+        balance,
+        surplus: balance - liability,
+    }
+}
+
+/**
+ * Pull `amount` of the asset that `from` approved to the escrow. The balance of
+ * the escrow is checked afterwards, so a token that charges a fee on the
+ * transfers can not fund less than the amount.
+ */
+fn transfer_funds_from_allowance(
+    env: &Env,
+    asset_address: &Address,
+    from: &Address,
+    amount: &i128,
+) {
+    let client = token::Client::new(env, asset_address);
+    let escrow_address = env.current_contract_address();
+
+    if client.allowance(from, &escrow_address) < *amount {
+        panic_with_error!(env, EscrowError::NoEnoughtFunds);
+    }
+
+    let balance_before = client.balance(&escrow_address);
+
+    client.transfer_from(&escrow_address, from, &escrow_address, amount);
+
+    if client.balance(&escrow_address) - balance_before < *amount {
+        panic_with_error!(env, EscrowError::NoEnoughtFunds);
+    }
+
+    add_liability(env, asset_address, *amount);
 }
 
 fn get_proposal(env: &Env, escrow_id: String) -> EscrowProposal {
@@ -59,117 +200,2718 @@ fn get_proposal(env: &Env, escrow_id: String) -> EscrowProposal {
     DataKey::Proposal(escrow_id).get(env).unwrap()
 }
 
-fn get_signature_tx_escrow(env: &Env, signaturit_id: String) -> SignatureTxEscrow {
-    if !DataKey::SignatureProcess(signaturit_id.clone()).has(&env) {
-        panic_with_error!(&env, EscrowError::SignatureProcessNotFound);
-    }
+fn is_expired(env: &Env, propose: &EscrowProposal) -> bool {
+    match propose.expires_at_ledger {
+        Some(expires_at_ledger) => env.ledger().sequence() > expires_at_ledger,
+        None => false,
+    }
+}
+
+fn check_not_expired(env: &Env, propose: &EscrowProposal) {
+    if is_expired(env, propose) {
+        panic_with_error!(env, EscrowError::ProposalExpired);
+    }
+}
+
+fn get_signature_tx_escrow(env: &Env, signaturit_id: String) -> SignatureTxEscrow {
+    if !DataKey::SignatureProcess(signaturit_id.clone()).has(&env) {
+        panic_with_error!(&env, EscrowError::SignatureProcessNotFound);
+    }
+
+    DataKey::SignatureProcess(signaturit_id).get(&env).unwrap()
+}
+
+fn get_pending_release(env: &Env, signaturit_id: String) -> PendingRelease {
+    if !DataKey::PendingRelease(signaturit_id.clone()).has(env) {
+        panic_with_error!(env, EscrowError::NotPendingRelease);
+    }
+
+    DataKey::PendingRelease(signaturit_id).get(env).unwrap()
+}
+
+/**
+ * Add the ID at the end of the listing. An ID is listed once, so adding it
+ * again does nothing.
+ */
+fn add_to_index(env: &Env, listing: &Listing, id: &String) {
+    let position_key = DataKey::ListingPosition(listing.clone(), id.clone());
+
+    if position_key.has(env) {
+        return;
+    }
+
+    let size: u32 = DataKey::ListingSize(listing.clone()).get(env).unwrap_or(0);
+
+    DataKey::ListingEntry(listing.clone(), size).set(env, id);
+    position_key.set(env, &size);
+    DataKey::ListingSize(listing.clone()).set(env, &(size + 1));
+}
+
+/**
+ * Remove the ID from the listing. Its position is left empty, so the position
+ * of the other IDs (the cursors of the pages) does not change.
+ */
+fn remove_from_index(env: &Env, listing: &Listing, id: &String) {
+    let position_key = DataKey::ListingPosition(listing.clone(), id.clone());
+    let position: Option<u32> = position_key.get(env);
+
+    if let Some(position) = position {
+        DataKey::ListingEntry(listing.clone(), position).remove(env);
+        position_key.remove(env);
+    }
+}
+
+/**
+ * The IDs of a listing on the positions from `cursor`, at most `limit` of
+ * them (and never more than `MAX_PAGE_SIZE`). The empty positions are
+ * skipped, so a page can hold less IDs than `limit` before the end.
+ */
+fn get_index_page(env: &Env, listing: &Listing, cursor: u32, limit: u32) -> Vec<String> {
+    let size: u32 = DataKey::ListingSize(listing.clone()).get(env).unwrap_or(0);
+
+    let start = cursor.min(size);
+    let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(size);
+
+    let mut ids = Vec::new(env);
+    for position in start..end {
+        let id: Option<String> = DataKey::ListingEntry(listing.clone(), position).get(env);
+
+        if let Some(id) = id {
+            ids.push_back(id);
+        }
+    }
+
+    ids
+}
+
+/**
+ * Change the status of a proposal keeping the status index in sync. All the
+ * status changes of the proposals go through it.
+ */
+fn set_proposal_status(env: &Env, propose: &mut EscrowProposal, status: ProposalStatus) {
+    if propose.status == status {
+        return;
+    }
+
+    remove_from_index(
+        env,
+        &Listing::ProposalsByStatus(propose.status),
+        &propose.escrow_id,
+    );
+    add_to_index(env, &Listing::ProposalsByStatus(status), &propose.escrow_id);
+
+    propose.status = status;
+
+    match status {
+        ProposalStatus::Picked => record_checkpoint(env, &propose.escrow_id, |lifecycle, at| {
+            lifecycle.picked = NullableCheckpoint::Some(at)
+        }),
+        ProposalStatus::Completed => record_checkpoint(env, &propose.escrow_id, |lifecycle, at| {
+            lifecycle.completed = NullableCheckpoint::Some(at)
+        }),
+        _ => {}
+    }
+
+    // The bond is returned once the proposal is closed
+    if status == ProposalStatus::Completed || status == ProposalStatus::Canceled {
+        return_bond(env, propose);
+    }
+
+    // The asset not delivered is returned once the proposal is canceled
+    if status == ProposalStatus::Canceled {
+        return_delivery(env, propose);
+    }
+}
+
+fn get_lifecycle(env: &Env, escrow_id: String) -> Lifecycle {
+    DataKey::Lifecycle(escrow_id).get(env).unwrap_or(Lifecycle {
+        created: NullableCheckpoint::None,
+        picked: NullableCheckpoint::None,
+        completed: NullableCheckpoint::None,
+        failed: NullableCheckpoint::None,
+    })
+}
+
+fn current_checkpoint(env: &Env) -> Checkpoint {
+    Checkpoint {
+        ledger: env.ledger().sequence(),
+        timestamp: env.ledger().timestamp(),
+    }
+}
+
+/**
+ * Record the current ledger and timestamp on one of the checkpoints of the
+ * lifecycle of a proposal.
+ */
+fn record_checkpoint(
+    env: &Env,
+    escrow_id: &String,
+    update: impl FnOnce(&mut Lifecycle, Checkpoint),
+) {
+    let mut lifecycle = get_lifecycle(env, escrow_id.clone());
+
+    update(&mut lifecycle, current_checkpoint(env));
+
+    DataKey::Lifecycle(escrow_id.clone()).set(env, &lifecycle);
+}
+
+fn get_delivery(env: &Env, escrow_id: String) -> Option<Delivery> {
+    DataKey::Delivery(escrow_id).get(env)
+}
+
+/**
+ * Send the asset sold by a proposal to the buyer, if it has one
+ */
+fn deliver_asset(env: &Env, signature_process: &SignatureTxEscrow, propose: &EscrowProposal) {
+    let mut delivery = match get_delivery(env, propose.escrow_id.clone()) {
+        Some(delivery) if delivery.status == DeliveryStatus::Held => delivery,
+        _ => return,
+    };
+
+    transfer_out(
+        env,
+        &delivery.asset,
+        &signature_process.buyer,
+        &delivery.amount,
+    );
+
+    delivery.status = DeliveryStatus::Delivered;
+    DataKey::Delivery(propose.escrow_id.clone()).set(env, &delivery);
+
+    // Emit the AssetDelivered event
+    EscrowEvent::AssetDelivered(
+        propose.escrow_id.clone(),
+        signature_process.buyer.clone(),
+        delivery.amount,
+    )
+    .publish(env);
+}
+
+fn return_delivery(env: &Env, propose: &EscrowProposal) {
+    let mut delivery = match get_delivery(env, propose.escrow_id.clone()) {
+        Some(delivery) if delivery.status == DeliveryStatus::Held => delivery,
+        _ => return,
+    };
+
+    transfer_out(env, &delivery.asset, &propose.owner, &delivery.amount);
+
+    delivery.status = DeliveryStatus::Returned;
+    DataKey::Delivery(propose.escrow_id.clone()).set(env, &delivery);
+
+    // Emit the DeliveryReturned event
+    EscrowEvent::DeliveryReturned(
+        propose.escrow_id.clone(),
+        propose.owner.clone(),
+        delivery.amount,
+    )
+    .publish(env);
+}
+
+fn return_bond(env: &Env, propose: &mut EscrowProposal) {
+    if propose.bond == 0 {
+        return;
+    }
+
+    transfer_out(env, &propose.asset, &propose.owner, &propose.bond);
+
+    // Emit the BondReturned event
+    EscrowEvent::BondReturned(
+        propose.escrow_id.clone(),
+        propose.owner.clone(),
+        propose.bond,
+    )
+    .publish(env);
+
+    propose.bond = 0;
+}
+
+/**
+ * Increase the version of the terms of a proposal, so the buyers that agreed
+ * to the previous ones are rejected. Every change of the terms goes through it.
+ */
+fn bump_version(env: &Env, propose: &mut EscrowProposal) {
+    propose.version += 1;
+    DataKey::Proposal(propose.escrow_id.clone()).set(env, propose);
+
+    // Emit the ProposalUpdated event
+    EscrowEvent::ProposalUpdated(
+        propose.escrow_id.clone(),
+        propose.version,
+        propose.min_funds,
+    )
+    .publish(env);
+}
+
+/**
+ * Post or return the difference between the bond held and the one due for
+ * the current `min_funds` of the proposal.
+ */
+fn adjust_bond(env: &Env, propose: &mut EscrowProposal) {
+    let bond = propose.min_funds * propose.bond_bps as i128 / MAX_FEE_BPS as i128;
+
+    if bond > propose.bond {
+        let amount = bond - propose.bond;
+        transfer_in(env, &propose.asset, &propose.owner, &amount);
+
+        // Emit the BondPosted event
+        EscrowEvent::BondPosted(propose.escrow_id.clone(), propose.owner.clone(), amount)
+            .publish(env);
+    } else if bond < propose.bond {
+        let amount = propose.bond - bond;
+        transfer_out(env, &propose.asset, &propose.owner, &amount);
+
+        // Emit the BondReturned event
+        EscrowEvent::BondReturned(propose.escrow_id.clone(), propose.owner.clone(), amount)
+            .publish(env);
+    }
+
+    propose.bond = bond;
+}
+
+/**
+ * Give the bond of the owner to the buyer (or to the contributors, pro rata)
+ * of a signature process that the owner declined.
+ */
+fn forfeit_bond(env: &Env, signature_process: &SignatureTxEscrow, propose: &mut EscrowProposal) {
+    if propose.bond == 0 {
+        return;
+    }
+
+    refund_buyer_share(env, signature_process, propose, propose.bond);
+
+    // Emit the BondForfeited event
+    EscrowEvent::BondForfeited(
+        propose.escrow_id.clone(),
+        signature_process.buyer.clone(),
+        propose.bond,
+    )
+    .publish(env);
+
+    propose.bond = 0;
+}
+
+fn get_contributions(env: &Env, escrow_id: String) -> Vec<Contribution> {
+    DataKey::Contributions(escrow_id)
+        .get(env)
+        .unwrap_or(Vec::new(env))
+}
+
+fn get_total_contributed(contributions: &Vec<Contribution>) -> i128 {
+    contributions
+        .iter()
+        .fold(0, |total, contribution| total + contribution.amount)
+}
+
+fn get_milestone_plan(env: &Env, escrow_id: String) -> MilestonePlan {
+    if !DataKey::Milestones(escrow_id.clone()).has(env) {
+        panic_with_error!(env, EscrowError::MilestonesNotSet);
+    }
+
+    DataKey::Milestones(escrow_id).get(env).unwrap()
+}
+
+fn get_auction(env: &Env, escrow_id: String) -> Auction {
+    if !DataKey::Auction(escrow_id.clone()).has(env) {
+        panic_with_error!(env, EscrowError::AuctionNotOpen);
+    }
+
+    DataKey::Auction(escrow_id).get(env).unwrap()
+}
+
+/**
+ * Return to the highest bidder of an open auction the funds held.
+ */
+fn refund_top_bid(env: &Env, propose: &EscrowProposal, auction: &Auction) {
+    if let Some(top_bid) = auction.bids.last() {
+        transfer_out(env, &propose.asset, &top_bid.bidder, &top_bid.amount);
+
+        // Emit the BidRefunded event
+        EscrowEvent::BidRefunded(propose.escrow_id.clone(), top_bid.bidder, top_bid.amount)
+            .publish(env);
+    }
+}
+
+/**
+ * Return the funds held for a proposal that is not picked yet: the
+ * contributions of a pooled proposal and the highest bid of an open auction.
+ * The auction is closed without bids, so it can not be closed nor offered
+ * anymore.
+ */
+fn refund_unpicked_funds(env: &Env, propose: &EscrowProposal) {
+    if propose.mode == ProposalMode::Pooled {
+        refund_contributions(env, propose);
+    }
+
+    if propose.mode == ProposalMode::Auction && DataKey::Auction(propose.escrow_id.clone()).has(env)
+    {
+        let mut auction = get_auction(env, propose.escrow_id.clone());
+
+        // The highest bid of an open auction is held by the escrow
+        if auction.status == AuctionStatus::Open {
+            refund_top_bid(env, propose, &auction);
+        }
+
+        auction.status = AuctionStatus::Closed;
+        auction.bids = Vec::new(env);
+        auction.offered_to = NullableAddress::None;
+        DataKey::Auction(propose.escrow_id.clone()).set(env, &auction);
+    }
+}
+
+fn get_milestones_total(milestones: &Vec<Milestone>) -> i128 {
+    milestones
+        .iter()
+        .fold(0, |total, milestone| total + milestone.amount)
+}
+
+/**
+ * Position of the milestone linked to the signature process.
+ */
+fn find_milestone(plan: &MilestonePlan, signaturit_id: &String) -> u32 {
+    plan.milestones
+        .iter()
+        .position(|milestone| {
+            milestone.signaturit_id == NullableString::Some(signaturit_id.clone())
+        })
+        .unwrap() as u32
+}
+
+/**
+ * Return to each contributor of a pooled proposal the funds that gave, and
+ * leave the proposal without contributions.
+ */
+fn refund_contributions(env: &Env, propose: &EscrowProposal) {
+    let contributions = get_contributions(env, propose.escrow_id.clone());
+
+    for contribution in contributions.iter() {
+        transfer_out(
+            env,
+            &propose.asset,
+            &contribution.contributor,
+            &contribution.amount,
+        );
+
+        // Emit the ContributionRefunded event
+        EscrowEvent::ContributionRefunded(
+            propose.escrow_id.clone(),
+            contribution.contributor,
+            contribution.amount,
+        )
+        .publish(env);
+    }
+
+    DataKey::Contributions(propose.escrow_id.clone()).remove(env);
+}
+
+/**
+ * The buyer is who can cancel a signature tx escrow. On pooled proposals the
+ * buyer is the escrow itself, so any contributor acts on behalf of the others.
+ */
+fn require_buyer_auth(
+    env: &Env,
+    signature_process: &SignatureTxEscrow,
+    propose: &EscrowProposal,
+    caller: &Address,
+) {
+    caller.require_auth();
+
+    let is_buyer = match propose.mode {
+        ProposalMode::Pooled => get_contributions(env, propose.escrow_id.clone())
+            .iter()
+            .any(|contribution| contribution.contributor == *caller),
+        _ => *caller == signature_process.buyer,
+    };
+
+    if !is_buyer {
+        panic_with_error!(env, EscrowError::NotAParty);
+    }
+}
+
+/**
+ * The parties of a signature tx escrow are the receiver and the buyer, or the
+ * contributors on pooled proposals.
+ */
+fn is_party(
+    env: &Env,
+    signature_process: &SignatureTxEscrow,
+    propose: &EscrowProposal,
+    caller: &Address,
+) -> bool {
+    if *caller == signature_process.receiver {
+        return true;
+    }
+
+    match propose.mode {
+        ProposalMode::Pooled => get_contributions(env, propose.escrow_id.clone())
+            .iter()
+            .any(|contribution| contribution.contributor == *caller),
+        _ => *caller == signature_process.buyer,
+    }
+}
+
+/**
+ * Register the signature process on the oracle for a proposal with the funds
+ * already held by the escrow, and link it to the proposal.
+ */
+fn register_signature_process(
+    env: &Env,
+    propose: &mut EscrowProposal,
+    signaturit_id: String,
+    buyer: Address,
+    funds: i128,
+) {
+    // Get the oracle client
+    let oracle_client = oracle::OracleClient::new(env, &get_oracle(env));
+
+    // Grant auth for calling the function
+    env.authorize_as_current_contract(vec![
+        env,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: get_oracle(env),
+                fn_name: Symbol::new(env, "register_new_signature_process"),
+                args: (env.current_contract_address(), signaturit_id.clone()).into_val(env),
+            },
+            sub_invocations: vec![env],
+        }),
+    ]);
+
+    // Get the oracle id for this process signature
+    let oracle_id = oracle_client
+        .register_new_signature_process(&env.current_contract_address(), &signaturit_id);
+
+    let timeout = propose
+        .signature_timeout
+        .unwrap_or_else(|| get_signature_timeout(env));
+
+    let tx_register = SignatureTxEscrow {
+        id: signaturit_id.clone(),
+        propose_id: propose.escrow_id.clone(),
+        oracle_id,
+        buyer,
+        receiver: propose.owner.clone(),
+        asset: propose.asset.clone(),
+        funds,
+        status: SignatureStatus::Progress,
+        nft_proof_ids: Vec::new(env),
+        cancel_oracle_id: None,
+        // A timeout chosen too large never expires instead of overflowing
+        deadline_ledger: env.ledger().sequence().saturating_add(timeout),
+        // The fee is locked in when the funds are deposited
+        fee_bps: get_fee(env),
+        registered: current_checkpoint(env),
+        settled: NullableCheckpoint::None,
+    };
+
+    add_to_index(
+        env,
+        &Listing::EscrowsByBuyer(tx_register.buyer.clone()),
+        &tx_register.id,
+    );
+    add_to_index(
+        env,
+        &Listing::EscrowsByProposal(propose.escrow_id.clone()),
+        &tx_register.id,
+    );
+
+    // The escrow holds the funds of a pooled proposal, so it is also listed
+    // under each contributor
+    if propose.mode == ProposalMode::Pooled {
+        for contribution in get_contributions(env, propose.escrow_id.clone()).iter() {
+            add_to_index(
+                env,
+                &Listing::EscrowsByBuyer(contribution.contributor),
+                &tx_register.id,
+            );
+        }
+    }
+
+    // This way, the propose can be picked just once per time
+    set_proposal_status(env, propose, ProposalStatus::Picked);
+    propose.signature_tx_linked = NullableString::Some(signaturit_id.clone());
+    DataKey::Proposal(propose.escrow_id.clone()).set(env, propose);
+    DataKey::SignatureProcess(signaturit_id.clone()).set(env, &tx_register);
+    DataKey::OracleIdToEscrow(get_oracle(env), oracle_id).set(env, &signaturit_id);
+
+    // Emit the RegisterEscrow event
+    EscrowEvent::RegisterEscrow(
+        tx_register.id,
+        tx_register.propose_id,
+        tx_register.oracle_id,
+        tx_register.buyer,
+        tx_register.funds,
+    )
+    .publish(env);
+}
+
+/**
+ * Mint a note NFT with the hash of the signed document, indexed to the
+ * signature tx escrow that it proves. Returns the ID of the NFT minted.
+ */
+fn mint_note(env: &Env, signaturit_id: &String, to: &Address, document_hash: &String) -> u32 {
+    // Get the NFT client
+    let nft_client = notes_nft::NotesNFTClient::new(env, &get_nft(env));
+
+    // Grant auth for calling the function
+    env.authorize_as_current_contract(vec![
+        env,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: get_nft(env),
+                fn_name: Symbol::new(env, "mint"),
+                args: (to.clone(), document_hash.clone()).into_val(env),
+            },
+            sub_invocations: vec![env],
+        }),
+    ]);
+
+    let token_id = nft_client.mint(to, document_hash);
+    DataKey::NftToEscrow(token_id).set(env, signaturit_id);
+
+    token_id
+}
+
+/**
+ * Mint a note NFT for each contributor of a pooled proposal. Returns the
+ * amount of notes minted.
+ */
+fn mint_contributor_notes(
+    env: &Env,
+    signature_process: &SignatureTxEscrow,
+    propose: &EscrowProposal,
+    document_hash: &String,
+) -> Vec<u32> {
+    let mut contributions = get_contributions(env, propose.escrow_id.clone());
+    let mut nft_ids = Vec::new(env);
+
+    for index in 0..contributions.len() {
+        let mut contribution = contributions.get(index).unwrap();
+
+        let token_id_minted = mint_note(
+            env,
+            &signature_process.id,
+            &contribution.contributor,
+            document_hash,
+        );
+
+        contribution.nft_id = Some(token_id_minted);
+        contributions.set(index, contribution.clone());
+        nft_ids.push_back(token_id_minted);
+
+        // Emit the ContributorNoteMinted event
+        EscrowEvent::ContributorNoteMinted(
+            propose.escrow_id.clone(),
+            contribution.contributor,
+            token_id_minted,
+        )
+        .publish(env);
+    }
+
+    DataKey::Contributions(propose.escrow_id.clone()).set(env, &contributions);
+
+    nft_ids
+}
+
+fn check_beneficiaries(env: &Env, beneficiaries: &Vec<Beneficiary>, dust_beneficiary: &Address) {
+    let mut total_bps: u32 = 0;
+    let mut has_dust_beneficiary = false;
+
+    for beneficiary in beneficiaries.iter() {
+        if beneficiary.bps == 0 || beneficiary.bps > MAX_FEE_BPS {
+            panic_with_error!(env, EscrowError::InvalidBeneficiaries);
+        }
+
+        total_bps += beneficiary.bps;
+        has_dust_beneficiary |= beneficiary.address == *dust_beneficiary;
+    }
+
+    if total_bps != MAX_FEE_BPS || !has_dust_beneficiary {
+        panic_with_error!(env, EscrowError::InvalidBeneficiaries);
+    }
+}
+
+/**
+ * Pay the released funds to the receiver or, if the proposal has
+ * beneficiaries, split them by their shares. The dust of the rounding goes to
+ * the designated beneficiary.
+ */
+fn pay_receiver(env: &Env, signature_process: &SignatureTxEscrow, amount: i128) {
+    let split: BeneficiarySplit =
+        match DataKey::Beneficiaries(signature_process.propose_id.clone()).get(env) {
+            Some(split) => split,
+            None => {
+                transfer_out(
+                    env,
+                    &signature_process.asset,
+                    &signature_process.receiver,
+                    &amount,
+                );
+                return;
+            }
+        };
+
+    let mut dust = amount;
+    for beneficiary in split.beneficiaries.iter() {
+        dust -= amount * beneficiary.bps as i128 / MAX_FEE_BPS as i128;
+    }
+
+    for beneficiary in split.beneficiaries.iter() {
+        let mut share = amount * beneficiary.bps as i128 / MAX_FEE_BPS as i128;
+        if beneficiary.address == split.dust_beneficiary {
+            share += dust;
+            dust = 0;
+        }
+
+        if share > 0 {
+            transfer_out(env, &signature_process.asset, &beneficiary.address, &share);
+        }
+
+        // Emit the BeneficiaryPaid event
+        EscrowEvent::BeneficiaryPaid(
+            signature_process.id.clone(),
+            signature_process.propose_id.clone(),
+            beneficiary.address,
+            share,
+        )
+        .publish(env);
+    }
+}
+
+fn get_vesting(env: &Env, signaturit_id: String) -> VestingPosition {
+    match DataKey::Vesting(signaturit_id).get(env) {
+        Some(vesting) => vesting,
+        None => panic_with_error!(env, EscrowError::NoVesting),
+    }
+}
+
+/**
+ * Funds of a vesting position unlocked on the current ledger. They unlock
+ * after the cliff, once per interval, until the whole duration passed.
+ */
+fn get_vested_amount(env: &Env, vesting: &VestingPosition) -> i128 {
+    let elapsed = env.ledger().sequence() - vesting.start_ledger;
+
+    if elapsed < vesting.schedule.cliff {
+        return 0;
+    }
+
+    if elapsed >= vesting.schedule.duration {
+        return vesting.total;
+    }
+
+    let unlocked = elapsed - elapsed % vesting.schedule.interval;
+
+    vesting.total * unlocked as i128 / vesting.schedule.duration as i128
+}
+
+fn get_nft_recipients(env: &Env, escrow_id: String) -> NftRecipients {
+    DataKey::NftRecipients(escrow_id)
+        .get(env)
+        .unwrap_or(NftRecipients::Buyer)
+}
+
+/**
+ * Mint the proof notes of a completed signature to the counterparties set on
+ * the proposal. On a pooled proposal the buyer side are the contributors,
+ * whose notes are linked to their contributions instead. Returns the IDs of
+ * the NFTs minted.
+ */
+fn mint_proof_notes(
+    env: &Env,
+    signature_process: &SignatureTxEscrow,
+    propose: &EscrowProposal,
+    recipients: NftRecipients,
+    document_hash: &String,
+) -> Vec<u32> {
+    let mut nft_ids = Vec::new(env);
+
+    if recipients != NftRecipients::Receiver && propose.mode != ProposalMode::Pooled {
+        nft_ids.push_back(mint_note(
+            env,
+            &signature_process.id,
+            &signature_process.buyer,
+            document_hash,
+        ));
+    }
+
+    if recipients != NftRecipients::Buyer {
+        nft_ids.push_back(mint_note(
+            env,
+            &signature_process.id,
+            &signature_process.receiver,
+            document_hash,
+        ));
+    }
+
+    nft_ids
+}
+
+/**
+ * Send `amount` of the funds of a completed signature tx escrow to the
+ * receiver, keeping for the treasury the platform fee set when the signature
+ * was registered. If the proposal has a vesting schedule, the funds are kept
+ * on a vesting position instead. Returns the fee and the net amount.
+ */
+fn release_funds(env: &Env, signature_process: &SignatureTxEscrow, amount: i128) -> (i128, i128) {
+    let fee = amount * signature_process.fee_bps as i128 / MAX_FEE_BPS as i128;
+    let net_amount = amount - fee;
+
+    let schedule: Option<VestingSchedule> =
+        DataKey::VestingSchedule(signature_process.propose_id.clone()).get(env);
+
+    match schedule {
+        Some(schedule) => {
+            let vesting = VestingPosition {
+                total: net_amount,
+                claimed: 0,
+                start_ledger: env.ledger().sequence(),
+                schedule,
+            };
+
+            DataKey::Vesting(signature_process.id.clone()).set(env, &vesting);
+
+            // Emit the VestingStarted event
+            EscrowEvent::VestingStarted(
+                signature_process.id.clone(),
+                signature_process.propose_id.clone(),
+                vesting.total,
+                vesting.start_ledger,
+            )
+            .publish(env);
+        }
+        None => pay_receiver(env, signature_process, net_amount),
+    }
+
+    if fee > 0 {
+        transfer_out(env, &signature_process.asset, &get_treasury(env), &fee);
+    }
+
+    (fee, net_amount)
+}
+
+/**
+ * Return to the buyer of a milestones proposal the funds not released yet.
+ * The failed milestone, and the ones after it, are not going to be
+ * registered anymore.
+ */
+fn refund_milestones(env: &Env, signature_process: &SignatureTxEscrow, propose: &EscrowProposal) {
+    let mut plan = get_milestone_plan(env, propose.escrow_id.clone());
+    let index = find_milestone(&plan, &signature_process.id);
+
+    let remaining = get_milestones_total(&plan.milestones) - plan.released;
+
+    transfer_out(
+        env,
+        &signature_process.asset,
+        &signature_process.buyer,
+        &remaining,
+    );
+
+    for position in index..plan.milestones.len() {
+        let mut milestone = plan.milestones.get(position).unwrap();
+        milestone.status = MilestoneStatus::Refunded;
+        plan.milestones.set(position, milestone);
+    }
+
+    DataKey::Milestones(propose.escrow_id.clone()).set(env, &plan);
+
+    // Emit the MilestonesRefunded event
+    EscrowEvent::MilestonesRefunded(
+        propose.escrow_id.clone(),
+        index,
+        signature_process.buyer.clone(),
+        remaining,
+    )
+    .publish(env);
+}
+
+/**
+ * Return the funds of a signature tx escrow to the buyer, or to the
+ * contributors on pooled proposals, and make the proposal available again to
+ * be picked. A milestones proposal is canceled instead, since some of its
+ * tranches may be already released.
+ */
+fn refund_signature_tx(
+    env: &Env,
+    signature_process: &mut SignatureTxEscrow,
+    propose: &mut EscrowProposal,
+    status: SignatureStatus,
+) {
+    match propose.mode {
+        // Return the funds to the address that picked the propose
+        ProposalMode::Direct | ProposalMode::Auction => transfer_out(
+            env,
+            &signature_process.asset,
+            &signature_process.buyer,
+            &signature_process.funds,
+        ),
+        // Return to each contributor what they gave
+        ProposalMode::Pooled => refund_contributions(env, propose),
+        // Return to the buyer what is not released yet
+        ProposalMode::Milestones => refund_milestones(env, signature_process, propose),
+    }
+
+    signature_process.status = status;
+    signature_process.settled = NullableCheckpoint::Some(current_checkpoint(env));
+    DataKey::SignatureProcess(signature_process.id.clone()).set(env, signature_process);
+
+    // Both sides of a delivery versus payment are returned
+    let status = match propose.mode {
+        ProposalMode::Milestones => ProposalStatus::Canceled,
+        _ if DataKey::Delivery(propose.escrow_id.clone()).has(env) => ProposalStatus::Canceled,
+        _ => ProposalStatus::Actived,
+    };
+    set_proposal_status(env, propose, status);
+    propose.signature_tx_linked = NullableString::None;
+    DataKey::Proposal(propose.escrow_id.clone()).set(env, propose);
+
+    record_checkpoint(env, &propose.escrow_id, |lifecycle, at| {
+        lifecycle.failed = NullableCheckpoint::Some(at)
+    });
+}
+
+/**
+ * Return to the buyer the part of the funds of a signature tx escrow that is
+ * not released, splitting it between the contributors on pooled proposals in
+ * proportion to what they gave.
+ */
+fn refund_buyer_share(
+    env: &Env,
+    signature_process: &SignatureTxEscrow,
+    propose: &EscrowProposal,
+    amount: i128,
+) {
+    if propose.mode != ProposalMode::Pooled {
+        transfer_out(
+            env,
+            &signature_process.asset,
+            &signature_process.buyer,
+            &amount,
+        );
+
+        return;
+    }
+
+    let contributions = get_contributions(env, propose.escrow_id.clone());
+    let mut refunded: i128 = 0;
+
+    for (index, contribution) in contributions.iter().enumerate() {
+        // The last contributor gets the remainder of the rounding
+        let share = if index as u32 == contributions.len() - 1 {
+            amount - refunded
+        } else {
+            contribution.amount * amount / signature_process.funds
+        };
+        refunded += share;
+
+        transfer_out(
+            env,
+            &signature_process.asset,
+            &contribution.contributor,
+            &share,
+        );
+
+        // Emit the ContributionRefunded event
+        EscrowEvent::ContributionRefunded(
+            propose.escrow_id.clone(),
+            contribution.contributor,
+            share,
+        )
+        .publish(env);
+    }
+}
+
+/**
+ * Release the funds of a signed process and mint the note NFTs. The receiver
+ * gets `receiver_amount` of the funds, minus the platform fee, and the rest is
+ * returned to the buyer.
+ */
+fn settle_signature(
+    env: &Env,
+    signature_process: &mut SignatureTxEscrow,
+    propose: &mut EscrowProposal,
+    document_hash: &String,
+    receiver_amount: i128,
+) {
+    // Release the funds to the owner of the propose, minus the platform fee
+    let (fee, net_amount) = release_funds(env, signature_process, receiver_amount);
+
+    if receiver_amount < signature_process.funds {
+        refund_buyer_share(
+            env,
+            signature_process,
+            propose,
+            signature_process.funds - receiver_amount,
+        );
+    }
+
+    let recipients = get_nft_recipients(env, propose.escrow_id.clone());
+
+    signature_process.nft_proof_ids =
+        mint_proof_notes(env, signature_process, propose, recipients, document_hash);
+
+    if propose.mode == ProposalMode::Pooled {
+        // Each contributor receives a note for the funds given
+        if recipients != NftRecipients::Receiver {
+            let contributor_nft_ids =
+                mint_contributor_notes(env, signature_process, propose, document_hash);
+            signature_process.nft_proof_ids.append(&contributor_nft_ids);
+        }
+
+        signature_process.status = SignatureStatus::Completed;
+        signature_process.settled = NullableCheckpoint::Some(current_checkpoint(env));
+        DataKey::SignatureProcess(signature_process.id.clone()).set(env, signature_process);
+
+        set_proposal_status(env, propose, ProposalStatus::Completed);
+        DataKey::Proposal(propose.escrow_id.clone()).set(env, propose);
+
+        // Emit the PooledCompleted event
+        EscrowEvent::PooledCompleted(
+            signature_process.id.clone(),
+            signature_process.propose_id.clone(),
+            signature_process.receiver.clone(),
+            signature_process.funds,
+            fee,
+            net_amount,
+            signature_process.nft_proof_ids.clone(),
+        )
+        .publish(env);
+
+        return;
+    }
+
+    signature_process.status = SignatureStatus::Completed;
+    signature_process.settled = NullableCheckpoint::Some(current_checkpoint(env));
+    DataKey::SignatureProcess(signature_process.id.clone()).set(env, signature_process);
+
+    if propose.mode == ProposalMode::Milestones {
+        let mut plan = get_milestone_plan(env, propose.escrow_id.clone());
+        let index = find_milestone(&plan, &signature_process.id);
+
+        let mut milestone = plan.milestones.get(index).unwrap();
+        milestone.status = MilestoneStatus::Released;
+        plan.milestones.set(index, milestone);
+        plan.released += signature_process.funds;
+        DataKey::Milestones(propose.escrow_id.clone()).set(env, &plan);
+
+        // The proposal is completed once the last milestone is released
+        propose.signature_tx_linked = NullableString::None;
+        if index == plan.milestones.len() - 1 {
+            set_proposal_status(env, propose, ProposalStatus::Completed);
+        }
+        DataKey::Proposal(propose.escrow_id.clone()).set(env, propose);
+
+        // Emit the MilestoneReleased event
+        EscrowEvent::MilestoneReleased(
+            signature_process.propose_id.clone(),
+            index,
+            signature_process.id.clone(),
+            signature_process.funds,
+            fee,
+            net_amount,
+            signature_process.nft_proof_ids.clone(),
+        )
+        .publish(env);
+
+        return;
+    }
+
+    // The payment and the delivery of the asset are settled together
+    deliver_asset(env, signature_process, propose);
+
+    set_proposal_status(env, propose, ProposalStatus::Completed);
+    DataKey::Proposal(propose.escrow_id.clone()).set(env, propose);
+
+    // Emit the SignedCompleted event
+    EscrowEvent::SignedCompleted(
+        signature_process.id.clone(),
+        signature_process.propose_id.clone(),
+        signature_process.buyer.clone(),
+        signature_process.receiver.clone(),
+        signature_process.funds,
+        fee,
+        net_amount,
+        signature_process.nft_proof_ids.clone(),
+    )
+    .publish(env);
+}
+
+/**
+ * The direct proposal to pick with `funds`. The buyer gives the version of the
+ * terms that agreed (`expected_version`) and the highest price that accepts
+ * (`max_funds`), so an update of the proposal in between rejects the
+ * transaction instead of picking it on different terms.
+ */
+fn get_direct_proposal(
+    env: &Env,
+    proposal_id: String,
+    signaturit_id: &String,
+    funds: i128,
+    max_funds: i128,
+    expected_version: u32,
+) -> EscrowProposal {
+    let propose: EscrowProposal = get_proposal(env, proposal_id);
+
+    if DataKey::SignatureProcess(signaturit_id.clone()).has(env) {
+        panic_with_error!(env, EscrowError::SignatureProcessExist);
+    }
+
+    if propose.status != ProposalStatus::Actived {
+        panic_with_error!(env, EscrowError::PickedOrCanceled);
+    }
+
+    check_not_expired(env, &propose);
+
+    if propose.mode != ProposalMode::Direct {
+        panic_with_error!(env, EscrowError::NotDirectProposal);
+    }
+
+    if propose.version != expected_version {
+        panic_with_error!(env, EscrowError::VersionMismatch);
+    }
+
+    if propose.min_funds > max_funds {
+        panic_with_error!(env, EscrowError::PriceAboveMax);
+    }
+
+    if funds < propose.min_funds {
+        panic_with_error!(env, EscrowError::NoEnoughtFunds);
+    }
+
+    propose
+}
+
+fn check_relayer(env: &Env) {
+    match get_relayer(env) {
+        Some(relayer) => relayer.require_auth(),
+        None => panic_with_error!(env, EscrowError::NotAParty),
+    }
+}
+
+#[contract]
+pub struct EscrowContract;
+
+#[contractimpl]
+impl EscrowContract {
+    pub fn get_oracle(env: Env) -> Address {
+        check_initialization(&env);
+        get_oracle(&env)
+    }
+
+    pub fn get_admin(env: Env) -> Address {
+        check_initialization(&env);
+        get_admin(&env)
+    }
+
+    /**
+     * Schema version of the stored records
+     */
+    pub fn version(env: Env) -> u32 {
+        check_initialization(&env);
+        get_schema_version(&env)
+    }
+
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        check_initialization(&env);
+        DataKey::PendingAdmin.get(&env)
+    }
+
+    pub fn is_paused(env: Env) -> bool {
+        check_initialization(&env);
+        is_paused(&env)
+    }
+
+    pub fn is_asset_allowed(env: Env, asset_address: Address) -> bool {
+        check_initialization(&env);
+        is_asset_allowed(&env, &asset_address)
+    }
+
+    pub fn get_treasury(env: Env) -> Address {
+        check_initialization(&env);
+        get_treasury(&env)
+    }
+
+    pub fn get_fee(env: Env) -> u32 {
+        check_initialization(&env);
+        get_fee(&env)
+    }
+
+    pub fn get_dispute_window(env: Env) -> u32 {
+        check_initialization(&env);
+        get_dispute_window(&env)
+    }
+
+    pub fn get_arbitrator(env: Env) -> Option<Address> {
+        check_initialization(&env);
+        get_arbitrator(&env)
+    }
+
+    pub fn get_relayer(env: Env) -> Option<Address> {
+        check_initialization(&env);
+        get_relayer(&env)
+    }
+
+    /**
+     * Compare what the escrow owes of an asset with the balance that it
+     * actually holds.
+     */
+    pub fn reconcile(env: Env, asset_address: Address) -> Reconciliation {
+        check_initialization(&env);
+        get_reconciliation(&env, &asset_address)
+    }
+
+    pub fn get_nft_notes(env: Env) -> Address {
+        check_initialization(&env);
+        get_nft(&env)
+    }
+
+    pub fn get_signature_timeout(env: Env) -> u32 {
+        check_initialization(&env);
+        get_signature_timeout(&env)
+    }
+
+    pub fn get_proposal(env: Env, escrow_id: String) -> EscrowProposal {
+        get_proposal(&env, escrow_id)
+    }
+
+    pub fn get_signature_tx_escrow(env: Env, signaturit_id: String) -> SignatureTxEscrow {
+        get_signature_tx_escrow(&env, signaturit_id)
+    }
+
+    /**
+     * The signature tx escrow registered with `oracle_id` on the given oracle.
+     * Each oracle counts its ids from 0, so the ids of an oracle replaced
+     * with `set_oracle` still lead to their own escrows.
+     */
+    pub fn get_escrow_by_oracle_id(env: Env, oracle: Address, oracle_id: u32) -> SignatureTxEscrow {
+        match DataKey::OracleIdToEscrow(oracle, oracle_id).get(&env) {
+            Some(signaturit_id) => get_signature_tx_escrow(&env, signaturit_id),
+            None => panic_with_error!(&env, EscrowError::SignatureProcessNotFound),
+        }
+    }
+
+    /**
+     * The signature tx escrow proved by the note NFT `token_id`, minted to a
+     * counterparty or to a contributor
+     */
+    pub fn get_escrow_by_nft(env: Env, token_id: u32) -> SignatureTxEscrow {
+        match DataKey::NftToEscrow(token_id).get(&env) {
+            Some(signaturit_id) => get_signature_tx_escrow(&env, signaturit_id),
+            None => panic_with_error!(&env, EscrowError::SignatureProcessNotFound),
+        }
+    }
+
+    pub fn get_milestones(env: Env, escrow_id: String) -> MilestonePlan {
+        get_milestone_plan(&env, escrow_id)
+    }
+
+    pub fn get_milestone(env: Env, escrow_id: String, index: u32) -> Milestone {
+        match get_milestone_plan(&env, escrow_id).milestones.get(index) {
+            Some(milestone) => milestone,
+            None => panic_with_error!(&env, EscrowError::MilestoneNotFound),
+        }
+    }
+
+    pub fn get_auction(env: Env, escrow_id: String) -> Auction {
+        get_auction(&env, escrow_id)
+    }
+
+    /**
+     * Who receives the proof NFTs of a proposal: the buyer (by default), the
+     * receiver or both
+     */
+    pub fn get_nft_recipients(env: Env, escrow_id: String) -> NftRecipients {
+        check_initialization(&env);
+        get_proposal(&env, escrow_id.clone());
+        get_nft_recipients(&env, escrow_id)
+    }
+
+    /**
+     * The asset sold by a proposal, if it has one
+     */
+    pub fn get_delivery(env: Env, escrow_id: String) -> Option<Delivery> {
+        check_initialization(&env);
+        get_proposal(&env, escrow_id.clone());
+        get_delivery(&env, escrow_id)
+    }
+
+    /**
+     * The beneficiaries that receive the released funds of a proposal, if
+     * they were set
+     */
+    pub fn get_beneficiaries(env: Env, escrow_id: String) -> Option<BeneficiarySplit> {
+        check_initialization(&env);
+        get_proposal(&env, escrow_id.clone());
+        DataKey::Beneficiaries(escrow_id).get(&env)
+    }
+
+    /**
+     * The vesting schedule of a proposal, if it was set
+     */
+    pub fn get_vesting_schedule(env: Env, escrow_id: String) -> Option<VestingSchedule> {
+        check_initialization(&env);
+        get_proposal(&env, escrow_id.clone());
+        DataKey::VestingSchedule(escrow_id).get(&env)
+    }
+
+    pub fn get_vesting(env: Env, signaturit_id: String) -> VestingPosition {
+        check_initialization(&env);
+        get_vesting(&env, signaturit_id)
+    }
+
+    /**
+     * Funds of a vesting position that the receiver can claim now
+     */
+    pub fn get_claimable(env: Env, signaturit_id: String) -> i128 {
+        check_initialization(&env);
+        let vesting = get_vesting(&env, signaturit_id);
+        get_vested_amount(&env, &vesting) - vesting.claimed
+    }
+
+    pub fn get_pending_release(env: Env, signaturit_id: String) -> PendingRelease {
+        get_pending_release(&env, signaturit_id)
+    }
+
+    /**
+     * Proposals in the given status, paginated from `cursor` (the position on
+     * the listing) with at most `limit` positions per page. The positions do
+     * not change when a proposal leaves the listing, so the next page always
+     * starts at `cursor + limit`, and a page can hold less than `limit`
+     * proposals before the end of the listing.
+     */
+    pub fn list_proposals(
+        env: Env,
+        status: ProposalStatus,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<EscrowProposal> {
+        let mut proposals: Vec<EscrowProposal> = Vec::new(&env);
+
+        for escrow_id in get_index_page(&env, &Listing::ProposalsByStatus(status), cursor, limit) {
+            proposals.push_back(get_proposal(&env, escrow_id));
+        }
+
+        proposals
+    }
+
+    /**
+     * Proposals added by the owner, paginated as `list_proposals`.
+     */
+    pub fn proposals_by_owner(
+        env: Env,
+        owner: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<EscrowProposal> {
+        let mut proposals: Vec<EscrowProposal> = Vec::new(&env);
+
+        for escrow_id in get_index_page(&env, &Listing::ProposalsByOwner(owner), cursor, limit) {
+            proposals.push_back(get_proposal(&env, escrow_id));
+        }
+
+        proposals
+    }
+
+    /**
+     * Signature tx escrows registered with the funds of the buyer, paginated
+     * as `list_proposals`. The escrows of pooled proposals are listed under
+     * each contributor and under the address of the escrow, which holds the
+     * contributions.
+     */
+    pub fn escrows_by_buyer(
+        env: Env,
+        buyer: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<SignatureTxEscrow> {
+        let mut escrows: Vec<SignatureTxEscrow> = Vec::new(&env);
+
+        for signaturit_id in get_index_page(&env, &Listing::EscrowsByBuyer(buyer), cursor, limit) {
+            escrows.push_back(get_signature_tx_escrow(&env, signaturit_id));
+        }
+
+        escrows
+    }
+
+    /**
+     * Every signature tx escrow linked to the proposal, including the failed
+     * and expired ones, in the order they were registered and paginated as
+     * `list_proposals`.
+     */
+    pub fn escrows_by_proposal(
+        env: Env,
+        escrow_id: String,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<SignatureTxEscrow> {
+        get_proposal(&env, escrow_id.clone());
+
+        let mut escrows: Vec<SignatureTxEscrow> = Vec::new(&env);
+
+        for signaturit_id in
+            get_index_page(&env, &Listing::EscrowsByProposal(escrow_id), cursor, limit)
+        {
+            escrows.push_back(get_signature_tx_escrow(&env, signaturit_id));
+        }
+
+        escrows
+    }
+
+    /**
+     * Ledger sequence and timestamp of the transitions of the proposal. The
+     * proposals added before the lifecycle was recorded have no `created`.
+     */
+    pub fn get_lifecycle(env: Env, escrow_id: String) -> Lifecycle {
+        get_proposal(&env, escrow_id.clone());
+        get_lifecycle(&env, escrow_id)
+    }
+
+    pub fn get_contributions(env: Env, escrow_id: String) -> Vec<Contribution> {
+        get_proposal(&env, escrow_id.clone());
+        get_contributions(&env, escrow_id)
+    }
+
+    /**
+     * Initialize the escrow. The `asset_address` is the first asset allowed to
+     * be used on the proposals, and the `admin` can manage the allowed assets.
+     * On each completed signature, `fee_bps` (basis points, up to 1_000) of
+     * the funds are sent to the `treasury`.
+     */
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        asset_address: Address,
+        oracle_address: Address,
+        nft_notes_address: Address,
+        signature_timeout: u32,
+        treasury: Address,
+        fee_bps: u32,
+    ) {
+        if DataKey::Admin.has(&env)
+            && DataKey::OracleAddress.has(&env)
+            && DataKey::NFTNotesAddress.has(&env)
+        {
+            panic_with_error!(env, EscrowError::AlreadyInitialized);
+        }
+
+        DataKey::Admin.set(&env, &admin);
+        DataKey::SchemaVersion.set(&env, &SCHEMA_VERSION);
+        DataKey::AllowedAsset(asset_address.clone()).set(&env, &true);
+        DataKey::OracleAddress.set(&env, &oracle_address);
+        DataKey::NFTNotesAddress.set(&env, &nft_notes_address);
+        DataKey::SignatureTimeout.set(&env, &signature_timeout);
+
+        check_fee(&env, fee_bps);
+        DataKey::Treasury.set(&env, &treasury);
+        DataKey::FeeBps.set(&env, &fee_bps);
+
+        // Emit the Initialized event
+        EscrowEvent::Initialized(
+            admin,
+            asset_address,
+            oracle_address,
+            nft_notes_address,
+            signature_timeout,
+            treasury,
+            fee_bps,
+        )
+        .publish(&env);
+    }
+
+    /**
+     * Replace the code of the contract keeping its storage. If the new code
+     * changes the layout of the stored records, `migrate` has to be called
+     * after the upgrade.
+     */
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        check_initialization(&env);
+        get_admin(&env).require_auth();
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        // Emit the Upgraded event
+        EscrowEvent::Upgraded(new_wasm_hash).publish(&env);
+    }
+
+    /**
+     * Rewrite the given proposals and signature processes from the stored
+     * schema version to the one of the current code. The storage can not be
+     * iterated, so the ids of all the stored records have to be given. They
+     * can be split over several calls, and the records already migrated are
+     * skipped. The escrow should be paused until `finish_migration` is called.
+     */
+    pub fn migrate(env: Env, proposal_ids: Vec<String>, signaturit_ids: Vec<String>) {
+        check_initialization(&env);
+        get_admin(&env).require_auth();
+
+        let from_version = get_schema_version(&env);
+
+        if from_version >= SCHEMA_VERSION {
+            panic_with_error!(&env, EscrowError::AlreadyMigrated);
+        }
+
+        for proposal_id in proposal_ids.iter() {
+            migration::migrate_proposal(&env, proposal_id, from_version);
+        }
+
+        for signaturit_id in signaturit_ids.iter() {
+            migration::migrate_signature_process(&env, signaturit_id, from_version);
+        }
+    }
+
+    /**
+     * Store the schema version of the current code once all the records have
+     * been given to `migrate`.
+     */
+    pub fn finish_migration(env: Env) {
+        check_initialization(&env);
+        get_admin(&env).require_auth();
+
+        let from_version = get_schema_version(&env);
+
+        if from_version >= SCHEMA_VERSION {
+            panic_with_error!(&env, EscrowError::AlreadyMigrated);
+        }
+
+        DataKey::SchemaVersion.set(&env, &SCHEMA_VERSION);
+
+        // Emit the Migrated event
+        EscrowEvent::Migrated(from_version, SCHEMA_VERSION).publish(&env);
+    }
+
+    /**
+     * Upgrade the NFT Notes contract, which has the escrow as admin.
+     */
+    pub fn upgrade_nft_notes(env: Env, new_wasm_hash: BytesN<32>) {
+        check_initialization(&env);
+        get_admin(&env).require_auth();
+
+        let nft_client = notes_nft::NotesNFTClient::new(&env, &get_nft(&env));
+        nft_client.upgrade(&new_wasm_hash);
+    }
+
+    /**
+     * Migrate the storage of the NFT Notes contract after an upgrade.
+     */
+    pub fn migrate_nft_notes(env: Env) {
+        check_initialization(&env);
+        get_admin(&env).require_auth();
+
+        let nft_client = notes_nft::NotesNFTClient::new(&env, &get_nft(&env));
+        nft_client.migrate();
+    }
+
+    /**
+     * Start the transfer of the admin role. The new admin has to accept it
+     * with `accept_admin` before it takes effect.
+     */
+    pub fn transfer_admin(env: Env, new_admin: Address) {
+        check_initialization(&env);
+
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        DataKey::PendingAdmin.set(&env, &new_admin);
+
+        // Emit the AdminTransferStarted event
+        EscrowEvent::AdminTransferStarted(admin, new_admin).publish(&env);
+    }
+
+    /**
+     * Accept the admin role offered with `transfer_admin`.
+     */
+    pub fn accept_admin(env: Env) {
+        check_initialization(&env);
+
+        let new_admin: Address = match DataKey::PendingAdmin.get(&env) {
+            Some(pending_admin) => pending_admin,
+            None => panic_with_error!(&env, EscrowError::NoPendingAdmin),
+        };
+        new_admin.require_auth();
+
+        let previous_admin = get_admin(&env);
+
+        DataKey::Admin.set(&env, &new_admin);
+        DataKey::PendingAdmin.remove(&env);
+
+        // Emit the AdminTransferred event
+        EscrowEvent::AdminTransferred(previous_admin, new_admin).publish(&env);
+    }
+
+    /**
+     * Stop accepting new proposals and escrows. The signature processes in
+     * progress can still be settled by the oracle, canceled or reclaimed.
+     */
+    pub fn pause(env: Env) {
+        check_initialization(&env);
+
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        DataKey::Paused.set(&env, &true);
+
+        // Emit the Paused event
+        EscrowEvent::Paused(admin).publish(&env);
+    }
+
+    pub fn unpause(env: Env) {
+        check_initialization(&env);
+
+        let admin = get_admin(&env);
+        admin.require_auth();
+
+        DataKey::Paused.remove(&env);
+
+        // Emit the Unpaused event
+        EscrowEvent::Unpaused(admin).publish(&env);
+    }
+
+    /**
+     * Update the oracle address. The callbacks of the signature processes
+     * registered on the previous oracle will not be accepted anymore.
+     */
+    pub fn set_oracle(env: Env, oracle_address: Address) {
+        check_initialization(&env);
+        get_admin(&env).require_auth();
+
+        DataKey::OracleAddress.set(&env, &oracle_address);
+
+        // Emit the OracleUpdated event
+        EscrowEvent::OracleUpdated(oracle_address).publish(&env);
+    }
+
+    /**
+     * Update the NFT Notes address used to mint the proof of the completed
+     * signatures. The escrow has to be the admin of the new NFT contract.
+     */
+    pub fn set_nft_notes(env: Env, nft_notes_address: Address) {
+        check_initialization(&env);
+        get_admin(&env).require_auth();
+
+        DataKey::NFTNotesAddress.set(&env, &nft_notes_address);
+
+        // Emit the NFTNotesUpdated event
+        EscrowEvent::NFTNotesUpdated(nft_notes_address).publish(&env);
+    }
+
+    /**
+     * Update the platform fee (basis points, up to 1_000) taken on each
+     * completed signature. The signatures already registered keep their fee.
+     */
+    pub fn set_fee(env: Env, fee_bps: u32) {
+        check_initialization(&env);
+        get_admin(&env).require_auth();
+
+        check_fee(&env, fee_bps);
+        DataKey::FeeBps.set(&env, &fee_bps);
+
+        // Emit the FeeUpdated event
+        EscrowEvent::FeeUpdated(fee_bps).publish(&env);
+    }
+
+    /**
+     * Update the address that receives the platform fees.
+     */
+    pub fn set_treasury(env: Env, treasury: Address) {
+        check_initialization(&env);
+        get_admin(&env).require_auth();
+
+        DataKey::Treasury.set(&env, &treasury);
+
+        // Emit the TreasuryUpdated event
+        EscrowEvent::TreasuryUpdated(treasury).publish(&env);
+    }
+
+    /**
+     * Set the amount of ledgers that the funds of a completed signature are
+     * held before being released, so the parties can dispute it. With `0` the
+     * funds are released with the signature.
+     */
+    pub fn set_dispute_window(env: Env, dispute_window: u32) {
+        check_initialization(&env);
+        get_admin(&env).require_auth();
+
+        DataKey::DisputeWindow.set(&env, &dispute_window);
+
+        // Emit the DisputeWindowUpdated event
+        EscrowEvent::DisputeWindowUpdated(dispute_window).publish(&env);
+    }
+
+    /**
+     * Set the address that resolves the disputes.
+     */
+    pub fn set_arbitrator(env: Env, arbitrator: Address) {
+        check_initialization(&env);
+        get_admin(&env).require_auth();
+
+        DataKey::Arbitrator.set(&env, &arbitrator);
+
+        // Emit the ArbitratorUpdated event
+        EscrowEvent::ArbitratorUpdated(arbitrator).publish(&env);
+    }
+
+    /**
+     * Set the trusted platform relayer, which can register escrows on behalf
+     * of the buyers with `relay_register_escrow`. The buyers still authorize
+     * the transfer of their funds.
+     */
+    pub fn set_relayer(env: Env, relayer: Address) {
+        check_initialization(&env);
+        get_admin(&env).require_auth();
+
+        DataKey::Relayer.set(&env, &relayer);
+
+        // Emit the RelayerUpdated event
+        EscrowEvent::RelayerUpdated(relayer).publish(&env);
+    }
+
+    /**
+     * Remove the platform relayer, so the escrows can only be registered by
+     * the buyers.
+     */
+    pub fn remove_relayer(env: Env) {
+        check_initialization(&env);
+        get_admin(&env).require_auth();
+
+        let relayer = match get_relayer(&env) {
+            Some(relayer) => relayer,
+            None => panic_with_error!(&env, EscrowError::NotAParty),
+        };
+
+        DataKey::Relayer.remove(&env);
+
+        // Emit the RelayerRemoved event
+        EscrowEvent::RelayerRemoved(relayer).publish(&env);
+    }
+
+    /**
+     * Send to `to` the balance of an asset above what the escrow owes, such as
+     * the tokens sent to the contract by mistake. The funds held for the
+     * proposals and signature processes are never swept.
+     */
+    pub fn sweep_surplus(env: Env, asset_address: Address, to: Address) {
+        check_initialization(&env);
+        get_admin(&env).require_auth();
+
+        let surplus = get_reconciliation(&env, &asset_address).surplus;
+
+        if surplus <= 0 {
+            panic_with_error!(&env, EscrowError::NothingToClaim);
+        }
+
+        let client = token::Client::new(&env, &asset_address);
+        client.transfer(&env.current_contract_address(), &to, &surplus);
+
+        // Emit the SurplusSwept event
+        EscrowEvent::SurplusSwept(asset_address, to, surplus).publish(&env);
+    }
+
+    /**
+     * Allow a SEP-41 token to be used as the settlement asset of the proposals.
+     */
+    pub fn allow_asset(env: Env, asset_address: Address) {
+        check_initialization(&env);
+        get_admin(&env).require_auth();
+
+        DataKey::AllowedAsset(asset_address.clone()).set(&env, &true);
+
+        // Emit the AssetAllowed event
+        EscrowEvent::AssetAllowed(asset_address).publish(&env);
+    }
+
+    /**
+     * Remove an asset from the allowed ones. The proposals already using this
+     * asset are not affected.
+     */
+    pub fn disallow_asset(env: Env, asset_address: Address) {
+        check_initialization(&env);
+        get_admin(&env).require_auth();
+
+        DataKey::AllowedAsset(asset_address.clone()).remove(&env);
+
+        // Emit the AssetDisallowed event
+        EscrowEvent::AssetDisallowed(asset_address).publish(&env);
+    }
+
+    /**
+     * Register a new proposal to be picked, priced in one of the allowed
+     * assets (`asset_address`). On `Pooled` mode, several addresses
+     * contribute to the proposal before the signature process is registered.
+     * The optional `terms` are:
+     * - `signature_timeout`: amount of ledgers that the buyer should wait for
+     *   the signature process before reclaiming the funds. If it is not given
+     *   the global timeout is used.
+     * - `expires_at_ledger`: after it the proposal can not be picked and
+     *   anyone can expire it.
+     * - `bond_bps`: bond posted by the owner, in basis points of `min_funds`,
+     *   returned when the proposal is closed unless the owner declines the
+     *   signature.
+     * - `delivery`: tokenized asset sold by the proposal, deposited by the
+     *   owner and delivered to the buyer together with the payment.
+     */
+    pub fn add_proposal(
+        env: Env,
+        stocken_proposal_id: String,
+        proposer_address: Address,
+        asset_address: Address,
+        min_funds: i128,
+        mode: ProposalMode,
+        terms: ProposalTerms,
+    ) {
+        check_initialization(&env);
+        check_not_paused(&env);
+
+        proposer_address.require_auth();
+
+        if !is_asset_allowed(&env, &asset_address) {
+            panic_with_error!(&env, EscrowError::AssetNotAllowed);
+        }
+
+        if DataKey::Proposal(stocken_proposal_id.clone()).has(&env) {
+            panic_with_error!(&env, EscrowError::AlreadyProposed);
+        }
+
+        if terms.bond_bps > MAX_FEE_BPS {
+            panic_with_error!(&env, EscrowError::InvalidAmount);
+        }
+
+        if let NullableDelivery::Some(delivery) = &terms.delivery {
+            // The asset is delivered to a single buyer
+            if mode != ProposalMode::Direct && mode != ProposalMode::Auction {
+                panic_with_error!(&env, EscrowError::NotDirectProposal);
+            }
+
+            if delivery.amount <= 0 {
+                panic_with_error!(&env, EscrowError::InvalidAmount);
+            }
+        }
+
+        let mut propose = EscrowProposal {
+            escrow_id: stocken_proposal_id.clone(),
+            owner: proposer_address.clone(),
+            status: ProposalStatus::Actived,
+            asset: asset_address,
+            min_funds,
+            signature_tx_linked: NullableString::None,
+            signature_timeout: terms.signature_timeout,
+            mode,
+            expires_at_ledger: terms.expires_at_ledger,
+            version: 1,
+            bond: 0,
+            bond_bps: terms.bond_bps,
+        };
+
+        check_not_expired(&env, &propose);
+
+        adjust_bond(&env, &mut propose);
+
+        if let NullableDelivery::Some(mut delivery) = terms.delivery {
+            transfer_in(&env, &delivery.asset, &propose.owner, &delivery.amount);
+
+            delivery.status = DeliveryStatus::Held;
+            DataKey::Delivery(stocken_proposal_id.clone()).set(&env, &delivery);
+
+            // Emit the DeliveryDeposited event
+            EscrowEvent::DeliveryDeposited(
+                stocken_proposal_id.clone(),
+                delivery.asset,
+                delivery.amount,
+            )
+            .publish(&env);
+        }
+
+        // Save the proposal
+        DataKey::Proposal(stocken_proposal_id.clone()).set(&env, &propose);
+        record_checkpoint(&env, &stocken_proposal_id, |lifecycle, at| {
+            lifecycle.created = NullableCheckpoint::Some(at)
+        });
+
+        add_to_index(
+            &env,
+            &Listing::ProposalsByStatus(propose.status),
+            &stocken_proposal_id,
+        );
+        add_to_index(
+            &env,
+            &Listing::ProposalsByOwner(propose.owner.clone()),
+            &stocken_proposal_id,
+        );
+
+        // Emit the NewProposal event
+        EscrowEvent::NewProposal(propose.escrow_id, propose.owner).publish(&env);
+    }
+
+    /**
+     * Cancel an active proposal so it can not be picked anymore. Only the
+     * owner of the proposal can cancel it, and only while it is not picked.
+     * The contributions of a pooled proposal are refunded.
+     */
+    pub fn cancel_proposal(env: Env, escrow_id: String) {
+        check_initialization(&env);
+
+        let mut propose = get_proposal(&env, escrow_id.clone());
+
+        propose.owner.require_auth();
+
+        if propose.status != ProposalStatus::Actived {
+            panic_with_error!(&env, EscrowError::PickedOrCanceled);
+        }
+
+        refund_unpicked_funds(&env, &propose);
+
+        set_proposal_status(&env, &mut propose, ProposalStatus::Canceled);
+        DataKey::Proposal(escrow_id).set(&env, &propose);
+
+        // Emit the ProposalCanceled event
+        EscrowEvent::ProposalCanceled(propose.escrow_id, propose.owner).publish(&env);
+    }
+
+    /**
+     * Split the released funds of an active proposal among several
+     * beneficiaries, each one with a share in basis points that sum 10_000.
+     * The dust of the rounding goes to `dust_beneficiary`, which has to be one
+     * of them. Without beneficiaries the funds are paid to the owner again.
+     * Only the owner of the proposal can set them.
+     */
+    pub fn set_beneficiaries(
+        env: Env,
+        proposal_id: String,
+        beneficiaries: Vec<Beneficiary>,
+        dust_beneficiary: Address,
+    ) {
+        check_initialization(&env);
+
+        let mut propose = get_proposal(&env, proposal_id.clone());
+
+        propose.owner.require_auth();
+
+        if propose.status != ProposalStatus::Actived {
+            panic_with_error!(&env, EscrowError::PickedOrCanceled);
+        }
+
+        if beneficiaries.is_empty() {
+            DataKey::Beneficiaries(proposal_id.clone()).remove(&env);
+        } else {
+            check_beneficiaries(&env, &beneficiaries, &dust_beneficiary);
+
+            DataKey::Beneficiaries(proposal_id.clone()).set(
+                &env,
+                &BeneficiarySplit {
+                    beneficiaries: beneficiaries.clone(),
+                    dust_beneficiary: dust_beneficiary.clone(),
+                },
+            );
+        }
+
+        bump_version(&env, &mut propose);
+
+        // Emit the BeneficiariesSet event
+        EscrowEvent::BeneficiariesSet(proposal_id, beneficiaries.len(), dust_beneficiary)
+            .publish(&env);
+    }
+
+    /**
+     * Keep the released funds of an active proposal on a vesting position,
+     * unlocked linearly to the receiver. The `cliff`, `duration` and
+     * `interval` are in ledgers, counted from the release. A `duration` of 0
+     * removes the schedule. Only the owner of the proposal can set it.
+     */
+    pub fn set_vesting(env: Env, proposal_id: String, cliff: u32, duration: u32, interval: u32) {
+        check_initialization(&env);
+
+        let mut propose = get_proposal(&env, proposal_id.clone());
+
+        propose.owner.require_auth();
+
+        if propose.status != ProposalStatus::Actived {
+            panic_with_error!(&env, EscrowError::PickedOrCanceled);
+        }
+
+        if duration == 0 {
+            DataKey::VestingSchedule(proposal_id.clone()).remove(&env);
+        } else {
+            if interval == 0 || interval > duration || cliff > duration {
+                panic_with_error!(&env, EscrowError::InvalidVesting);
+            }
+
+            DataKey::VestingSchedule(proposal_id.clone()).set(
+                &env,
+                &VestingSchedule {
+                    cliff,
+                    duration,
+                    interval,
+                },
+            );
+        }
+
+        bump_version(&env, &mut propose);
+
+        // Emit the VestingSet event
+        EscrowEvent::VestingSet(proposal_id, cliff, duration, interval).publish(&env);
+    }
+
+    /**
+     * Withdraw the funds of a vesting position unlocked so far. Only the
+     * receiver of the signature process can claim them, and they are paid
+     * to the beneficiaries of the proposal if it has.
+     */
+    pub fn claim_vested(env: Env, signaturit_id: String) {
+        check_initialization(&env);
+
+        let signature_process = get_signature_tx_escrow(&env, signaturit_id.clone());
+
+        signature_process.receiver.require_auth();
+
+        let mut vesting = get_vesting(&env, signaturit_id.clone());
+
+        let amount = get_vested_amount(&env, &vesting) - vesting.claimed;
+
+        if amount == 0 {
+            panic_with_error!(&env, EscrowError::NothingToClaim);
+        }
+
+        vesting.claimed += amount;
+        DataKey::Vesting(signaturit_id.clone()).set(&env, &vesting);
+
+        pay_receiver(&env, &signature_process, amount);
+
+        // Emit the VestedClaimed event
+        EscrowEvent::VestedClaimed(
+            signaturit_id,
+            signature_process.receiver,
+            amount,
+            vesting.claimed,
+        )
+        .publish(&env);
+    }
+
+    /**
+     * Set who receives the proof NFTs when the signature of an active
+     * proposal is completed. Only the owner of the proposal can set it.
+     */
+    pub fn set_nft_recipients(env: Env, proposal_id: String, recipients: NftRecipients) {
+        check_initialization(&env);
+
+        let mut propose = get_proposal(&env, proposal_id.clone());
+
+        propose.owner.require_auth();
+
+        if propose.status != ProposalStatus::Actived {
+            panic_with_error!(&env, EscrowError::PickedOrCanceled);
+        }
+
+        DataKey::NftRecipients(proposal_id.clone()).set(&env, &recipients);
+
+        bump_version(&env, &mut propose);
+
+        // Emit the NftRecipientsUpdated event
+        EscrowEvent::NftRecipientsUpdated(proposal_id, recipients).publish(&env);
+    }
+
+    /**
+     * Update the terms of an active proposal. Only the owner of the proposal
+     * can update it, and each update increases the version of the terms, so
+     * the buyers that expected the previous ones are rejected. The bond keeps
+     * its basis points, so the owner posts or gets back the difference when
+     * `min_funds` changes. The milestones of a proposal have to cover the new
+     * `min_funds`.
+     */
+    pub fn update_proposal(
+        env: Env,
+        escrow_id: String,
+        min_funds: i128,
+        signature_timeout: Option<u32>,
+        expires_at_ledger: Option<u32>,
+    ) {
+        check_initialization(&env);
+        check_not_paused(&env);
+
+        let mut propose = get_proposal(&env, escrow_id.clone());
+
+        propose.owner.require_auth();
+
+        if propose.status != ProposalStatus::Actived {
+            panic_with_error!(&env, EscrowError::PickedOrCanceled);
+        }
+
+        if propose.mode == ProposalMode::Milestones
+            && DataKey::Milestones(escrow_id.clone()).has(&env)
+        {
+            let plan = get_milestone_plan(&env, escrow_id.clone());
+
+            if get_milestones_total(&plan.milestones) < min_funds {
+                panic_with_error!(&env, EscrowError::InvalidMilestones);
+            }
+        }
+
+        propose.min_funds = min_funds;
+        propose.signature_timeout = signature_timeout;
+        propose.expires_at_ledger = expires_at_ledger;
+
+        check_not_expired(&env, &propose);
+
+        adjust_bond(&env, &mut propose);
+        bump_version(&env, &mut propose);
+    }
+
+    /**
+     * Cancel an active proposal after its expiration ledger, returning the
+     * funds held for it as `cancel_proposal` does. Anyone can call it.
+     */
+    pub fn expire_proposal(env: Env, escrow_id: String) {
+        check_initialization(&env);
+
+        let mut propose = get_proposal(&env, escrow_id.clone());
+
+        if propose.status != ProposalStatus::Actived {
+            panic_with_error!(&env, EscrowError::PickedOrCanceled);
+        }
+
+        let expires_at_ledger = match propose.expires_at_ledger {
+            Some(expires_at_ledger) if is_expired(&env, &propose) => expires_at_ledger,
+            _ => panic_with_error!(&env, EscrowError::ProposalNotExpired),
+        };
+
+        refund_unpicked_funds(&env, &propose);
+
+        set_proposal_status(&env, &mut propose, ProposalStatus::Canceled);
+        DataKey::Proposal(escrow_id).set(&env, &propose);
+
+        // Emit the ProposalExpired event
+        EscrowEvent::ProposalExpired(propose.escrow_id, propose.owner, expires_at_ledger)
+            .publish(&env);
+    }
+
+    /**
+     * Pick a direct proposal, giving the funds and registering the signature
+     * process on the Oracle. The buyer gives the version of the terms that
+     * agreed (`expected_version`) and the highest price that accepts
+     * (`max_funds`), so an update of the proposal in between rejects the
+     * transaction instead of picking it on different terms.
+     */
+    pub fn register_escrow(
+        env: Env,
+        proposal_id: String,
+        signaturit_id: String,
+        sender_id: Address,
+        funds: i128,
+        max_funds: i128,
+        expected_version: u32,
+    ) {
+        check_initialization(&env);
+        check_not_paused(&env);
+
+        sender_id.require_auth();
+
+        let mut propose = get_direct_proposal(
+            &env,
+            proposal_id,
+            &signaturit_id,
+            funds,
+            max_funds,
+            expected_version,
+        );
+
+        transfer_in(&env, &propose.asset, &sender_id, &funds);
+
+        register_signature_process(&env, &mut propose, signaturit_id, sender_id, funds);
+    }
+
+    /**
+     * Pick a direct proposal on behalf of the buyer. Only the platform relayer
+     * can call it, and the buyer only authorizes the transfer of the funds
+     * made by the escrow (a sub-invocation of this call).
+     */
+    pub fn relay_register_escrow(
+        env: Env,
+        proposal_id: String,
+        signaturit_id: String,
+        sender_id: Address,
+        funds: i128,
+        max_funds: i128,
+        expected_version: u32,
+    ) {
+        check_initialization(&env);
+        check_not_paused(&env);
+
+        check_relayer(&env);
+
+        let mut propose = get_direct_proposal(
+            &env,
+            proposal_id,
+            &signaturit_id,
+            funds,
+            max_funds,
+            expected_version,
+        );
+
+        transfer_in(&env, &propose.asset, &sender_id, &funds);
+
+        register_signature_process(&env, &mut propose, signaturit_id, sender_id, funds);
+    }
+
+    /**
+     * Pick a direct proposal with the funds that the buyer approved to the
+     * escrow (`approve` on the token), so the buyer does not sign this call.
+     * Only the platform relayer can call it, and the escrow has to receive
+     * the whole `funds`.
+     */
+    pub fn register_escrow_from_allowance(
+        env: Env,
+        proposal_id: String,
+        signaturit_id: String,
+        sender_id: Address,
+        funds: i128,
+        max_funds: i128,
+        expected_version: u32,
+    ) {
+        check_initialization(&env);
+        check_not_paused(&env);
+
+        check_relayer(&env);
+
+        let mut propose = get_direct_proposal(
+            &env,
+            proposal_id,
+            &signaturit_id,
+            funds,
+            max_funds,
+            expected_version,
+        );
+
+        transfer_funds_from_allowance(&env, &propose.asset, &sender_id, &funds);
+
+        register_signature_process(&env, &mut propose, signaturit_id, sender_id, funds);
+    }
+
+    /**
+     * Give funds to a pooled proposal. The funds are held by the escrow until
+     * the signature process ends, and returned if it fails or the proposal is
+     * canceled.
+     */
+    pub fn contribute(env: Env, proposal_id: String, contributor: Address, amount: i128) {
+        check_initialization(&env);
+        check_not_paused(&env);
+
+        contributor.require_auth();
+
+        let propose: EscrowProposal = get_proposal(&env, proposal_id.clone());
+
+        if propose.status != ProposalStatus::Actived {
+            panic_with_error!(&env, EscrowError::PickedOrCanceled);
+        }
+
+        check_not_expired(&env, &propose);
+
+        if propose.mode != ProposalMode::Pooled {
+            panic_with_error!(&env, EscrowError::NotPooledProposal);
+        }
+
+        if amount <= 0 {
+            panic_with_error!(&env, EscrowError::InvalidAmount);
+        }
+
+        transfer_in(&env, &propose.asset, &contributor, &amount);
+
+        let mut contributions = get_contributions(&env, proposal_id.clone());
+
+        // The contributions of the same address are accumulated
+        match contributions
+            .iter()
+            .position(|contribution| contribution.contributor == contributor)
+        {
+            Some(index) => {
+                let mut contribution = contributions.get(index as u32).unwrap();
+                contribution.amount += amount;
+                contributions.set(index as u32, contribution);
+            }
+            None => contributions.push_back(Contribution {
+                contributor: contributor.clone(),
+                amount,
+                nft_id: None,
+            }),
+        }
+
+        DataKey::Contributions(proposal_id.clone()).set(&env, &contributions);
+
+        // Emit the ContributionAdded event
+        EscrowEvent::ContributionAdded(
+            proposal_id,
+            contributor,
+            amount,
+            get_total_contributed(&contributions),
+        )
+        .publish(&env);
+    }
+
+    /**
+     * Register the signature process of a pooled proposal once the
+     * contributions reach the minimun funds. Only the owner of the proposal
+     * can register it.
+     */
+    pub fn register_pooled_escrow(env: Env, proposal_id: String, signaturit_id: String) {
+        check_initialization(&env);
+        check_not_paused(&env);
+
+        let mut propose: EscrowProposal = get_proposal(&env, proposal_id.clone());
+
+        propose.owner.require_auth();
+
+        if DataKey::SignatureProcess(signaturit_id.clone()).has(&env) {
+            panic_with_error!(&env, EscrowError::SignatureProcessExist);
+        }
+
+        if propose.status != ProposalStatus::Actived {
+            panic_with_error!(&env, EscrowError::PickedOrCanceled);
+        }
+
+        check_not_expired(&env, &propose);
+
+        if propose.mode != ProposalMode::Pooled {
+            panic_with_error!(&env, EscrowError::NotPooledProposal);
+        }
+
+        let funds = get_total_contributed(&get_contributions(&env, proposal_id));
+
+        if funds < propose.min_funds {
+            panic_with_error!(&env, EscrowError::NoEnoughtFunds);
+        }
+
+        register_signature_process(
+            &env,
+            &mut propose,
+            signaturit_id,
+            env.current_contract_address(),
+            funds,
+        );
+    }
+
+    /**
+     * Split a milestones proposal in tranches, each one released when its own
+     * signature process is completed. The tranches have to cover the minimun
+     * funds of the proposal. Only the owner can set them, and only while the
+     * proposal is not picked.
+     */
+    pub fn set_milestones(env: Env, proposal_id: String, amounts: Vec<i128>) {
+        check_initialization(&env);
+        check_not_paused(&env);
+
+        let mut propose: EscrowProposal = get_proposal(&env, proposal_id.clone());
+
+        propose.owner.require_auth();
+
+        if propose.status != ProposalStatus::Actived {
+            panic_with_error!(&env, EscrowError::PickedOrCanceled);
+        }
+
+        if propose.mode != ProposalMode::Milestones {
+            panic_with_error!(&env, EscrowError::NotMilestonesProposal);
+        }
+
+        let mut milestones: Vec<Milestone> = Vec::new(&env);
+
+        for amount in amounts.iter() {
+            if amount <= 0 {
+                panic_with_error!(&env, EscrowError::InvalidMilestones);
+            }
+
+            milestones.push_back(Milestone {
+                amount,
+                signaturit_id: NullableString::None,
+                status: MilestoneStatus::Pending,
+            });
+        }
+
+        let total_funds = get_milestones_total(&milestones);
+
+        if milestones.is_empty() || total_funds < propose.min_funds {
+            panic_with_error!(&env, EscrowError::InvalidMilestones);
+        }
+
+        let plan = MilestonePlan {
+            buyer: NullableAddress::None,
+            released: 0,
+            milestones,
+        };
+
+        DataKey::Milestones(proposal_id.clone()).set(&env, &plan);
+
+        bump_version(&env, &mut propose);
+
+        // Emit the MilestonesSet event
+        EscrowEvent::MilestonesSet(proposal_id, plan.milestones.len(), total_funds).publish(&env);
+    }
+
+    /**
+     * Pick a milestones proposal giving the funds of all its milestones. The
+     * funds are held by the escrow and released tranche by tranche. The buyer
+     * gives the version of the terms that agreed, as on `register_escrow`.
+     */
+    pub fn fund_milestones(env: Env, proposal_id: String, buyer: Address, expected_version: u32) {
+        check_initialization(&env);
+        check_not_paused(&env);
+
+        buyer.require_auth();
+
+        let mut propose: EscrowProposal = get_proposal(&env, proposal_id.clone());
+
+        if propose.status != ProposalStatus::Actived {
+            panic_with_error!(&env, EscrowError::PickedOrCanceled);
+        }
+
+        check_not_expired(&env, &propose);
+
+        if propose.mode != ProposalMode::Milestones {
+            panic_with_error!(&env, EscrowError::NotMilestonesProposal);
+        }
+
+        if propose.version != expected_version {
+            panic_with_error!(&env, EscrowError::VersionMismatch);
+        }
+
+        let mut plan = get_milestone_plan(&env, proposal_id.clone());
+        let funds = get_milestones_total(&plan.milestones);
+
+        transfer_in(&env, &propose.asset, &buyer, &funds);
+
+        plan.buyer = NullableAddress::Some(buyer.clone());
+        DataKey::Milestones(proposal_id.clone()).set(&env, &plan);
+
+        set_proposal_status(&env, &mut propose, ProposalStatus::Picked);
+        DataKey::Proposal(proposal_id.clone()).set(&env, &propose);
+
+        // Emit the MilestonesFunded event
+        EscrowEvent::MilestonesFunded(proposal_id, buyer, funds).publish(&env);
+    }
+
+    /**
+     * Register on the oracle the signature process of the next milestone of a
+     * funded proposal. The milestones are signed one after the other, so the
+     * previous one has to be released first. Only the buyer can register it.
+     */
+    pub fn register_milestone(env: Env, proposal_id: String, signaturit_id: String) {
+        check_initialization(&env);
+        check_not_paused(&env);
+
+        let mut propose: EscrowProposal = get_proposal(&env, proposal_id.clone());
+
+        if propose.mode != ProposalMode::Milestones {
+            panic_with_error!(&env, EscrowError::NotMilestonesProposal);
+        }
+
+        let mut plan = get_milestone_plan(&env, proposal_id.clone());
+
+        let buyer = match plan.buyer.clone() {
+            NullableAddress::Some(buyer) => buyer,
+            NullableAddress::None => panic_with_error!(&env, EscrowError::MilestonesNotFunded),
+        };
+
+        buyer.require_auth();
+
+        if DataKey::SignatureProcess(signaturit_id.clone()).has(&env) {
+            panic_with_error!(&env, EscrowError::SignatureProcessExist);
+        }
+
+        if plan
+            .milestones
+            .iter()
+            .any(|milestone| milestone.status == MilestoneStatus::Progress)
+        {
+            panic_with_error!(&env, EscrowError::MilestoneInProgress);
+        }
+
+        let index = match plan
+            .milestones
+            .iter()
+            .position(|milestone| milestone.status == MilestoneStatus::Pending)
+        {
+            Some(index) => index as u32,
+            None => panic_with_error!(&env, EscrowError::NoPendingMilestone),
+        };
+
+        let mut milestone = plan.milestones.get(index).unwrap();
+
+        register_signature_process(
+            &env,
+            &mut propose,
+            signaturit_id.clone(),
+            buyer,
+            milestone.amount,
+        );
+
+        milestone.signaturit_id = NullableString::Some(signaturit_id);
+        milestone.status = MilestoneStatus::Progress;
+        plan.milestones.set(index, milestone);
+        DataKey::Milestones(proposal_id).set(&env, &plan);
+    }
+
+    /**
+     * Open an auction for a proposal in auction mode, accepting bids from the
+     * `reserve_price` until the `end_ledger`. It also reopens the auction of a
+     * proposal whose signature failed, discarding the previous bids, and an
+     * auction that ended without bids. Only the owner can open it.
+     */
+    pub fn open_auction(env: Env, proposal_id: String, reserve_price: i128, end_ledger: u32) {
+        check_initialization(&env);
+        check_not_paused(&env);
+
+        let propose: EscrowProposal = get_proposal(&env, proposal_id.clone());
+
+        propose.owner.require_auth();
+
+        if propose.mode != ProposalMode::Auction {
+            panic_with_error!(&env, EscrowError::NotAuctionProposal);
+        }
+
+        if propose.status != ProposalStatus::Actived {
+            panic_with_error!(&env, EscrowError::PickedOrCanceled);
+        }
+
+        if reserve_price <= 0 || end_ledger <= env.ledger().sequence() {
+            panic_with_error!(&env, EscrowError::InvalidAuction);
+        }
+
+        if DataKey::Auction(proposal_id.clone()).has(&env) {
+            let auction = get_auction(&env, proposal_id.clone());
+            let ended_without_bids =
+                auction.bids.is_empty() && env.ledger().sequence() > auction.end_ledger;
+
+            if auction.status == AuctionStatus::Open && !ended_without_bids {
+                panic_with_error!(&env, EscrowError::AuctionOpen);
+            }
+        }
+
+        let auction = Auction {
+            reserve_price,
+            end_ledger,
+            status: AuctionStatus::Open,
+            bids: Vec::new(&env),
+            offered_to: NullableAddress::None,
+        };
+
+        DataKey::Auction(proposal_id.clone()).set(&env, &auction);
+
+        // Emit the AuctionOpened event
+        EscrowEvent::AuctionOpened(proposal_id, reserve_price, end_ledger).publish(&env);
+    }
+
+    /**
+     * Bid for a proposal on auction. The funds are held by the escrow while
+     * the bid is the highest one, and returned when a higher bid is placed.
+     */
+    pub fn bid(env: Env, proposal_id: String, bidder: Address, amount: i128) {
+        check_initialization(&env);
+        check_not_paused(&env);
+
+        bidder.require_auth();
+
+        let propose: EscrowProposal = get_proposal(&env, proposal_id.clone());
+
+        check_not_expired(&env, &propose);
+
+        if propose.mode != ProposalMode::Auction {
+            panic_with_error!(&env, EscrowError::NotAuctionProposal);
+        }
+
+        if propose.status != ProposalStatus::Actived {
+            panic_with_error!(&env, EscrowError::PickedOrCanceled);
+        }
+
+        let mut auction = get_auction(&env, proposal_id.clone());
+
+        if auction.status != AuctionStatus::Open || env.ledger().sequence() > auction.end_ledger {
+            panic_with_error!(&env, EscrowError::AuctionNotOpen);
+        }
+
+        let is_highest = match auction.bids.last() {
+            Some(top_bid) => amount > top_bid.amount,
+            None => true,
+        };
+
+        if amount < auction.reserve_price || !is_highest {
+            panic_with_error!(&env, EscrowError::BidTooLow);
+        }
 
-    DataKey::SignatureProcess(signaturit_id).get(&env).unwrap()
-}
+        transfer_in(&env, &propose.asset, &bidder, &amount);
 
-#[contract]
-pub struct EscrowContract;
+        // The previous highest bidder gets the funds back
+        refund_top_bid(&env, &propose, &auction);
 
-#[contractimpl]
-impl EscrowContract {
-    pub fn get_oracle(env: Env) -> Address {
-        check_initialization(&env);
-        get_oracle(&env)
-    }
+        auction.bids.push_back(Bid {
+            bidder: bidder.clone(),
+            amount,
+        });
+        DataKey::Auction(proposal_id.clone()).set(&env, &auction);
 
-    pub fn get_asset(env: Env) -> Address {
-        check_initialization(&env);
-        get_asset(&env)
+        // Emit the BidPlaced event
+        EscrowEvent::BidPlaced(proposal_id, bidder, amount).publish(&env);
     }
 
-    pub fn get_nft_notes(env: Env) -> Address {
+    /**
+     * Close an ended auction and register the signature process of the
+     * highest bid, as with `register_escrow`. Only the owner can close it.
+     */
+    pub fn close_auction(env: Env, proposal_id: String, signaturit_id: String) {
         check_initialization(&env);
-        get_nft(&env)
-    }
+        check_not_paused(&env);
 
-    pub fn get_proposal(env: Env, escrow_id: String) -> EscrowProposal {
-        get_proposal(&env, escrow_id)
-    }
+        let mut propose: EscrowProposal = get_proposal(&env, proposal_id.clone());
 
-    pub fn get_signature_tx_escrow(env: Env, signaturit_id: String) -> SignatureTxEscrow {
-        get_signature_tx_escrow(&env, signaturit_id)
-    }
+        propose.owner.require_auth();
 
-    pub fn initialize(
-        env: Env,
-        asset_address: Address,
-        oracle_address: Address,
-        nft_notes_address: Address,
-    ) {
-        if DataKey::AssetAddress.has(&env)
-            && DataKey::OracleAddress.has(&env)
-            && DataKey::NFTNotesAddress.has(&env)
-        {
-            panic_with_error!(env, EscrowError::AlreadyInitialized);
+        if propose.mode != ProposalMode::Auction {
+            panic_with_error!(&env, EscrowError::NotAuctionProposal);
         }
 
-        DataKey::AssetAddress.set(&env, &asset_address);
-        DataKey::OracleAddress.set(&env, &oracle_address);
-        DataKey::NFTNotesAddress.set(&env, &nft_notes_address);
+        if propose.status != ProposalStatus::Actived {
+            panic_with_error!(&env, EscrowError::PickedOrCanceled);
+        }
 
-        // Emit the Initialized event
-        EscrowEvent::Initialized(asset_address, oracle_address, nft_notes_address).publish(&env);
+        check_not_expired(&env, &propose);
+
+        let mut auction = get_auction(&env, proposal_id.clone());
+
+        if auction.status != AuctionStatus::Open {
+            panic_with_error!(&env, EscrowError::AuctionNotOpen);
+        }
+
+        if env.ledger().sequence() <= auction.end_ledger {
+            panic_with_error!(&env, EscrowError::AuctionNotEnded);
+        }
+
+        if DataKey::SignatureProcess(signaturit_id.clone()).has(&env) {
+            panic_with_error!(&env, EscrowError::SignatureProcessExist);
+        }
+
+        let winner = match auction.bids.last() {
+            Some(top_bid) => top_bid,
+            None => panic_with_error!(&env, EscrowError::NoBids),
+        };
+
+        auction.status = AuctionStatus::Closed;
+        DataKey::Auction(proposal_id.clone()).set(&env, &auction);
+
+        register_signature_process(
+            &env,
+            &mut propose,
+            signaturit_id,
+            winner.bidder.clone(),
+            winner.amount,
+        );
+
+        // Emit the AuctionClosed event
+        EscrowEvent::AuctionClosed(proposal_id, winner.bidder, winner.amount).publish(&env);
     }
 
     /**
-     * Register a new proposal to be picked,
+     * Offer the deal to the next highest bidder after the signature of the
+     * previous one failed (or the offer was not accepted). Only the owner can
+     * offer it.
      */
-    pub fn add_proposal(
-        env: Env,
-        stocken_proposal_id: String,
-        proposer_address: Address,
-        min_funds: i128,
-    ) {
+    pub fn offer_to_next_bidder(env: Env, proposal_id: String) {
         check_initialization(&env);
 
-        if DataKey::Proposal(stocken_proposal_id.clone()).has(&env) {
-            panic_with_error!(&env, EscrowError::AlreadyProposed);
+        let propose: EscrowProposal = get_proposal(&env, proposal_id.clone());
+
+        propose.owner.require_auth();
+
+        if propose.mode != ProposalMode::Auction {
+            panic_with_error!(&env, EscrowError::NotAuctionProposal);
         }
 
-        let propose = EscrowProposal {
-            escrow_id: stocken_proposal_id.clone(),
-            owner: proposer_address.clone(),
-            status: ProposalStatus::Actived,
-            min_funds,
-            signature_tx_linked: NullableString::None,
+        if propose.status != ProposalStatus::Actived {
+            panic_with_error!(&env, EscrowError::PickedOrCanceled);
+        }
+
+        let mut auction = get_auction(&env, proposal_id.clone());
+
+        if auction.status == AuctionStatus::Open {
+            panic_with_error!(&env, EscrowError::AuctionOpen);
+        }
+
+        // Discard the bid that was not signed
+        auction.bids.pop_back();
+
+        let next_bid = match auction.bids.last() {
+            Some(next_bid) => next_bid,
+            None => panic_with_error!(&env, EscrowError::NoBids),
         };
 
-        // Save the proposal
-        DataKey::Proposal(stocken_proposal_id).set(&env, &propose);
+        auction.offered_to = NullableAddress::Some(next_bid.bidder.clone());
+        DataKey::Auction(proposal_id.clone()).set(&env, &auction);
 
-        // Emit the NewProposal event
-        EscrowEvent::NewProposal(propose.escrow_id, propose.owner).publish(&env);
+        // Emit the AuctionOffered event
+        EscrowEvent::AuctionOffered(proposal_id, next_bid.bidder, next_bid.amount).publish(&env);
     }
 
-    pub fn register_escrow(
-        env: Env,
-        proposal_id: String,
-        signaturit_id: String,
-        sender_id: Address,
-        funds: i128,
-    ) {
+    /**
+     * Accept the deal offered after the auction, giving the funds of the bid
+     * and registering its signature process. Only the bidder offered can
+     * accept it.
+     */
+    pub fn accept_offer(env: Env, proposal_id: String, signaturit_id: String) {
         check_initialization(&env);
+        check_not_paused(&env);
 
         let mut propose: EscrowProposal = get_proposal(&env, proposal_id.clone());
 
-        if DataKey::SignatureProcess(signaturit_id.clone()).has(&env) {
-            panic_with_error!(&env, EscrowError::SignatureProcessExist);
+        if propose.mode != ProposalMode::Auction {
+            panic_with_error!(&env, EscrowError::NotAuctionProposal);
         }
 
         if propose.status != ProposalStatus::Actived {
             panic_with_error!(&env, EscrowError::PickedOrCanceled);
         }
 
-        if funds < propose.min_funds {
-            panic_with_error!(&env, EscrowError::NoEnoughtFunds);
+        check_not_expired(&env, &propose);
+
+        let mut auction = get_auction(&env, proposal_id.clone());
+
+        let bidder = match auction.offered_to.clone() {
+            NullableAddress::Some(bidder) => bidder,
+            NullableAddress::None => panic_with_error!(&env, EscrowError::NoOffer),
+        };
+
+        bidder.require_auth();
+
+        if DataKey::SignatureProcess(signaturit_id.clone()).has(&env) {
+            panic_with_error!(&env, EscrowError::SignatureProcessExist);
+        }
+
+        let amount = auction.bids.last().unwrap().amount;
+
+        transfer_in(&env, &propose.asset, &bidder, &amount);
+
+        auction.offered_to = NullableAddress::None;
+        DataKey::Auction(proposal_id).set(&env, &auction);
+
+        register_signature_process(&env, &mut propose, signaturit_id, bidder, amount);
+    }
+
+    /**
+     * Ask the oracle to cancel the signature process of a picked proposal.
+     * Only the buyer (any contributor on pooled proposals) can ask for it. If
+     * the process is canceled, the oracle will answer with `failed_signature`
+     * and the funds will be returned. If not, it will answer with
+     * `rejected_cancel` and the cancel can be requested again.
+     */
+    pub fn request_cancel(env: Env, signaturit_id: String, caller: Address) {
+        check_initialization(&env);
+
+        let mut signature_process = get_signature_tx_escrow(&env, signaturit_id.clone());
+        let propose = get_proposal(&env, signature_process.propose_id.clone());
+
+        require_buyer_auth(&env, &signature_process, &propose, &caller);
+
+        if signature_process.status != SignatureStatus::Progress {
+            panic_with_error!(&env, EscrowError::SignatureNotInProgress);
         }
 
-        transfer_funds(&env, &sender_id, &env.current_contract_address(), &funds);
+        if signature_process.cancel_oracle_id.is_some() {
+            panic_with_error!(&env, EscrowError::CancelAlreadyRequested);
+        }
 
         // Get the oracle client
         let oracle_client = oracle::OracleClient::new(&env, &get_oracle(&env));
@@ -180,141 +2922,281 @@ impl EscrowContract {
             InvokerContractAuthEntry::Contract(SubContractInvocation {
                 context: ContractContext {
                     contract: get_oracle(&env),
-                    fn_name: Symbol::new(&env, "register_new_signature_process"),
+                    fn_name: Symbol::new(&env, "request_cancel"),
                     args: (env.current_contract_address(), signaturit_id.clone()).into_val(&env),
                 },
                 sub_invocations: vec![&env],
             }),
         ]);
 
-        // Get the oracle id for this process signature
-        let oracle_id = oracle_client
-            .register_new_signature_process(&env.current_contract_address(), &signaturit_id);
+        // Get the oracle id for this cancel request
+        let cancel_oracle_id =
+            oracle_client.request_cancel(&env.current_contract_address(), &signaturit_id);
 
-        let tx_register = SignatureTxEscrow {
-            id: signaturit_id.clone(),
-            propose_id: propose.escrow_id.clone(),
-            oracle_id,
-            buyer: sender_id,
-            receiver: propose.owner.clone(),
-            funds,
-            status: SignatureStatus::Progress,
-            nft_proof_id: None,
+        signature_process.cancel_oracle_id = Some(cancel_oracle_id);
+        DataKey::SignatureProcess(signaturit_id.clone()).set(&env, &signature_process);
+        DataKey::OracleIdToEscrow(get_oracle(&env), cancel_oracle_id).set(&env, &signaturit_id);
+
+        // Emit the CancelRequested event
+        EscrowEvent::CancelRequested(
+            signature_process.id,
+            signature_process.propose_id,
+            cancel_oracle_id,
+            signature_process.buyer,
+        )
+        .publish(&env);
+    }
+
+    /**
+     * Dispute a completed signature while its funds are held. Any party of the
+     * signature (the receiver, the buyer or a contributor) can open it, and
+     * the arbitrator decides how the funds are released.
+     */
+    pub fn open_dispute(env: Env, signaturit_id: String, caller: Address) {
+        check_initialization(&env);
+
+        caller.require_auth();
+
+        let mut signature_process = get_signature_tx_escrow(&env, signaturit_id.clone());
+        let propose = get_proposal(&env, signature_process.propose_id.clone());
+
+        if signature_process.status != SignatureStatus::PendingRelease {
+            panic_with_error!(&env, EscrowError::NotPendingRelease);
+        }
+
+        if !is_party(&env, &signature_process, &propose, &caller) {
+            panic_with_error!(&env, EscrowError::NotAParty);
+        }
+
+        if get_arbitrator(&env).is_none() {
+            panic_with_error!(&env, EscrowError::NoArbitrator);
+        }
+
+        let mut pending_release = get_pending_release(&env, signaturit_id.clone());
+
+        if env.ledger().sequence() > pending_release.release_ledger {
+            panic_with_error!(&env, EscrowError::DisputeWindowClosed);
+        }
+
+        pending_release.disputed_by = NullableAddress::Some(caller.clone());
+        DataKey::PendingRelease(signaturit_id.clone()).set(&env, &pending_release);
+
+        signature_process.status = SignatureStatus::Disputed;
+        DataKey::SignatureProcess(signaturit_id).set(&env, &signature_process);
+
+        // Emit the DisputeOpened event
+        EscrowEvent::DisputeOpened(signature_process.id, signature_process.propose_id, caller)
+            .publish(&env);
+    }
+
+    /**
+     * Settle a disputed signature. The receiver gets `receiver_share` of the
+     * funds (minus the platform fee) and the rest is returned to the buyer.
+     * With a `0` share the signature is treated as failed, otherwise the notes
+     * are minted as with any completed signature. Only the arbitrator can
+     * resolve it.
+     */
+    pub fn resolve_dispute(env: Env, signaturit_id: String, receiver_share: i128) {
+        check_initialization(&env);
+
+        let arbitrator = match get_arbitrator(&env) {
+            Some(arbitrator) => arbitrator,
+            None => panic_with_error!(&env, EscrowError::NoArbitrator),
         };
 
-        // This way, the propose can be picked just once per time
-        propose.status = ProposalStatus::Picked;
-        propose.signature_tx_linked = NullableString::Some(signaturit_id.clone());
-        DataKey::Proposal(proposal_id).set(&env, &propose);
-        DataKey::SignatureProcess(signaturit_id).set(&env, &tx_register);
-
-        // Emit the RegisterEscrow event
-        EscrowEvent::RegisterEscrow(
-            tx_register.id,
-            tx_register.propose_id,
-            tx_register.oracle_id,
-            tx_register.buyer,
-            tx_register.funds,
+        arbitrator.require_auth();
+
+        let mut signature_process = get_signature_tx_escrow(&env, signaturit_id.clone());
+        let mut propose = get_proposal(&env, signature_process.propose_id.clone());
+
+        if signature_process.status != SignatureStatus::Disputed {
+            panic_with_error!(&env, EscrowError::NotDisputed);
+        }
+
+        if receiver_share < 0 || receiver_share > signature_process.funds {
+            panic_with_error!(&env, EscrowError::InvalidAmount);
+        }
+
+        let pending_release = get_pending_release(&env, signaturit_id.clone());
+        DataKey::PendingRelease(signaturit_id).remove(&env);
+
+        if receiver_share == 0 {
+            refund_signature_tx(
+                &env,
+                &mut signature_process,
+                &mut propose,
+                SignatureStatus::Canceled,
+            );
+        } else {
+            settle_signature(
+                &env,
+                &mut signature_process,
+                &mut propose,
+                &pending_release.document_hash,
+                receiver_share,
+            );
+        }
+
+        // Emit the DisputeResolved event
+        EscrowEvent::DisputeResolved(
+            signature_process.id,
+            signature_process.propose_id,
+            arbitrator,
+            receiver_share,
+            signature_process.funds - receiver_share,
         )
         .publish(&env);
     }
-}
 
-#[contractimpl]
-impl OracleConsumer for EscrowContract {
-    fn completed_signature(env: Env, signaturit_id: String, document_hash: String) {
+    /**
+     * Release the funds of a completed signature once its dispute window ended
+     * without disputes. Anyone can call it.
+     */
+    pub fn finalize(env: Env, signaturit_id: String) {
         check_initialization(&env);
-        get_oracle(&env).require_auth();
 
         let mut signature_process = get_signature_tx_escrow(&env, signaturit_id.clone());
+        let mut propose = get_proposal(&env, signature_process.propose_id.clone());
 
-        let mut propose = get_proposal(&env, signature_process.clone().propose_id);
+        if signature_process.status != SignatureStatus::PendingRelease {
+            panic_with_error!(&env, EscrowError::NotPendingRelease);
+        }
 
-        // Release the funds to the owner of the propose
-        env.authorize_as_current_contract(vec![
-            &env,
-            InvokerContractAuthEntry::Contract(SubContractInvocation {
-                context: ContractContext {
-                    contract: get_asset(&env),
-                    fn_name: Symbol::new(&env, "transfer"),
-                    args: (
-                        env.current_contract_address(),
-                        signature_process.receiver.clone(),
-                        signature_process.funds.clone(),
-                    )
-                        .into_val(&env),
-                },
-                sub_invocations: vec![&env],
-            }),
-        ]);
-        transfer_funds(
+        let pending_release = get_pending_release(&env, signaturit_id.clone());
+
+        if env.ledger().sequence() <= pending_release.release_ledger {
+            panic_with_error!(&env, EscrowError::DisputeWindowOpen);
+        }
+
+        DataKey::PendingRelease(signaturit_id).remove(&env);
+
+        let funds = signature_process.funds;
+        settle_signature(
             &env,
-            &env.current_contract_address(), // from
-            &signature_process.receiver,     // to
-            &signature_process.funds,        // amount
+            &mut signature_process,
+            &mut propose,
+            &pending_release.document_hash,
+            funds,
         );
+    }
 
-        // Get the NFT client
-        let nft_client = notes_nft::NotesNFTClient::new(&env, &get_nft(&env));
+    /**
+     * Return the funds to the buyer (or to the contributors) when the oracle
+     * did not answer before the deadline of the signature process. The
+     * proposal can be picked again and any later response from the oracle
+     * will be rejected. Only the buyer can reclaim them, but anyone can on
+     * pooled proposals, since the funds go back to every contributor.
+     */
+    pub fn reclaim_funds(env: Env, signaturit_id: String) {
+        check_initialization(&env);
 
-        // Grant auth for calling the function
-        // Mint to NFT for the "buyer" address since is the address that gave the funds
-        env.authorize_as_current_contract(vec![
-            &env,
-            InvokerContractAuthEntry::Contract(SubContractInvocation {
-                context: ContractContext {
-                    contract: get_nft(&env),
-                    fn_name: Symbol::new(&env, "mint"),
-                    args: (signature_process.buyer.clone(), document_hash.clone()).into_val(&env),
-                },
-                sub_invocations: vec![&env],
-            }),
-        ]);
+        let mut signature_process = get_signature_tx_escrow(&env, signaturit_id);
+        let mut propose = get_proposal(&env, signature_process.propose_id.clone());
+
+        if propose.mode != ProposalMode::Pooled {
+            signature_process.buyer.require_auth();
+        }
 
-        // NFT ID minted
-        let token_id_minted: u32 = nft_client.mint(&signature_process.buyer, &document_hash);
+        if signature_process.status != SignatureStatus::Progress {
+            panic_with_error!(&env, EscrowError::SignatureNotInProgress);
+        }
 
-        signature_process.nft_proof_id = Some(token_id_minted);
-        signature_process.status = SignatureStatus::Completed;
-        DataKey::SignatureProcess(signaturit_id.clone()).set(&env, &signature_process);
+        if env.ledger().sequence() <= signature_process.deadline_ledger {
+            panic_with_error!(&env, EscrowError::DeadlineNotReached);
+        }
 
-        propose.status = ProposalStatus::Completed;
-        DataKey::Proposal(propose.escrow_id.clone()).set(&env, &propose);
+        refund_signature_tx(
+            &env,
+            &mut signature_process,
+            &mut propose,
+            SignatureStatus::Expired,
+        );
 
-        // Emit the SignedCompleted event
-        EscrowEvent::SignedCompleted(
+        // Emit the FundsReclaimed event
+        EscrowEvent::FundsReclaimed(
             signature_process.id,
             signature_process.propose_id,
             signature_process.buyer,
-            signature_process.receiver,
             signature_process.funds,
-            token_id_minted,
         )
         .publish(&env);
     }
+}
 
-    fn failed_signature(env: Env, signaturit_id: String) {
+#[contractimpl]
+impl OracleConsumer for EscrowContract {
+    fn completed_signature(env: Env, signaturit_id: String, document_hash: String) {
         check_initialization(&env);
         get_oracle(&env).require_auth();
 
         let mut signature_process = get_signature_tx_escrow(&env, signaturit_id.clone());
 
+        if signature_process.status != SignatureStatus::Progress {
+            panic_with_error!(&env, EscrowError::SignatureNotInProgress);
+        }
+
         let mut propose = get_proposal(&env, signature_process.clone().propose_id);
 
-        // Return the funds to the address that picked the propose
-        transfer_funds(
+        let dispute_window = get_dispute_window(&env);
+
+        if dispute_window > 0 {
+            // Hold the funds until the dispute window ends
+            let pending_release = PendingRelease {
+                document_hash,
+                // A window chosen too large holds the funds until a dispute
+                // is resolved instead of overflowing
+                release_ledger: env.ledger().sequence().saturating_add(dispute_window),
+                disputed_by: NullableAddress::None,
+            };
+            DataKey::PendingRelease(signaturit_id.clone()).set(&env, &pending_release);
+
+            signature_process.status = SignatureStatus::PendingRelease;
+            DataKey::SignatureProcess(signaturit_id).set(&env, &signature_process);
+
+            // Emit the ReleasePending event
+            EscrowEvent::ReleasePending(
+                signature_process.id,
+                signature_process.propose_id,
+                pending_release.release_ledger,
+            )
+            .publish(&env);
+
+            return;
+        }
+
+        let funds = signature_process.funds;
+        settle_signature(
             &env,
-            &env.current_contract_address(), // from
-            &signature_process.buyer,        // to
-            &signature_process.funds,        // amount
+            &mut signature_process,
+            &mut propose,
+            &document_hash,
+            funds,
         );
+    }
 
-        signature_process.status = SignatureStatus::Canceled;
-        DataKey::SignatureProcess(signature_process.id.clone()).set(&env, &signature_process);
+    fn failed_signature(env: Env, signaturit_id: String, reason: FailureReason) {
+        check_initialization(&env);
+        get_oracle(&env).require_auth();
 
-        propose.status = ProposalStatus::Actived;
-        propose.signature_tx_linked = NullableString::None;
-        DataKey::Proposal(propose.escrow_id.clone()).set(&env, &propose);
+        let mut signature_process = get_signature_tx_escrow(&env, signaturit_id.clone());
+
+        if signature_process.status != SignatureStatus::Progress {
+            panic_with_error!(&env, EscrowError::SignatureNotInProgress);
+        }
+
+        let mut propose = get_proposal(&env, signature_process.clone().propose_id);
+
+        // The owner loses the bond if declined to sign
+        if reason == FailureReason::ReceiverDeclined {
+            forfeit_bond(&env, &signature_process, &mut propose);
+        }
+
+        refund_signature_tx(
+            &env,
+            &mut signature_process,
+            &mut propose,
+            SignatureStatus::Canceled,
+        );
 
         // Emit the SignedFailed event
         EscrowEvent::SignedFailed(
@@ -324,6 +3206,31 @@ impl OracleConsumer for EscrowContract {
         )
         .publish(&env);
     }
+
+    fn rejected_cancel(env: Env, signaturit_id: String) {
+        check_initialization(&env);
+        get_oracle(&env).require_auth();
+
+        let mut signature_process = get_signature_tx_escrow(&env, signaturit_id.clone());
+
+        let cancel_oracle_id = match signature_process.cancel_oracle_id {
+            Some(cancel_oracle_id) => cancel_oracle_id,
+            None => return,
+        };
+
+        // The buyer can ask for the cancel again
+        signature_process.cancel_oracle_id = None;
+        DataKey::SignatureProcess(signaturit_id).set(&env, &signature_process);
+
+        // Emit the CancelRejected event
+        EscrowEvent::CancelRejected(
+            signature_process.id,
+            signature_process.propose_id,
+            cancel_oracle_id,
+            signature_process.buyer,
+        )
+        .publish(&env);
+    }
 }
 
 mod test;
@@ -0,0 +1,40 @@
+use soroban_sdk::{Env, String};
+use storage::Storage;
+
+use crate::types::DataKey;
+use crate::SCHEMA_VERSION;
+
+/**
+ * Bring a stored proposal from its schema version to the one of the current
+ * code. Each proposal keeps the version it was migrated to, so the migration
+ * can be split over several calls and a proposal given twice is skipped.
+ *
+ * The steps of a new schema version go here, oldest first. A step can only
+ * read the record it migrates, since the records are given in any order.
+ */
+pub fn migrate_proposal(env: &Env, proposal_id: String, schema_version: u32) {
+    let key = DataKey::ProposalSchema(proposal_id);
+    let from_version: u32 = key.get(env).unwrap_or(schema_version);
+
+    if from_version >= SCHEMA_VERSION {
+        return;
+    }
+
+    key.set(env, &SCHEMA_VERSION);
+}
+
+/**
+ * Bring a stored signature process from its schema version to the one of the
+ * current code, as `migrate_proposal`. A signature process can be migrated
+ * before its proposal, so its steps can not read the proposal.
+ */
+pub fn migrate_signature_process(env: &Env, signaturit_id: String, schema_version: u32) {
+    let key = DataKey::SignatureProcessSchema(signaturit_id);
+    let from_version: u32 = key.get(env).unwrap_or(schema_version);
+
+    if from_version >= SCHEMA_VERSION {
+        return;
+    }
+
+    key.set(env, &SCHEMA_VERSION);
+}
@@ -1,12 +1,14 @@
 #![cfg(test)]
 
 extern crate std;
+use std::string::ToString;
 
 use soroban_sdk::{
-    testutils::Address as _,
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
     token::{Client as TokenClient, StellarAssetClient},
-    Address, Env, String,
+    Address, Env, IntoVal, String,
 };
+use uuid::Uuid;
 
 pub mod oracle {
     soroban_sdk::contractimport!(
@@ -22,6 +24,7 @@ fn create_oracle_contract<'a>(env: &Env) -> OracleClient<'a> {
     contract_client
 }
 
+#[allow(clippy::too_many_arguments)]
 pub mod escrow {
     soroban_sdk::contractimport!(file = "../../target/wasm32-unknown-unknown/release/escrow.wasm");
     pub type EscrowClient<'a> = Client<'a>;
@@ -62,6 +65,19 @@ pub const STOCKEN_ID_1: &str = "6ef7e237bbddb133bb3504cad9e2ec7ff90c0c9b63567a63
 // keccak256(STOCKEN_ID_2)
 pub const STOCKEN_ID_2: &str = "af8f0b8ba4749d7a83edcd03a18e3ee3807fca630f8a18e8e59be53ea15c9e95";
 
+// Ledgers to wait for the oracle before the buyer can reclaim the funds
+pub const SIGNATURE_TIMEOUT: u32 = 17_280;
+
+// Terms of a proposal without a timeout, expiration, bond nor delivery
+pub fn no_terms() -> escrow::ProposalTerms {
+    escrow::ProposalTerms {
+        signature_timeout: None,
+        expires_at_ledger: None,
+        bond_bps: 0,
+        delivery: escrow::NullableDelivery::None,
+    }
+}
+
 pub struct EscrowTest<'a> {
     env: Env,
     escrow: EscrowClient<'a>,
@@ -71,6 +87,7 @@ pub struct EscrowTest<'a> {
     alice: Address,
     bob: Address,
     admin: Address,
+    treasury: Address,
 }
 
 impl<'a> EscrowTest<'a> {
@@ -81,9 +98,13 @@ impl<'a> EscrowTest<'a> {
         test_setup.oracle.initialize(&test_setup.admin);
 
         test_setup.escrow.initialize(
+            &test_setup.admin,
             &test_setup.token.address,
             &test_setup.oracle.address,
             &test_setup.nft_notes.address,
+            &SIGNATURE_TIMEOUT,
+            &test_setup.treasury,
+            &0, // No platform fee
         );
 
         let name = String::from_str(&test_setup.env, "Signaturit Notes NFT");
@@ -99,10 +120,14 @@ impl<'a> EscrowTest<'a> {
     fn setup_non_init() -> Self {
         let env = Env::default();
 
+        // The flows call several contracts, which exceeds the default budget
+        env.budget().reset_unlimited();
+
         // Generate the accounts (users)
         let alice = Address::generate(&env);
         let bob = Address::generate(&env);
         let admin = Address::generate(&env);
+        let treasury = Address::generate(&env);
         assert_ne!(alice, bob);
         assert_ne!(alice, admin);
         assert_ne!(bob, admin);
@@ -130,11 +155,117 @@ impl<'a> EscrowTest<'a> {
             alice,
             bob,
             admin,
+            treasury,
         };
     }
+
+    /**
+     * Add a direct proposal of alice, asking for `min_funds` of the token.
+     */
+    fn add_proposal(&self, stocken_id: &str, min_funds: i128) -> String {
+        let stocken_id = String::from_str(&self.env, stocken_id);
+
+        self.escrow.mock_all_auths().add_proposal(
+            &stocken_id,
+            &self.alice,
+            &self.token.address,
+            &min_funds,
+            &escrow::ProposalMode::Direct,
+            &no_terms(),
+        );
+
+        stocken_id
+    }
+
+    /**
+     * Pick a direct proposal as bob, paying `funds` for the given version of
+     * its terms. Only bob authorizes the call.
+     */
+    fn register_escrow(&self, stocken_id: &String, funds: i128, version: u32) -> String {
+        let signaturit_id = String::from_str(&self.env, &Uuid::new_v4().to_string());
+
+        self.escrow
+            .mock_auths(&[MockAuth {
+                address: &self.bob,
+                invoke: &MockAuthInvoke {
+                    contract: &self.escrow.address,
+                    fn_name: "register_escrow",
+                    args: (
+                        stocken_id.clone(),
+                        signaturit_id.clone(),
+                        self.bob.clone(),
+                        funds,
+                        funds,
+                        version,
+                    )
+                        .into_val(&self.env),
+                    sub_invokes: &[MockAuthInvoke {
+                        contract: &self.token.address,
+                        fn_name: "transfer",
+                        args: (self.bob.clone(), self.escrow.address.clone(), funds)
+                            .into_val(&self.env),
+                        sub_invokes: &[],
+                    }],
+                },
+            }])
+            .register_escrow(
+                stocken_id,
+                &signaturit_id,
+                &self.bob,
+                &funds,
+                &funds,
+                &version,
+            );
+
+        signaturit_id
+    }
+
+    /**
+     * Pick a direct proposal as bob, on its current terms, and sign the
+     * document.
+     */
+    fn complete_signature(&self, stocken_id: &String, funds: i128) -> String {
+        let version = self.escrow.get_proposal(stocken_id).version;
+        let signaturit_id = self.register_escrow(stocken_id, funds, version);
+        let oracle_id = self
+            .escrow
+            .get_signature_tx_escrow(&signaturit_id)
+            .oracle_id;
+
+        let document_hash = Some(String::from_str(&self.env, "Test document hash"));
+        self.oracle
+            .mock_all_auths()
+            .signature_response(&oracle_id, &true, &document_hash, &None);
+
+        signaturit_id
+    }
 }
 
 mod add_proposal;
+mod admin;
+mod allowance;
+mod assets;
+mod auction;
+mod authorization;
+mod beneficiaries;
+mod bond;
+mod cancel_proposal;
+mod delivery;
+mod dispute;
+mod expire;
+mod fee;
+mod history;
 mod initialize;
+mod listing;
+mod lookup;
+mod milestones;
+mod nft_recipients;
+mod pooled;
+mod reclaim_funds;
+mod reconcile;
 mod register;
+mod request_cancel;
 mod signatures_response;
+mod update_proposal;
+mod upgrade;
+mod vesting;
@@ -2,7 +2,10 @@
 
 use crate::{
     events::EscrowEvent,
-    test::{escrow::EscrowError, EscrowTest, STOCKEN_ID_1, STOCKEN_ID_2},
+    test::{
+        escrow::{EscrowError, ProposalMode},
+        no_terms, EscrowTest, STOCKEN_ID_1, STOCKEN_ID_2,
+    },
 };
 use soroban_sdk::{
     testutils::{Address as _, Events},
@@ -16,9 +19,14 @@ fn add_proposal_not_initialized() {
     let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
     let amount_asked: i128 = 10_000_000_000_000_000_000; // 10 tokens
 
-    let res = test
-        .escrow
-        .try_add_proposal(&stocken_id, &test.alice, &amount_asked);
+    let res = test.escrow.try_add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &amount_asked,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
 
     assert_eq!(res, Err(Ok(EscrowError::NotInit.into())));
 }
@@ -30,8 +38,14 @@ fn add_proposal_already_proposed() {
     let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
     let amount_asked: i128 = 10_000_000_000_000_000_000; // 10 tokens
 
-    test.escrow
-        .add_proposal(&stocken_id, &test.alice, &amount_asked);
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &amount_asked,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
 
     let event_expected = (
         test.escrow.address.clone(),
@@ -48,9 +62,14 @@ fn add_proposal_already_proposed() {
     let same_stocken_id = stocken_id;
     let amount_asked_2: i128 = 25_000_000_000_000_000_000; // 25 tokens
 
-    let res = test
-        .escrow
-        .try_add_proposal(&same_stocken_id, &test.alice, &amount_asked_2);
+    let res = test.escrow.try_add_proposal(
+        &same_stocken_id,
+        &test.alice,
+        &test.token.address,
+        &amount_asked_2,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
 
     assert_eq!(res, Err(Ok(EscrowError::AlreadyProposed.into())));
 }
@@ -62,8 +81,14 @@ fn add_proposal() {
     let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
     let amount_asked: i128 = 10_000_000_000_000_000_000; // 10 tokens
 
-    test.escrow
-        .add_proposal(&stocken_id, &test.alice, &amount_asked);
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &amount_asked,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
 
     let event_expected = (
         test.escrow.address.clone(),
@@ -91,10 +116,22 @@ fn test_add_multiple_proposal() {
     let proposer_address_2 = Address::generate(&test.env);
     let amount_asked_2: i128 = 18_000_000_000_000_000_000;
 
-    test.escrow
-        .add_proposal(&stocken_id_1, &proposer_address_1, &amount_asked_1);
-    test.escrow
-        .add_proposal(&stocken_id_2, &proposer_address_2, &amount_asked_2);
+    test.escrow.add_proposal(
+        &stocken_id_1,
+        &proposer_address_1,
+        &test.token.address,
+        &amount_asked_1,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
+    test.escrow.add_proposal(
+        &stocken_id_2,
+        &proposer_address_2,
+        &test.token.address,
+        &amount_asked_2,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
 
     let event_expected_1 = (
         test.escrow.address.clone(),
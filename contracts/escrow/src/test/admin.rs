@@ -0,0 +1,346 @@
+#![cfg(test)]
+
+extern crate std;
+use core::ops::Mul;
+use std::string::ToString;
+
+use crate::{
+    events::EscrowEvent,
+    test::{
+        escrow::{EscrowError, ProposalMode, SignatureStatus},
+        no_terms, EscrowTest, STOCKEN_ID_1, STOCKEN_ID_2,
+    },
+};
+use soroban_sdk::{
+    testutils::{Address as _, Events, MockAuth, MockAuthInvoke},
+    Address, IntoVal, String,
+};
+use uuid::Uuid;
+
+#[test]
+fn transfer_admin() {
+    let test = EscrowTest::setup();
+
+    let new_admin = Address::generate(&test.env);
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.admin,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "transfer_admin",
+                args: (new_admin.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .transfer_admin(&new_admin);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::AdminTransferStarted(test.admin.clone(), new_admin.clone()).name(),)
+            .into_val(&test.env),
+        (test.admin.clone(), new_admin.clone()).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "AdminTransferStarted event not present"
+    );
+
+    // The admin does not change until the new one accepts
+    assert_eq!(test.escrow.get_admin(), test.admin);
+    assert_eq!(test.escrow.get_pending_admin(), Some(new_admin.clone()));
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &new_admin,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "accept_admin",
+                args: ().into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .accept_admin();
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::AdminTransferred(test.admin.clone(), new_admin.clone()).name(),)
+            .into_val(&test.env),
+        (test.admin.clone(), new_admin.clone()).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "AdminTransferred event not present"
+    );
+
+    assert_eq!(test.escrow.get_admin(), new_admin);
+    assert_eq!(test.escrow.get_pending_admin(), None);
+}
+
+#[test]
+fn accept_admin_not_pending() {
+    let test = EscrowTest::setup();
+
+    let res = test.escrow.mock_all_auths().try_accept_admin();
+
+    assert_eq!(res, Err(Ok(EscrowError::NoPendingAdmin.into())));
+}
+
+#[test]
+fn accept_admin_only_pending() {
+    let test = EscrowTest::setup();
+
+    let new_admin = Address::generate(&test.env);
+
+    test.escrow.mock_all_auths().transfer_admin(&new_admin);
+
+    // Bob is not the pending admin
+    let res = test
+        .escrow
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "accept_admin",
+                args: ().into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_accept_admin();
+
+    assert!(res.is_err(), "calling with non-pending admin not reverted");
+    assert_eq!(test.escrow.get_admin(), test.admin);
+}
+
+#[test]
+fn pause() {
+    let test = EscrowTest::setup();
+
+    // Add and pick a proposal before pausing
+    let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
+    let amount_asked: i128 = 10_000_000_000_000_000_000; // 10 tokens
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &amount_asked,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
+
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+    let amount_to_give: i128 = amount_asked.mul(2);
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "register_escrow",
+                args: (
+                    stocken_id.clone(),
+                    signaturit_id.clone(),
+                    test.bob.clone(),
+                    amount_to_give,
+                    amount_to_give,
+                    1_u32,
+                )
+                    .into_val(&test.env),
+                sub_invokes: &[MockAuthInvoke {
+                    contract: &test.token.address,
+                    fn_name: "transfer",
+                    args: (
+                        test.bob.clone(),
+                        test.escrow.address.clone(),
+                        amount_to_give,
+                    )
+                        .into_val(&test.env),
+                    sub_invokes: &[],
+                }],
+            },
+        }])
+        .register_escrow(
+            &stocken_id,
+            &signaturit_id,
+            &test.bob,
+            &amount_to_give,
+            &amount_to_give,
+            &1,
+        );
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.admin,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "pause",
+                args: ().into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .pause();
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::Paused(test.admin.clone()).name(),).into_val(&test.env),
+        (test.admin.clone(),).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "Paused event not present"
+    );
+
+    assert!(test.escrow.is_paused());
+
+    // New proposals and escrows are blocked
+    let stocken_id_2 = String::from_str(&test.env, STOCKEN_ID_2);
+    let res = test.escrow.try_add_proposal(
+        &stocken_id_2,
+        &test.alice,
+        &test.token.address,
+        &amount_asked,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
+
+    assert_eq!(res, Err(Ok(EscrowError::ContractPaused.into())));
+
+    let res = test.escrow.mock_all_auths().try_register_escrow(
+        &stocken_id,
+        &String::from_str(&test.env, &Uuid::new_v4().to_string()),
+        &test.bob,
+        &amount_to_give,
+        &amount_to_give,
+        &1,
+    );
+
+    assert_eq!(res, Err(Ok(EscrowError::ContractPaused.into())));
+
+    // The pending oracle callback is still settled
+    let oracle_id: u32 = 0;
+    let signature_response = true;
+    let document_hash = Some(String::from_str(&test.env, "Test document hash"));
+
+    test.oracle.mock_all_auths().signature_response(
+        &oracle_id,
+        &signature_response,
+        &document_hash,
+        &None,
+    );
+
+    assert_eq!(
+        test.escrow.get_signature_tx_escrow(&signaturit_id).status,
+        SignatureStatus::Completed
+    );
+
+    test.escrow.mock_all_auths().unpause();
+
+    assert!(!test.escrow.is_paused());
+
+    test.escrow.add_proposal(
+        &stocken_id_2,
+        &test.alice,
+        &test.token.address,
+        &amount_asked,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
+}
+
+#[test]
+fn pause_only_admin() {
+    let test = EscrowTest::setup();
+
+    let res = test
+        .escrow
+        .mock_auths(&[MockAuth {
+            address: &test.alice,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "pause",
+                args: ().into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_pause();
+
+    assert!(res.is_err(), "calling with non-admin not reverted");
+    assert!(!test.escrow.is_paused());
+}
+
+#[test]
+fn set_oracle_and_nft_notes() {
+    let test = EscrowTest::setup();
+
+    let new_oracle = Address::generate(&test.env);
+    let new_nft_notes = Address::generate(&test.env);
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.admin,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "set_oracle",
+                args: (new_oracle.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_oracle(&new_oracle);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::OracleUpdated(new_oracle.clone()).name(),).into_val(&test.env),
+        (new_oracle.clone(),).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "OracleUpdated event not present"
+    );
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.admin,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "set_nft_notes",
+                args: (new_nft_notes.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_nft_notes(&new_nft_notes);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::NFTNotesUpdated(new_nft_notes.clone()).name(),).into_val(&test.env),
+        (new_nft_notes.clone(),).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "NFTNotesUpdated event not present"
+    );
+
+    assert_eq!(test.escrow.get_oracle(), new_oracle);
+    assert_eq!(test.escrow.get_nft_notes(), new_nft_notes);
+
+    // Only the admin can update them
+    let res = test
+        .escrow
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "set_oracle",
+                args: (test.bob.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_set_oracle(&test.bob);
+
+    assert!(res.is_err(), "calling with non-admin not reverted");
+    assert_eq!(test.escrow.get_oracle(), new_oracle);
+}
@@ -0,0 +1,253 @@
+#![cfg(test)]
+
+extern crate std;
+use std::string::ToString;
+
+use crate::test::{
+    escrow::{EscrowError, ProposalMode, ProposalStatus},
+    no_terms, EscrowTest, STOCKEN_ID_1,
+};
+use soroban_sdk::{
+    contract, contractimpl, contracttype,
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    Address, ConversionError, Env, Error, IntoVal, InvokeError, String,
+};
+use uuid::Uuid;
+
+const FUNDS: i128 = 10_000_000_000_000_000_000; // 10 tokens
+
+#[contracttype]
+enum FeeTokenKey {
+    Balance(Address),
+    Allowance(Address, Address),
+}
+
+/**
+ * A token that keeps the 1% of every transfer
+ */
+#[contract]
+struct FeeToken;
+
+#[contractimpl]
+impl FeeToken {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage()
+            .instance()
+            .set(&FeeTokenKey::Balance(to), &(balance + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage()
+            .instance()
+            .get(&FeeTokenKey::Balance(id))
+            .unwrap_or(0)
+    }
+
+    pub fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        env.storage()
+            .instance()
+            .get(&FeeTokenKey::Allowance(from, spender))
+            .unwrap_or(0)
+    }
+
+    pub fn approve(env: Env, from: Address, spender: Address, amount: i128, _expiration: u32) {
+        from.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&FeeTokenKey::Allowance(from, spender), &amount);
+    }
+
+    pub fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        spender.require_auth();
+
+        let allowance = Self::allowance(env.clone(), from.clone(), spender.clone());
+        env.storage().instance().set(
+            &FeeTokenKey::Allowance(from.clone(), spender),
+            &(allowance - amount),
+        );
+
+        let balance_from = Self::balance(env.clone(), from.clone());
+        env.storage()
+            .instance()
+            .set(&FeeTokenKey::Balance(from), &(balance_from - amount));
+
+        Self::mint(env, to, amount - amount / 100);
+    }
+}
+
+fn add_proposal(test: &EscrowTest, asset: &Address) -> String {
+    let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
+
+    test.escrow.mock_all_auths().add_proposal(
+        &stocken_id,
+        &test.alice,
+        asset,
+        &FUNDS,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
+
+    stocken_id
+}
+
+fn add_relayer(test: &EscrowTest) -> Address {
+    let relayer = Address::generate(&test.env);
+
+    test.escrow.mock_all_auths().set_relayer(&relayer);
+
+    relayer
+}
+
+fn register_from_allowance(
+    test: &EscrowTest,
+    relayer: &Address,
+    stocken_id: &String,
+    signaturit_id: &String,
+) -> Result<Result<(), ConversionError>, Result<Error, InvokeError>> {
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: relayer,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "register_escrow_from_allowance",
+                args: (
+                    stocken_id.clone(),
+                    signaturit_id.clone(),
+                    test.bob.clone(),
+                    FUNDS,
+                    FUNDS,
+                    1_u32,
+                )
+                    .into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_register_escrow_from_allowance(
+            stocken_id,
+            signaturit_id,
+            &test.bob,
+            &FUNDS,
+            &FUNDS,
+            &1,
+        )
+}
+
+#[test]
+fn register_escrow_from_allowance() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = add_proposal(&test, &test.token.address);
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+    let relayer = add_relayer(&test);
+
+    // Bob approves the escrow beforehand, and does not sign the registration
+    test.token
+        .mock_all_auths()
+        .approve(&test.bob, &test.escrow.address, &FUNDS, &1_000);
+
+    let balance_before_bob = test.token.balance(&test.bob);
+
+    register_from_allowance(&test, &relayer, &stocken_id, &signaturit_id)
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(test.token.balance(&test.bob), balance_before_bob - FUNDS);
+    assert_eq!(test.token.balance(&test.escrow.address), FUNDS);
+    assert_eq!(test.token.allowance(&test.bob, &test.escrow.address), 0);
+
+    let signature_tx = test.escrow.get_signature_tx_escrow(&signaturit_id);
+    assert_eq!(signature_tx.buyer, test.bob);
+    assert_eq!(signature_tx.funds, FUNDS);
+    assert_eq!(
+        test.escrow.get_proposal(&stocken_id).status,
+        ProposalStatus::Picked
+    );
+}
+
+#[test]
+fn register_escrow_from_allowance_not_approved() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = add_proposal(&test, &test.token.address);
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+    let relayer = add_relayer(&test);
+
+    // The approved amount is below the funds
+    test.token
+        .mock_all_auths()
+        .approve(&test.bob, &test.escrow.address, &(FUNDS - 1), &1_000);
+
+    let res = register_from_allowance(&test, &relayer, &stocken_id, &signaturit_id);
+
+    assert_eq!(res, Err(Ok(EscrowError::NoEnoughtFunds.into())));
+}
+
+#[test]
+fn register_escrow_from_allowance_only_relayer() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = add_proposal(&test, &test.token.address);
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+
+    test.token
+        .mock_all_auths()
+        .approve(&test.bob, &test.escrow.address, &FUNDS, &1_000);
+
+    // Without a relayer no one can spend the allowance
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_register_escrow_from_allowance(
+            &stocken_id,
+            &signaturit_id,
+            &test.bob,
+            &FUNDS,
+            &FUNDS,
+            &1,
+        );
+
+    assert_eq!(res, Err(Ok(EscrowError::NotAParty.into())));
+
+    add_relayer(&test);
+
+    // Alice is not the relayer
+    let res = register_from_allowance(&test, &test.alice, &stocken_id, &signaturit_id);
+
+    assert!(res.is_err(), "calling with non-relayer not reverted");
+
+    assert_eq!(
+        test.escrow.get_proposal(&stocken_id).status,
+        ProposalStatus::Actived
+    );
+}
+
+#[test]
+fn register_escrow_from_allowance_fee_on_transfer() {
+    let test = EscrowTest::setup();
+
+    let fee_token = FeeTokenClient::new(&test.env, &test.env.register_contract(None, FeeToken));
+    fee_token.mint(&test.bob, &FUNDS);
+
+    test.escrow.mock_all_auths().allow_asset(&fee_token.address);
+
+    let stocken_id = add_proposal(&test, &fee_token.address);
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+    let relayer = add_relayer(&test);
+
+    fee_token
+        .mock_all_auths()
+        .approve(&test.bob, &test.escrow.address, &FUNDS, &1_000);
+
+    // The escrow would receive the 99% of the funds
+    let res = register_from_allowance(&test, &relayer, &stocken_id, &signaturit_id);
+
+    assert_eq!(res, Err(Ok(EscrowError::NoEnoughtFunds.into())));
+
+    assert_eq!(fee_token.balance(&test.bob), FUNDS);
+    assert_eq!(
+        test.escrow.get_proposal(&stocken_id).status,
+        ProposalStatus::Actived
+    );
+}
@@ -0,0 +1,226 @@
+#![cfg(test)]
+
+extern crate std;
+use core::ops::Mul;
+use std::string::ToString;
+
+use crate::{
+    events::EscrowEvent,
+    test::{
+        create_token_contract,
+        escrow::{EscrowError, ProposalMode, SignatureStatus},
+        no_terms, EscrowTest, STOCKEN_ID_1,
+    },
+};
+use soroban_sdk::{
+    testutils::{Events, MockAuth, MockAuthInvoke},
+    IntoVal, String,
+};
+use uuid::Uuid;
+
+#[test]
+fn allow_asset() {
+    let test = EscrowTest::setup();
+
+    let (other_token, _) = create_token_contract(&test.env, &test.admin);
+
+    assert!(!test.escrow.is_asset_allowed(&other_token.address));
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.admin,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "allow_asset",
+                args: (other_token.address.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .allow_asset(&other_token.address);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::AssetAllowed(other_token.address.clone()).name(),).into_val(&test.env),
+        (other_token.address.clone(),).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "AssetAllowed event not present"
+    );
+
+    assert!(test.escrow.is_asset_allowed(&other_token.address));
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.admin,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "disallow_asset",
+                args: (other_token.address.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .disallow_asset(&other_token.address);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::AssetDisallowed(other_token.address.clone()).name(),).into_val(&test.env),
+        (other_token.address.clone(),).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "AssetDisallowed event not present"
+    );
+
+    assert!(!test.escrow.is_asset_allowed(&other_token.address));
+}
+
+#[test]
+fn allow_asset_only_admin() {
+    let test = EscrowTest::setup();
+
+    let (other_token, _) = create_token_contract(&test.env, &test.admin);
+
+    let res = test
+        .escrow
+        .mock_auths(&[MockAuth {
+            address: &test.alice,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "allow_asset",
+                args: (other_token.address.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_allow_asset(&other_token.address);
+
+    assert!(res.is_err(), "calling with non-admin not reverted");
+    assert!(!test.escrow.is_asset_allowed(&other_token.address));
+}
+
+#[test]
+fn add_proposal_asset_not_allowed() {
+    let test = EscrowTest::setup();
+
+    let (other_token, _) = create_token_contract(&test.env, &test.admin);
+
+    let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
+    let amount_asked: i128 = 10_000_000_000_000_000_000; // 10 tokens
+
+    let res = test.escrow.try_add_proposal(
+        &stocken_id,
+        &test.alice,
+        &other_token.address,
+        &amount_asked,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
+
+    assert_eq!(res, Err(Ok(EscrowError::AssetNotAllowed.into())));
+}
+
+#[test]
+fn settle_in_proposal_asset() {
+    let test = EscrowTest::setup();
+
+    let (other_token, other_token_admin) = create_token_contract(&test.env, &test.admin);
+    let amount: i128 = 1_000_000_000_000_000_000_000; // 1K tokens (18 decimals)
+    other_token_admin.mock_all_auths().mint(&test.bob, &amount);
+
+    test.escrow
+        .mock_all_auths()
+        .allow_asset(&other_token.address);
+
+    // Add a proposal priced on the other asset
+    let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
+    let amount_asked: i128 = 10_000_000_000_000_000_000; // 10 tokens
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &other_token.address,
+        &amount_asked,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
+
+    assert_eq!(
+        test.escrow.get_proposal(&stocken_id).asset,
+        other_token.address
+    );
+
+    // The asset can be disallowed without affecting the existing proposals
+    test.escrow
+        .mock_all_auths()
+        .disallow_asset(&other_token.address);
+
+    // Pick the proposal
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+    let amount_to_give: i128 = amount_asked.mul(2);
+
+    let balance_before_bob = test.token.balance(&test.bob);
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "register_escrow",
+                args: (
+                    stocken_id.clone(),
+                    signaturit_id.clone(),
+                    test.bob.clone(),
+                    amount_to_give,
+                    amount_to_give,
+                    1_u32,
+                )
+                    .into_val(&test.env),
+                sub_invokes: &[MockAuthInvoke {
+                    contract: &other_token.address,
+                    fn_name: "transfer",
+                    args: (
+                        test.bob.clone(),
+                        test.escrow.address.clone(),
+                        amount_to_give,
+                    )
+                        .into_val(&test.env),
+                    sub_invokes: &[],
+                }],
+            },
+        }])
+        .register_escrow(
+            &stocken_id,
+            &signaturit_id,
+            &test.bob,
+            &amount_to_give,
+            &amount_to_give,
+            &1,
+        );
+
+    assert_eq!(
+        test.escrow.get_signature_tx_escrow(&signaturit_id).asset,
+        other_token.address
+    );
+    assert_eq!(other_token.balance(&test.escrow.address), amount_to_give);
+    assert_eq!(test.token.balance(&test.bob), balance_before_bob);
+
+    // The signature is completed and the owner receive the other asset
+    let oracle_id: u32 = 0;
+    let signature_response = true;
+    let document_hash = Some(String::from_str(&test.env, "Test document hash"));
+
+    test.oracle.mock_all_auths().signature_response(
+        &oracle_id,
+        &signature_response,
+        &document_hash,
+        &None,
+    );
+
+    assert_eq!(
+        test.escrow.get_signature_tx_escrow(&signaturit_id).status,
+        SignatureStatus::Completed
+    );
+    assert_eq!(other_token.balance(&test.alice), amount_to_give);
+    assert_eq!(other_token.balance(&test.escrow.address), 0);
+}
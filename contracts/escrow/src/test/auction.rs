@@ -0,0 +1,577 @@
+#![cfg(test)]
+
+extern crate std;
+use std::string::ToString;
+
+use crate::{
+    events::EscrowEvent,
+    test::{
+        escrow::{AuctionStatus, EscrowError, NullableAddress, ProposalMode, ProposalStatus},
+        no_terms,
+        oracle::FailureReason,
+        EscrowTest, STOCKEN_ID_1,
+    },
+};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger, MockAuth, MockAuthInvoke},
+    token::StellarAssetClient,
+    Address, IntoVal, String,
+};
+use uuid::Uuid;
+
+// Ledgers that the auction accepts bids
+const AUCTION_LEDGERS: u32 = 100;
+
+const RESERVE_PRICE: i128 = 10_000_000_000_000_000_000; // 10 tokens
+
+fn create_bidder(test: &EscrowTest) -> Address {
+    let bidder = Address::generate(&test.env);
+    let amount: i128 = 1_000_000_000_000_000_000_000; // 1K tokens (18 decimals)
+
+    StellarAssetClient::new(&test.env, &test.token.address)
+        .mock_all_auths()
+        .mint(&bidder, &amount);
+
+    bidder
+}
+
+fn open_auction(test: &EscrowTest) -> String {
+    let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &RESERVE_PRICE,
+        &ProposalMode::Auction,
+        &no_terms(),
+    );
+
+    let end_ledger = test.env.ledger().sequence() + AUCTION_LEDGERS;
+    test.escrow
+        .mock_all_auths()
+        .open_auction(&stocken_id, &RESERVE_PRICE, &end_ledger);
+
+    stocken_id
+}
+
+fn end_auction(test: &EscrowTest) {
+    test.env.ledger().with_mut(|ledger| {
+        ledger.sequence_number += AUCTION_LEDGERS + 1;
+    });
+}
+
+fn close_auction(test: &EscrowTest, stocken_id: &String) -> String {
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.alice,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "close_auction",
+                args: (stocken_id.clone(), signaturit_id.clone()).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .close_auction(stocken_id, &signaturit_id);
+
+    signaturit_id
+}
+
+#[test]
+fn open_auction_checks() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &RESERVE_PRICE,
+        &ProposalMode::Auction,
+        &no_terms(),
+    );
+
+    let end_ledger = test.env.ledger().sequence() + AUCTION_LEDGERS;
+
+    // Already ended
+    let res = test.escrow.mock_all_auths().try_open_auction(
+        &stocken_id,
+        &RESERVE_PRICE,
+        &test.env.ledger().sequence(),
+    );
+
+    assert_eq!(res, Err(Ok(EscrowError::InvalidAuction.into())));
+
+    // Only the owner can open it
+    let res = test
+        .escrow
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "open_auction",
+                args: (stocken_id.clone(), RESERVE_PRICE, end_ledger).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_open_auction(&stocken_id, &RESERVE_PRICE, &end_ledger);
+
+    assert!(res.is_err(), "calling with non-owner not reverted");
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.alice,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "open_auction",
+                args: (stocken_id.clone(), RESERVE_PRICE, end_ledger).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .open_auction(&stocken_id, &RESERVE_PRICE, &end_ledger);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::AuctionOpened(stocken_id.clone(), RESERVE_PRICE, end_ledger).name(),)
+            .into_val(&test.env),
+        (stocken_id.clone(), RESERVE_PRICE, end_ledger).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "AuctionOpened event not present"
+    );
+
+    let auction = test.escrow.get_auction(&stocken_id);
+    assert_eq!(auction.status, AuctionStatus::Open);
+    assert_eq!(auction.end_ledger, end_ledger);
+    assert_eq!(auction.bids.len(), 0);
+
+    let res =
+        test.escrow
+            .mock_all_auths()
+            .try_open_auction(&stocken_id, &RESERVE_PRICE, &end_ledger);
+
+    assert_eq!(res, Err(Ok(EscrowError::AuctionOpen.into())));
+}
+
+#[test]
+fn bid_not_auction_proposal() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &RESERVE_PRICE,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
+
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_bid(&stocken_id, &test.bob, &RESERVE_PRICE);
+
+    assert_eq!(res, Err(Ok(EscrowError::NotAuctionProposal.into())));
+}
+
+#[test]
+fn bid_refunds_previous_bidder() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = open_auction(&test);
+    let carol = create_bidder(&test);
+
+    let balance_before_bob = test.token.balance(&test.bob);
+
+    // Under the reserve price
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_bid(&stocken_id, &test.bob, &(RESERVE_PRICE - 1));
+
+    assert_eq!(res, Err(Ok(EscrowError::BidTooLow.into())));
+
+    let bid_bob = RESERVE_PRICE;
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "bid",
+                args: (stocken_id.clone(), test.bob.clone(), bid_bob).into_val(&test.env),
+                sub_invokes: &[MockAuthInvoke {
+                    contract: &test.token.address,
+                    fn_name: "transfer",
+                    args: (test.bob.clone(), test.escrow.address.clone(), bid_bob)
+                        .into_val(&test.env),
+                    sub_invokes: &[],
+                }],
+            },
+        }])
+        .bid(&stocken_id, &test.bob, &bid_bob);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::BidPlaced(stocken_id.clone(), test.bob.clone(), bid_bob).name(),)
+            .into_val(&test.env),
+        (stocken_id.clone(), test.bob.clone(), bid_bob).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "BidPlaced event not present"
+    );
+
+    assert_eq!(test.token.balance(&test.escrow.address), bid_bob);
+
+    // Not higher than the current one
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_bid(&stocken_id, &carol, &bid_bob);
+
+    assert_eq!(res, Err(Ok(EscrowError::BidTooLow.into())));
+
+    let bid_carol = bid_bob + 1;
+    test.escrow
+        .mock_all_auths()
+        .bid(&stocken_id, &carol, &bid_carol);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::BidRefunded(stocken_id.clone(), test.bob.clone(), bid_bob).name(),)
+            .into_val(&test.env),
+        (stocken_id.clone(), test.bob.clone(), bid_bob).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "BidRefunded event not present"
+    );
+
+    // Only the highest bid is held
+    assert_eq!(test.token.balance(&test.bob), balance_before_bob);
+    assert_eq!(test.token.balance(&test.escrow.address), bid_carol);
+    assert_eq!(test.escrow.get_auction(&stocken_id).bids.len(), 2);
+
+    end_auction(&test);
+
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_bid(&stocken_id, &test.bob, &(bid_carol + 1));
+
+    assert_eq!(res, Err(Ok(EscrowError::AuctionNotOpen.into())));
+}
+
+#[test]
+fn close_auction_registers_winner() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = open_auction(&test);
+    let carol = create_bidder(&test);
+
+    let bid_bob = RESERVE_PRICE;
+    let bid_carol = RESERVE_PRICE * 2;
+    test.escrow
+        .mock_all_auths()
+        .bid(&stocken_id, &test.bob, &bid_bob);
+    test.escrow
+        .mock_all_auths()
+        .bid(&stocken_id, &carol, &bid_carol);
+
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_close_auction(&stocken_id, &signaturit_id);
+
+    assert_eq!(res, Err(Ok(EscrowError::AuctionNotEnded.into())));
+
+    end_auction(&test);
+
+    let balance_before_alice = test.token.balance(&test.alice);
+    let signaturit_id = close_auction(&test, &stocken_id);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::AuctionClosed(stocken_id.clone(), carol.clone(), bid_carol).name(),)
+            .into_val(&test.env),
+        (stocken_id.clone(), carol.clone(), bid_carol).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "AuctionClosed event not present"
+    );
+
+    // The winner bid is a normal signature tx escrow
+    let signature_tx = test.escrow.get_signature_tx_escrow(&signaturit_id);
+    assert_eq!(signature_tx.buyer, carol);
+    assert_eq!(signature_tx.funds, bid_carol);
+    assert_eq!(
+        test.escrow.get_proposal(&stocken_id).status,
+        ProposalStatus::Picked
+    );
+    assert_eq!(
+        test.escrow.get_auction(&stocken_id).status,
+        AuctionStatus::Closed
+    );
+
+    let document_hash = Some(String::from_str(&test.env, "Test document hash"));
+    test.oracle
+        .mock_all_auths()
+        .signature_response(&0, &true, &document_hash, &None);
+
+    assert_eq!(
+        test.token.balance(&test.alice),
+        balance_before_alice + bid_carol
+    );
+    assert_eq!(test.nft_notes.owner_of(&0), carol);
+}
+
+#[test]
+fn offer_to_next_bidder() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = open_auction(&test);
+    let carol = create_bidder(&test);
+
+    let bid_bob = RESERVE_PRICE;
+    let bid_carol = RESERVE_PRICE * 2;
+    test.escrow
+        .mock_all_auths()
+        .bid(&stocken_id, &test.bob, &bid_bob);
+    test.escrow
+        .mock_all_auths()
+        .bid(&stocken_id, &carol, &bid_carol);
+
+    end_auction(&test);
+    close_auction(&test, &stocken_id);
+
+    // No offer before the signature fails
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_offer_to_next_bidder(&stocken_id);
+
+    assert_eq!(res, Err(Ok(EscrowError::PickedOrCanceled.into())));
+
+    test.oracle.mock_all_auths().signature_response(
+        &0,
+        &false,
+        &None,
+        &Some(FailureReason::Expired),
+    );
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.alice,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "offer_to_next_bidder",
+                args: (stocken_id.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .offer_to_next_bidder(&stocken_id);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::AuctionOffered(stocken_id.clone(), test.bob.clone(), bid_bob).name(),)
+            .into_val(&test.env),
+        (stocken_id.clone(), test.bob.clone(), bid_bob).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "AuctionOffered event not present"
+    );
+
+    assert_eq!(
+        test.escrow.get_auction(&stocken_id).offered_to,
+        NullableAddress::Some(test.bob.clone())
+    );
+
+    // Only Bob can accept it
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+    let res = test
+        .escrow
+        .mock_auths(&[MockAuth {
+            address: &carol,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "accept_offer",
+                args: (stocken_id.clone(), signaturit_id.clone()).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_accept_offer(&stocken_id, &signaturit_id);
+
+    assert!(res.is_err(), "calling with non-offered bidder not reverted");
+
+    test.escrow
+        .mock_all_auths()
+        .accept_offer(&stocken_id, &signaturit_id);
+
+    let signature_tx = test.escrow.get_signature_tx_escrow(&signaturit_id);
+    assert_eq!(signature_tx.buyer, test.bob);
+    assert_eq!(signature_tx.funds, bid_bob);
+    assert_eq!(test.token.balance(&test.escrow.address), bid_bob);
+
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_accept_offer(&stocken_id, &signaturit_id);
+
+    assert_eq!(res, Err(Ok(EscrowError::PickedOrCanceled.into())));
+}
+
+#[test]
+fn reopen_auction() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = open_auction(&test);
+    test.escrow
+        .mock_all_auths()
+        .bid(&stocken_id, &test.bob, &RESERVE_PRICE);
+
+    end_auction(&test);
+    close_auction(&test, &stocken_id);
+
+    test.oracle.mock_all_auths().signature_response(
+        &0,
+        &false,
+        &None,
+        &Some(FailureReason::Expired),
+    );
+
+    // Nobody else to offer the deal to
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_offer_to_next_bidder(&stocken_id);
+
+    assert_eq!(res, Err(Ok(EscrowError::NoBids.into())));
+
+    let end_ledger = test.env.ledger().sequence() + AUCTION_LEDGERS;
+    test.escrow
+        .mock_all_auths()
+        .open_auction(&stocken_id, &RESERVE_PRICE, &end_ledger);
+
+    let auction = test.escrow.get_auction(&stocken_id);
+    assert_eq!(auction.status, AuctionStatus::Open);
+    assert_eq!(auction.bids.len(), 0);
+}
+
+#[test]
+fn reopen_auction_without_bids() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = open_auction(&test);
+    let end_ledger = test.env.ledger().sequence() + AUCTION_LEDGERS;
+
+    // Still accepting bids
+    let res = test.escrow.mock_all_auths().try_open_auction(
+        &stocken_id,
+        &RESERVE_PRICE,
+        &(end_ledger + AUCTION_LEDGERS),
+    );
+
+    assert_eq!(res, Err(Ok(EscrowError::AuctionOpen.into())));
+
+    end_auction(&test);
+
+    // Nobody bid, so there is no winner to close it with
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_close_auction(&stocken_id, &String::from_str(&test.env, "signaturit"));
+
+    assert_eq!(res, Err(Ok(EscrowError::NoBids.into())));
+
+    let end_ledger = test.env.ledger().sequence() + AUCTION_LEDGERS;
+    test.escrow
+        .mock_all_auths()
+        .open_auction(&stocken_id, &RESERVE_PRICE, &end_ledger);
+
+    let auction = test.escrow.get_auction(&stocken_id);
+    assert_eq!(auction.status, AuctionStatus::Open);
+    assert_eq!(auction.end_ledger, end_ledger);
+
+    test.escrow
+        .mock_all_auths()
+        .bid(&stocken_id, &test.bob, &RESERVE_PRICE);
+    assert_eq!(test.escrow.get_auction(&stocken_id).bids.len(), 1);
+}
+
+#[test]
+fn cancel_refunds_top_bid() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = open_auction(&test);
+
+    let balance_before_bob = test.token.balance(&test.bob);
+    test.escrow
+        .mock_all_auths()
+        .bid(&stocken_id, &test.bob, &RESERVE_PRICE);
+
+    test.escrow.mock_all_auths().cancel_proposal(&stocken_id);
+
+    assert_eq!(test.token.balance(&test.bob), balance_before_bob);
+    assert_eq!(test.token.balance(&test.escrow.address), 0);
+
+    // The refunded bid is not listed anymore
+    let auction = test.escrow.get_auction(&stocken_id);
+    assert_eq!(auction.status, AuctionStatus::Closed);
+    assert_eq!(auction.bids.len(), 0);
+}
+
+#[test]
+fn bid_after_cancel() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = open_auction(&test);
+
+    test.escrow.mock_all_auths().cancel_proposal(&stocken_id);
+
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_bid(&stocken_id, &test.bob, &RESERVE_PRICE);
+
+    assert_eq!(res, Err(Ok(EscrowError::PickedOrCanceled.into())));
+    assert_eq!(test.token.balance(&test.escrow.address), 0);
+}
+
+#[test]
+fn close_auction_after_cancel() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = open_auction(&test);
+
+    test.escrow
+        .mock_all_auths()
+        .bid(&stocken_id, &test.bob, &RESERVE_PRICE);
+
+    test.escrow.mock_all_auths().cancel_proposal(&stocken_id);
+
+    end_auction(&test);
+
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_close_auction(&stocken_id, &signaturit_id);
+
+    assert_eq!(res, Err(Ok(EscrowError::PickedOrCanceled.into())));
+    assert_eq!(
+        test.escrow.get_proposal(&stocken_id).status,
+        ProposalStatus::Canceled
+    );
+}
@@ -0,0 +1,384 @@
+#![cfg(test)]
+
+extern crate std;
+use std::string::ToString;
+
+use crate::{
+    events::EscrowEvent,
+    test::{
+        escrow::{EscrowError, ProposalMode, ProposalStatus},
+        no_terms, EscrowTest, STOCKEN_ID_1,
+    },
+};
+use soroban_sdk::{
+    testutils::{Address as _, Events, MockAuth, MockAuthInvoke},
+    Address, IntoVal, String,
+};
+use uuid::Uuid;
+
+const FUNDS: i128 = 10_000_000_000_000_000_000; // 10 tokens
+
+fn add_relayer(test: &EscrowTest) -> Address {
+    let relayer = Address::generate(&test.env);
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.admin,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "set_relayer",
+                args: (relayer.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_relayer(&relayer);
+
+    relayer
+}
+
+#[test]
+fn add_proposal_only_proposer() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
+
+    // Bob can not add a proposal in the name of Alice
+    let res = test
+        .escrow
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "add_proposal",
+                args: (
+                    stocken_id.clone(),
+                    test.alice.clone(),
+                    test.token.address.clone(),
+                    FUNDS,
+                    ProposalMode::Direct,
+                    no_terms(),
+                )
+                    .into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_add_proposal(
+            &stocken_id,
+            &test.alice,
+            &test.token.address,
+            &FUNDS,
+            &ProposalMode::Direct,
+            &no_terms(),
+        );
+
+    assert!(res.is_err(), "calling with non-proposer not reverted");
+
+    let res = test.escrow.try_get_proposal(&stocken_id);
+
+    assert_eq!(res, Err(Ok(EscrowError::ProposalNotFound.into())));
+}
+
+#[test]
+fn register_escrow_only_sender() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+
+    // Alice can not pick the proposal with the funds of Bob
+    let res = test
+        .escrow
+        .mock_auths(&[MockAuth {
+            address: &test.alice,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "register_escrow",
+                args: (
+                    stocken_id.clone(),
+                    signaturit_id.clone(),
+                    test.bob.clone(),
+                    FUNDS,
+                    FUNDS,
+                    1_u32,
+                )
+                    .into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_register_escrow(&stocken_id, &signaturit_id, &test.bob, &FUNDS, &FUNDS, &1);
+
+    assert!(res.is_err(), "calling with non-sender not reverted");
+
+    // The authorization of the transfer alone is not enough
+    let res = test
+        .escrow
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.token.address,
+                fn_name: "transfer",
+                args: (test.bob.clone(), test.escrow.address.clone(), FUNDS).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_register_escrow(&stocken_id, &signaturit_id, &test.bob, &FUNDS, &FUNDS, &1);
+
+    assert!(res.is_err(), "calling without sender auth not reverted");
+
+    assert_eq!(
+        test.escrow.get_proposal(&stocken_id).status,
+        ProposalStatus::Actived
+    );
+}
+
+#[test]
+fn set_relayer() {
+    let test = EscrowTest::setup();
+
+    assert_eq!(test.escrow.get_relayer(), None);
+
+    let relayer = add_relayer(&test);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::RelayerUpdated(relayer.clone()).name(),).into_val(&test.env),
+        (relayer.clone(),).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "RelayerUpdated event not present"
+    );
+
+    assert_eq!(test.escrow.get_relayer(), Some(relayer));
+}
+
+#[test]
+fn set_relayer_only_admin() {
+    let test = EscrowTest::setup();
+
+    let relayer = Address::generate(&test.env);
+
+    let res = test
+        .escrow
+        .mock_auths(&[MockAuth {
+            address: &relayer,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "set_relayer",
+                args: (relayer.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_set_relayer(&relayer);
+
+    assert!(res.is_err(), "calling with non-admin not reverted");
+}
+
+#[test]
+fn remove_relayer() {
+    let test = EscrowTest::setup();
+
+    let res = test.escrow.mock_all_auths().try_remove_relayer();
+
+    assert_eq!(res, Err(Ok(EscrowError::NotAParty.into())));
+
+    let relayer = add_relayer(&test);
+
+    // Only the admin can remove it
+    let res = test
+        .escrow
+        .mock_auths(&[MockAuth {
+            address: &relayer,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "remove_relayer",
+                args: ().into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_remove_relayer();
+
+    assert!(res.is_err(), "calling with non-admin not reverted");
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.admin,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "remove_relayer",
+                args: ().into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .remove_relayer();
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::RelayerRemoved(relayer.clone()).name(),).into_val(&test.env),
+        (relayer.clone(),).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "RelayerRemoved event not present"
+    );
+
+    assert_eq!(test.escrow.get_relayer(), None);
+}
+
+#[test]
+fn relay_register_escrow() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+    let relayer = add_relayer(&test);
+
+    let balance_before_bob = test.token.balance(&test.bob);
+
+    // The relayer sends the call, and Bob only authorizes the transfer
+    test.escrow
+        .mock_auths(&[
+            MockAuth {
+                address: &relayer,
+                invoke: &MockAuthInvoke {
+                    contract: &test.escrow.address,
+                    fn_name: "relay_register_escrow",
+                    args: (
+                        stocken_id.clone(),
+                        signaturit_id.clone(),
+                        test.bob.clone(),
+                        FUNDS,
+                        FUNDS,
+                        1_u32,
+                    )
+                        .into_val(&test.env),
+                    sub_invokes: &[],
+                },
+            },
+            MockAuth {
+                address: &test.bob,
+                invoke: &MockAuthInvoke {
+                    contract: &test.token.address,
+                    fn_name: "transfer",
+                    args: (test.bob.clone(), test.escrow.address.clone(), FUNDS)
+                        .into_val(&test.env),
+                    sub_invokes: &[],
+                },
+            },
+        ])
+        .relay_register_escrow(&stocken_id, &signaturit_id, &test.bob, &FUNDS, &FUNDS, &1);
+
+    assert_eq!(test.token.balance(&test.bob), balance_before_bob - FUNDS);
+    assert_eq!(test.token.balance(&test.escrow.address), FUNDS);
+
+    let signature_tx = test.escrow.get_signature_tx_escrow(&signaturit_id);
+    assert_eq!(signature_tx.buyer, test.bob);
+    assert_eq!(
+        test.escrow.get_proposal(&stocken_id).status,
+        ProposalStatus::Picked
+    );
+}
+
+#[test]
+fn relay_register_escrow_without_relayer() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+
+    let res = test.escrow.mock_all_auths().try_relay_register_escrow(
+        &stocken_id,
+        &signaturit_id,
+        &test.bob,
+        &FUNDS,
+        &FUNDS,
+        &1,
+    );
+
+    assert_eq!(res, Err(Ok(EscrowError::NotAParty.into())));
+}
+
+#[test]
+fn relay_register_escrow_only_relayer() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+    add_relayer(&test);
+
+    // Alice is not the relayer
+    let res = test
+        .escrow
+        .mock_auths(&[
+            MockAuth {
+                address: &test.alice,
+                invoke: &MockAuthInvoke {
+                    contract: &test.escrow.address,
+                    fn_name: "relay_register_escrow",
+                    args: (
+                        stocken_id.clone(),
+                        signaturit_id.clone(),
+                        test.bob.clone(),
+                        FUNDS,
+                        FUNDS,
+                        1_u32,
+                    )
+                        .into_val(&test.env),
+                    sub_invokes: &[],
+                },
+            },
+            MockAuth {
+                address: &test.bob,
+                invoke: &MockAuthInvoke {
+                    contract: &test.token.address,
+                    fn_name: "transfer",
+                    args: (test.bob.clone(), test.escrow.address.clone(), FUNDS)
+                        .into_val(&test.env),
+                    sub_invokes: &[],
+                },
+            },
+        ])
+        .try_relay_register_escrow(&stocken_id, &signaturit_id, &test.bob, &FUNDS, &FUNDS, &1);
+
+    assert!(res.is_err(), "calling with non-relayer not reverted");
+}
+
+#[test]
+fn relay_register_escrow_without_sender_auth() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+    let relayer = add_relayer(&test);
+
+    // The relayer can not move the funds of Bob on its own
+    let res = test
+        .escrow
+        .mock_auths(&[MockAuth {
+            address: &relayer,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "relay_register_escrow",
+                args: (
+                    stocken_id.clone(),
+                    signaturit_id.clone(),
+                    test.bob.clone(),
+                    FUNDS,
+                    FUNDS,
+                    1_u32,
+                )
+                    .into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_relay_register_escrow(&stocken_id, &signaturit_id, &test.bob, &FUNDS, &FUNDS, &1);
+
+    assert!(res.is_err(), "calling without sender auth not reverted");
+
+    assert_eq!(
+        test.escrow.get_proposal(&stocken_id).status,
+        ProposalStatus::Actived
+    );
+}
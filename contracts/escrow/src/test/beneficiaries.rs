@@ -0,0 +1,237 @@
+#![cfg(test)]
+
+use crate::{
+    events::EscrowEvent,
+    test::{
+        escrow::{Beneficiary, EscrowError},
+        EscrowTest, STOCKEN_ID_1,
+    },
+};
+use soroban_sdk::{
+    testutils::{Address as _, Events, MockAuth, MockAuthInvoke},
+    vec, Address, IntoVal, Val, Vec,
+};
+
+// An amount that can not be split exactly by the shares
+const FUNDS: i128 = 1_000;
+
+fn beneficiaries(test: &EscrowTest, carol: &Address, dave: &Address) -> Vec<Beneficiary> {
+    vec![
+        &test.env,
+        Beneficiary {
+            address: test.alice.clone(),
+            bps: 3_333,
+        },
+        Beneficiary {
+            address: carol.clone(),
+            bps: 3_333,
+        },
+        Beneficiary {
+            address: dave.clone(),
+            bps: 3_334,
+        },
+    ]
+}
+
+#[test]
+fn set_beneficiaries() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+    let carol = Address::generate(&test.env);
+    let dave = Address::generate(&test.env);
+
+    assert_eq!(test.escrow.get_beneficiaries(&stocken_id), None);
+
+    let beneficiaries = beneficiaries(&test, &carol, &dave);
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.alice,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "set_beneficiaries",
+                args: (stocken_id.clone(), beneficiaries.clone(), carol.clone())
+                    .into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_beneficiaries(&stocken_id, &beneficiaries, &carol);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::BeneficiariesSet(stocken_id.clone(), 3, carol.clone()).name(),)
+            .into_val(&test.env),
+        (stocken_id.clone(), 3_u32, carol.clone()).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "BeneficiariesSet event not present"
+    );
+
+    let split = test.escrow.get_beneficiaries(&stocken_id).unwrap();
+
+    assert_eq!(split.beneficiaries, beneficiaries);
+    assert_eq!(split.dust_beneficiary, carol);
+}
+
+#[test]
+fn set_beneficiaries_invalid() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+    let carol = Address::generate(&test.env);
+    let dave = Address::generate(&test.env);
+
+    // The shares do not sum 10_000
+    let mut beneficiaries = beneficiaries(&test, &carol, &dave);
+    beneficiaries.pop_back();
+
+    let res =
+        test.escrow
+            .mock_all_auths()
+            .try_set_beneficiaries(&stocken_id, &beneficiaries, &carol);
+
+    assert_eq!(res, Err(Ok(EscrowError::InvalidBeneficiaries.into())));
+
+    // The dust beneficiary is not one of them
+    let beneficiaries = vec![
+        &test.env,
+        Beneficiary {
+            address: carol.clone(),
+            bps: 10_000,
+        },
+    ];
+
+    let res =
+        test.escrow
+            .mock_all_auths()
+            .try_set_beneficiaries(&stocken_id, &beneficiaries, &dave);
+
+    assert_eq!(res, Err(Ok(EscrowError::InvalidBeneficiaries.into())));
+
+    // A beneficiary without share
+    let beneficiaries = vec![
+        &test.env,
+        Beneficiary {
+            address: carol.clone(),
+            bps: 10_000,
+        },
+        Beneficiary {
+            address: dave.clone(),
+            bps: 0,
+        },
+    ];
+
+    let res =
+        test.escrow
+            .mock_all_auths()
+            .try_set_beneficiaries(&stocken_id, &beneficiaries, &carol);
+
+    assert_eq!(res, Err(Ok(EscrowError::InvalidBeneficiaries.into())));
+}
+
+#[test]
+fn set_beneficiaries_picked() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+    let carol = Address::generate(&test.env);
+    let dave = Address::generate(&test.env);
+
+    test.complete_signature(&stocken_id, FUNDS);
+
+    let res = test.escrow.mock_all_auths().try_set_beneficiaries(
+        &stocken_id,
+        &beneficiaries(&test, &carol, &dave),
+        &carol,
+    );
+
+    assert_eq!(res, Err(Ok(EscrowError::PickedOrCanceled.into())));
+}
+
+#[test]
+fn completed_signature_splits_funds() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+    let carol = Address::generate(&test.env);
+    let dave = Address::generate(&test.env);
+
+    test.escrow.mock_all_auths().set_beneficiaries(
+        &stocken_id,
+        &beneficiaries(&test, &carol, &dave),
+        &carol,
+    );
+
+    let balance_before_alice = test.token.balance(&test.alice);
+
+    let signaturit_id = test.complete_signature(&stocken_id, FUNDS);
+
+    // 1_000 * 33.33% is 333, and the dust left goes to carol
+    assert_eq!(test.token.balance(&test.alice), balance_before_alice + 333);
+    assert_eq!(test.token.balance(&carol), 334);
+    assert_eq!(test.token.balance(&dave), 333);
+    assert_eq!(test.token.balance(&test.escrow.address), 0);
+
+    let topics: Vec<Val> = ("BeneficiaryPaid",).into_val(&test.env);
+    let payouts = test
+        .env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, event_topics, _)| *event_topics == topics)
+        .count();
+
+    assert_eq!(payouts, 3);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::BeneficiaryPaid(
+            signaturit_id.clone(),
+            stocken_id.clone(),
+            carol.clone(),
+            334,
+        )
+        .name(),)
+            .into_val(&test.env),
+        (signaturit_id, stocken_id, carol, 334_i128).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "BeneficiaryPaid event not present"
+    );
+}
+
+#[test]
+fn remove_beneficiaries() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+    let carol = Address::generate(&test.env);
+    let dave = Address::generate(&test.env);
+
+    test.escrow.mock_all_auths().set_beneficiaries(
+        &stocken_id,
+        &beneficiaries(&test, &carol, &dave),
+        &carol,
+    );
+
+    test.escrow
+        .mock_all_auths()
+        .set_beneficiaries(&stocken_id, &Vec::new(&test.env), &carol);
+
+    assert_eq!(test.escrow.get_beneficiaries(&stocken_id), None);
+
+    let balance_before_alice = test.token.balance(&test.alice);
+
+    test.complete_signature(&stocken_id, FUNDS);
+
+    assert_eq!(
+        test.token.balance(&test.alice),
+        balance_before_alice + FUNDS
+    );
+    assert_eq!(test.token.balance(&carol), 0);
+}
@@ -0,0 +1,290 @@
+#![cfg(test)]
+
+use crate::{
+    events::EscrowEvent,
+    test::{
+        escrow::{EscrowError, ProposalMode, ProposalStatus, ProposalTerms},
+        no_terms,
+        oracle::FailureReason,
+        EscrowTest, STOCKEN_ID_1,
+    },
+};
+use soroban_sdk::{
+    testutils::{Events, MockAuth, MockAuthInvoke},
+    IntoVal, String,
+};
+
+const FUNDS: i128 = 10_000_000_000_000_000_000; // 10 tokens
+
+// The owner posts the 20% of the funds
+const BOND_BPS: u32 = 2_000;
+const BOND: i128 = 2_000_000_000_000_000_000; // 2 tokens
+
+fn add_proposal(test: &EscrowTest) -> String {
+    let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
+    let terms = ProposalTerms {
+        bond_bps: BOND_BPS,
+        ..no_terms()
+    };
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.alice,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "add_proposal",
+                args: (
+                    stocken_id.clone(),
+                    test.alice.clone(),
+                    test.token.address.clone(),
+                    FUNDS,
+                    ProposalMode::Direct,
+                    terms.clone(),
+                )
+                    .into_val(&test.env),
+                sub_invokes: &[MockAuthInvoke {
+                    contract: &test.token.address,
+                    fn_name: "transfer",
+                    args: (test.alice.clone(), test.escrow.address.clone(), BOND)
+                        .into_val(&test.env),
+                    sub_invokes: &[],
+                }],
+            },
+        }])
+        .add_proposal(
+            &stocken_id,
+            &test.alice,
+            &test.token.address,
+            &FUNDS,
+            &ProposalMode::Direct,
+            &terms,
+        );
+
+    stocken_id
+}
+
+#[test]
+fn add_proposal_with_bond() {
+    let test = EscrowTest::setup();
+
+    let balance_before_alice = test.token.balance(&test.alice);
+
+    let stocken_id = add_proposal(&test);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::BondPosted(stocken_id.clone(), test.alice.clone(), BOND).name(),)
+            .into_val(&test.env),
+        (stocken_id.clone(), test.alice.clone(), BOND).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "BondPosted event not present"
+    );
+
+    assert_eq!(test.escrow.get_proposal(&stocken_id).bond, BOND);
+    assert_eq!(test.token.balance(&test.alice), balance_before_alice - BOND);
+    assert_eq!(test.token.balance(&test.escrow.address), BOND);
+}
+
+#[test]
+fn add_proposal_invalid_bond() {
+    let test = EscrowTest::setup();
+
+    let res = test.escrow.try_add_proposal(
+        &String::from_str(&test.env, STOCKEN_ID_1),
+        &test.alice,
+        &test.token.address,
+        &FUNDS,
+        &ProposalMode::Direct,
+        &ProposalTerms {
+            bond_bps: 10_001,
+            ..no_terms()
+        },
+    );
+
+    assert_eq!(res, Err(Ok(EscrowError::InvalidAmount.into())));
+}
+
+#[test]
+fn cancel_proposal_returns_bond() {
+    let test = EscrowTest::setup();
+
+    let balance_before_alice = test.token.balance(&test.alice);
+
+    let stocken_id = add_proposal(&test);
+
+    test.escrow.mock_all_auths().cancel_proposal(&stocken_id);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::BondReturned(stocken_id.clone(), test.alice.clone(), BOND).name(),)
+            .into_val(&test.env),
+        (stocken_id.clone(), test.alice.clone(), BOND).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "BondReturned event not present"
+    );
+
+    assert_eq!(test.escrow.get_proposal(&stocken_id).bond, 0);
+    assert_eq!(test.token.balance(&test.alice), balance_before_alice);
+    assert_eq!(test.token.balance(&test.escrow.address), 0);
+}
+
+#[test]
+fn update_proposal_adjusts_bond() {
+    let test = EscrowTest::setup();
+
+    let balance_before_alice = test.token.balance(&test.alice);
+
+    let stocken_id = add_proposal(&test);
+
+    // Raising the price posts the rest of the bond
+    let new_funds = FUNDS + FUNDS / 2; // 15 tokens
+    let new_bond = BOND + BOND / 2; // 3 tokens
+
+    test.escrow
+        .mock_all_auths()
+        .update_proposal(&stocken_id, &new_funds, &None, &None);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::BondPosted(stocken_id.clone(), test.alice.clone(), BOND / 2).name(),)
+            .into_val(&test.env),
+        (stocken_id.clone(), test.alice.clone(), BOND / 2).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "BondPosted event not present"
+    );
+
+    let propose = test.escrow.get_proposal(&stocken_id);
+
+    assert_eq!(propose.bond, new_bond);
+    assert_eq!(propose.bond_bps, BOND_BPS);
+    assert_eq!(
+        test.token.balance(&test.alice),
+        balance_before_alice - new_bond
+    );
+    assert_eq!(test.token.balance(&test.escrow.address), new_bond);
+
+    // Lowering it returns the difference
+    test.escrow
+        .mock_all_auths()
+        .update_proposal(&stocken_id, &(FUNDS / 2), &None, &None);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (
+            EscrowEvent::BondReturned(stocken_id.clone(), test.alice.clone(), new_bond - BOND / 2)
+                .name(),
+        )
+            .into_val(&test.env),
+        (stocken_id.clone(), test.alice.clone(), new_bond - BOND / 2).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "BondReturned event not present"
+    );
+
+    assert_eq!(test.escrow.get_proposal(&stocken_id).bond, BOND / 2);
+    assert_eq!(
+        test.token.balance(&test.alice),
+        balance_before_alice - BOND / 2
+    );
+    assert_eq!(
+        test.escrow.reconcile(&test.token.address).liability,
+        BOND / 2
+    );
+}
+
+#[test]
+fn completed_signature_returns_bond() {
+    let test = EscrowTest::setup();
+
+    let balance_before_alice = test.token.balance(&test.alice);
+
+    let stocken_id = add_proposal(&test);
+    test.register_escrow(&stocken_id, FUNDS, 1);
+
+    let document_hash = Some(String::from_str(&test.env, "Test document hash"));
+    test.oracle
+        .mock_all_auths()
+        .signature_response(&0, &true, &document_hash, &None);
+
+    let propose = test.escrow.get_proposal(&stocken_id);
+
+    assert_eq!(propose.status, ProposalStatus::Completed);
+    assert_eq!(propose.bond, 0);
+    assert_eq!(
+        test.token.balance(&test.alice),
+        balance_before_alice + FUNDS
+    );
+    assert_eq!(test.token.balance(&test.escrow.address), 0);
+}
+
+#[test]
+fn owner_declined_forfeits_bond() {
+    let test = EscrowTest::setup();
+
+    let balance_before_alice = test.token.balance(&test.alice);
+    let balance_before_bob = test.token.balance(&test.bob);
+
+    let stocken_id = add_proposal(&test);
+    test.register_escrow(&stocken_id, FUNDS, 1);
+
+    test.oracle.mock_all_auths().signature_response(
+        &0,
+        &false,
+        &None,
+        &Some(FailureReason::ReceiverDeclined),
+    );
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::BondForfeited(stocken_id.clone(), test.bob.clone(), BOND).name(),)
+            .into_val(&test.env),
+        (stocken_id.clone(), test.bob.clone(), BOND).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "BondForfeited event not present"
+    );
+
+    // The buyer gets the funds back and the bond
+    assert_eq!(test.token.balance(&test.bob), balance_before_bob + BOND);
+    assert_eq!(test.token.balance(&test.alice), balance_before_alice - BOND);
+    assert_eq!(test.token.balance(&test.escrow.address), 0);
+    assert_eq!(test.escrow.get_proposal(&stocken_id).bond, 0);
+}
+
+#[test]
+fn other_failure_keeps_bond() {
+    let test = EscrowTest::setup();
+
+    let balance_before_bob = test.token.balance(&test.bob);
+
+    let stocken_id = add_proposal(&test);
+    test.register_escrow(&stocken_id, FUNDS, 1);
+
+    test.oracle.mock_all_auths().signature_response(
+        &0,
+        &false,
+        &None,
+        &Some(FailureReason::SenderDeclined),
+    );
+
+    // The bond is still held for the next buyer
+    let propose = test.escrow.get_proposal(&stocken_id);
+
+    assert_eq!(propose.status, ProposalStatus::Actived);
+    assert_eq!(propose.bond, BOND);
+    assert_eq!(test.token.balance(&test.bob), balance_before_bob);
+    assert_eq!(test.token.balance(&test.escrow.address), BOND);
+}
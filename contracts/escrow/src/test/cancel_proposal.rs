@@ -0,0 +1,205 @@
+#![cfg(test)]
+
+extern crate std;
+use core::ops::Mul;
+use std::string::ToString;
+
+use crate::{
+    events::EscrowEvent,
+    test::{
+        escrow::{EscrowError, ProposalMode, ProposalStatus},
+        no_terms, EscrowTest, STOCKEN_ID_1,
+    },
+};
+use soroban_sdk::{
+    testutils::{Events, MockAuth, MockAuthInvoke},
+    IntoVal, String,
+};
+use uuid::Uuid;
+
+#[test]
+fn cancel_proposal() {
+    let test = EscrowTest::setup();
+
+    // Add a proposal
+    let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
+    let amount_asked: i128 = 10_000_000_000_000_000_000; // 10 tokens
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &amount_asked,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.alice,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "cancel_proposal",
+                args: (stocken_id.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .cancel_proposal(&stocken_id);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::ProposalCanceled(stocken_id.clone(), test.alice.clone()).name(),)
+            .into_val(&test.env),
+        (stocken_id.clone(), &test.alice).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "ProposalCanceled event not present"
+    );
+
+    let proposal = test.escrow.get_proposal(&stocken_id);
+    assert_eq!(
+        proposal.status,
+        ProposalStatus::Canceled,
+        "proposal status was not updated"
+    );
+
+    // A canceled proposal can not be picked
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+    let res = test.escrow.mock_all_auths().try_register_escrow(
+        &stocken_id,
+        &signaturit_id,
+        &test.bob,
+        &amount_asked,
+        &amount_asked,
+        &1,
+    );
+
+    assert_eq!(res, Err(Ok(EscrowError::PickedOrCanceled.into())));
+}
+
+#[test]
+fn cancel_proposal_not_owner() {
+    let test = EscrowTest::setup();
+
+    // Add a proposal
+    let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
+    let amount_asked: i128 = 10_000_000_000_000_000_000; // 10 tokens
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &amount_asked,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
+
+    // Bob is not the owner of the proposal
+    let res = test
+        .escrow
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "cancel_proposal",
+                args: (stocken_id.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_cancel_proposal(&stocken_id);
+
+    assert!(res.is_err(), "calling with non-owner not reverted");
+
+    let proposal = test.escrow.get_proposal(&stocken_id);
+    assert_eq!(proposal.status, ProposalStatus::Actived);
+}
+
+#[test]
+fn cancel_proposal_not_found() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
+
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_cancel_proposal(&stocken_id);
+
+    assert_eq!(res, Err(Ok(EscrowError::ProposalNotFound.into())));
+}
+
+#[test]
+fn cancel_proposal_already_picked() {
+    let test = EscrowTest::setup();
+
+    // Add a proposal
+    let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
+    let amount_asked: i128 = 10_000_000_000_000_000_000; // 10 tokens
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &amount_asked,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
+
+    // Pick the proposal
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+    let amount_to_give: i128 = amount_asked.mul(2);
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "register_escrow",
+                args: (
+                    stocken_id.clone(),
+                    signaturit_id.clone(),
+                    test.bob.clone(),
+                    amount_to_give,
+                    amount_to_give,
+                    1_u32,
+                )
+                    .into_val(&test.env),
+                sub_invokes: &[MockAuthInvoke {
+                    contract: &test.token.address,
+                    fn_name: "transfer",
+                    args: (
+                        test.bob.clone(),
+                        test.escrow.address.clone(),
+                        amount_to_give,
+                    )
+                        .into_val(&test.env),
+                    sub_invokes: &[],
+                }],
+            },
+        }])
+        .register_escrow(
+            &stocken_id,
+            &signaturit_id,
+            &test.bob,
+            &amount_to_give,
+            &amount_to_give,
+            &1,
+        );
+
+    let res = test
+        .escrow
+        .mock_auths(&[MockAuth {
+            address: &test.alice,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "cancel_proposal",
+                args: (stocken_id.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_cancel_proposal(&stocken_id);
+
+    assert_eq!(res, Err(Ok(EscrowError::PickedOrCanceled.into())));
+
+    let proposal = test.escrow.get_proposal(&stocken_id);
+    assert_eq!(proposal.status, ProposalStatus::Picked);
+}
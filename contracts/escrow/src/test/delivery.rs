@@ -0,0 +1,237 @@
+#![cfg(test)]
+
+use crate::{
+    events::EscrowEvent,
+    test::{
+        create_token_contract,
+        escrow::{
+            Delivery, DeliveryStatus, EscrowError, NullableDelivery, ProposalMode, ProposalStatus,
+            ProposalTerms,
+        },
+        no_terms,
+        oracle::FailureReason,
+        EscrowTest, STOCKEN_ID_1,
+    },
+};
+use soroban_sdk::{
+    testutils::{Events, MockAuth, MockAuthInvoke},
+    token::Client as TokenClient,
+    IntoVal, String,
+};
+
+const FUNDS: i128 = 10_000_000_000_000_000_000; // 10 tokens
+
+// Units of the security token sold by the proposal
+const SHARES: i128 = 100;
+
+fn add_proposal<'a>(test: &EscrowTest<'a>) -> (String, TokenClient<'a>) {
+    let (security_token, security_token_admin) = create_token_contract(&test.env, &test.admin);
+    security_token_admin
+        .mock_all_auths()
+        .mint(&test.alice, &SHARES);
+
+    let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
+
+    let delivery = Delivery {
+        asset: security_token.address.clone(),
+        amount: SHARES,
+        status: DeliveryStatus::Held,
+    };
+    let terms = ProposalTerms {
+        delivery: NullableDelivery::Some(delivery),
+        ..no_terms()
+    };
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.alice,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "add_proposal",
+                args: (
+                    stocken_id.clone(),
+                    test.alice.clone(),
+                    test.token.address.clone(),
+                    FUNDS,
+                    ProposalMode::Direct,
+                    terms.clone(),
+                )
+                    .into_val(&test.env),
+                sub_invokes: &[MockAuthInvoke {
+                    contract: &security_token.address,
+                    fn_name: "transfer",
+                    args: (test.alice.clone(), test.escrow.address.clone(), SHARES)
+                        .into_val(&test.env),
+                    sub_invokes: &[],
+                }],
+            },
+        }])
+        .add_proposal(
+            &stocken_id,
+            &test.alice,
+            &test.token.address,
+            &FUNDS,
+            &ProposalMode::Direct,
+            &terms,
+        );
+
+    (stocken_id, security_token)
+}
+
+#[test]
+fn add_proposal_with_delivery() {
+    let test = EscrowTest::setup();
+
+    let (stocken_id, security_token) = add_proposal(&test);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::DeliveryDeposited(
+            stocken_id.clone(),
+            security_token.address.clone(),
+            SHARES,
+        )
+        .name(),)
+            .into_val(&test.env),
+        (stocken_id.clone(), security_token.address.clone(), SHARES).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "DeliveryDeposited event not present"
+    );
+
+    let delivery = test.escrow.get_delivery(&stocken_id).unwrap();
+
+    assert_eq!(delivery.asset, security_token.address);
+    assert_eq!(delivery.amount, SHARES);
+    assert_eq!(delivery.status, DeliveryStatus::Held);
+
+    assert_eq!(security_token.balance(&test.alice), 0);
+    assert_eq!(security_token.balance(&test.escrow.address), SHARES);
+}
+
+#[test]
+fn add_proposal_delivery_not_direct() {
+    let test = EscrowTest::setup();
+
+    let (security_token, _) = create_token_contract(&test.env, &test.admin);
+
+    let res = test.escrow.try_add_proposal(
+        &String::from_str(&test.env, STOCKEN_ID_1),
+        &test.alice,
+        &test.token.address,
+        &FUNDS,
+        &ProposalMode::Pooled,
+        &ProposalTerms {
+            delivery: NullableDelivery::Some(Delivery {
+                asset: security_token.address.clone(),
+                amount: SHARES,
+                status: DeliveryStatus::Held,
+            }),
+            ..no_terms()
+        },
+    );
+
+    assert_eq!(res, Err(Ok(EscrowError::NotDirectProposal.into())));
+}
+
+#[test]
+fn completed_signature_delivers_asset() {
+    let test = EscrowTest::setup();
+
+    let balance_before_alice = test.token.balance(&test.alice);
+    let balance_before_bob = test.token.balance(&test.bob);
+
+    let (stocken_id, security_token) = add_proposal(&test);
+    test.register_escrow(&stocken_id, FUNDS, 1);
+
+    let document_hash = Some(String::from_str(&test.env, "Test document hash"));
+    test.oracle
+        .mock_all_auths()
+        .signature_response(&0, &true, &document_hash, &None);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::AssetDelivered(stocken_id.clone(), test.bob.clone(), SHARES).name(),)
+            .into_val(&test.env),
+        (stocken_id.clone(), test.bob.clone(), SHARES).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "AssetDelivered event not present"
+    );
+
+    // The owner is paid and the buyer receives the asset
+    assert_eq!(
+        test.token.balance(&test.alice),
+        balance_before_alice + FUNDS
+    );
+    assert_eq!(test.token.balance(&test.bob), balance_before_bob - FUNDS);
+    assert_eq!(security_token.balance(&test.bob), SHARES);
+    assert_eq!(security_token.balance(&test.escrow.address), 0);
+
+    assert_eq!(
+        test.escrow.get_delivery(&stocken_id).unwrap().status,
+        DeliveryStatus::Delivered
+    );
+}
+
+#[test]
+fn failed_signature_returns_both_sides() {
+    let test = EscrowTest::setup();
+
+    let balance_before_bob = test.token.balance(&test.bob);
+
+    let (stocken_id, security_token) = add_proposal(&test);
+    test.register_escrow(&stocken_id, FUNDS, 1);
+
+    test.oracle.mock_all_auths().signature_response(
+        &0,
+        &false,
+        &None,
+        &Some(FailureReason::Expired),
+    );
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::DeliveryReturned(stocken_id.clone(), test.alice.clone(), SHARES).name(),)
+            .into_val(&test.env),
+        (stocken_id.clone(), test.alice.clone(), SHARES).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "DeliveryReturned event not present"
+    );
+
+    assert_eq!(test.token.balance(&test.bob), balance_before_bob);
+    assert_eq!(security_token.balance(&test.alice), SHARES);
+    assert_eq!(security_token.balance(&test.escrow.address), 0);
+
+    assert_eq!(
+        test.escrow.get_proposal(&stocken_id).status,
+        ProposalStatus::Canceled
+    );
+    assert_eq!(
+        test.escrow.get_delivery(&stocken_id).unwrap().status,
+        DeliveryStatus::Returned
+    );
+}
+
+#[test]
+fn cancel_proposal_returns_delivery() {
+    let test = EscrowTest::setup();
+
+    let (stocken_id, security_token) = add_proposal(&test);
+
+    test.escrow.mock_all_auths().cancel_proposal(&stocken_id);
+
+    assert_eq!(security_token.balance(&test.alice), SHARES);
+    assert_eq!(security_token.balance(&test.escrow.address), 0);
+    assert_eq!(
+        test.escrow.get_delivery(&stocken_id).unwrap().status,
+        DeliveryStatus::Returned
+    );
+}
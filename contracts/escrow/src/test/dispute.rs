@@ -0,0 +1,449 @@
+#![cfg(test)]
+
+extern crate std;
+use std::string::ToString;
+
+use crate::{
+    events::EscrowEvent,
+    test::{
+        escrow::{EscrowError, NullableAddress, ProposalMode, ProposalStatus, SignatureStatus},
+        no_terms, EscrowTest, STOCKEN_ID_1,
+    },
+};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger, MockAuth, MockAuthInvoke},
+    vec, Address, IntoVal, String,
+};
+use uuid::Uuid;
+
+// Ledgers that the funds are held after the signature
+const DISPUTE_WINDOW: u32 = 100;
+
+const FUNDS: i128 = 20_000_000_000_000_000_000; // 20 tokens
+
+/**
+ * Add a proposal, pick it by Bob and complete the signature, leaving the funds
+ * held during the dispute window.
+ */
+fn complete_with_window(test: &EscrowTest) -> (String, String) {
+    complete_with_custom_window(test, DISPUTE_WINDOW)
+}
+
+fn complete_with_custom_window(test: &EscrowTest, dispute_window: u32) -> (String, String) {
+    test.escrow
+        .mock_all_auths()
+        .set_dispute_window(&dispute_window);
+
+    let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &FUNDS,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
+
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "register_escrow",
+                args: (
+                    stocken_id.clone(),
+                    signaturit_id.clone(),
+                    test.bob.clone(),
+                    FUNDS,
+                    FUNDS,
+                    1_u32,
+                )
+                    .into_val(&test.env),
+                sub_invokes: &[MockAuthInvoke {
+                    contract: &test.token.address,
+                    fn_name: "transfer",
+                    args: (test.bob.clone(), test.escrow.address.clone(), FUNDS)
+                        .into_val(&test.env),
+                    sub_invokes: &[],
+                }],
+            },
+        }])
+        .register_escrow(&stocken_id, &signaturit_id, &test.bob, &FUNDS, &FUNDS, &1);
+
+    let document_hash = Some(String::from_str(&test.env, "Test document hash"));
+    test.oracle
+        .mock_all_auths()
+        .signature_response(&0, &true, &document_hash, &None);
+
+    (stocken_id, signaturit_id)
+}
+
+#[test]
+fn set_dispute_window_and_arbitrator() {
+    let test = EscrowTest::setup();
+
+    assert_eq!(test.escrow.get_dispute_window(), 0);
+    assert_eq!(test.escrow.get_arbitrator(), None);
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.admin,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "set_dispute_window",
+                args: (DISPUTE_WINDOW,).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_dispute_window(&DISPUTE_WINDOW);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::DisputeWindowUpdated(DISPUTE_WINDOW).name(),).into_val(&test.env),
+        (DISPUTE_WINDOW,).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "DisputeWindowUpdated event not present"
+    );
+
+    let arbitrator = Address::generate(&test.env);
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.admin,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "set_arbitrator",
+                args: (arbitrator.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_arbitrator(&arbitrator);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::ArbitratorUpdated(arbitrator.clone()).name(),).into_val(&test.env),
+        (arbitrator.clone(),).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "ArbitratorUpdated event not present"
+    );
+
+    assert_eq!(test.escrow.get_dispute_window(), DISPUTE_WINDOW);
+    assert_eq!(test.escrow.get_arbitrator(), Some(arbitrator));
+
+    // Only the admin can set them
+    let res = test
+        .escrow
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "set_arbitrator",
+                args: (test.bob.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_set_arbitrator(&test.bob);
+
+    assert!(res.is_err(), "calling with non-admin not reverted");
+}
+
+#[test]
+fn finalize_after_window() {
+    let test = EscrowTest::setup();
+
+    let balance_before_alice = test.token.balance(&test.alice);
+    let (stocken_id, signaturit_id) = complete_with_window(&test);
+
+    let release_ledger = test.env.ledger().sequence() + DISPUTE_WINDOW;
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (
+            EscrowEvent::ReleasePending(signaturit_id.clone(), stocken_id.clone(), release_ledger)
+                .name(),
+        )
+            .into_val(&test.env),
+        (signaturit_id.clone(), stocken_id.clone(), release_ledger).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "ReleasePending event not present"
+    );
+
+    // The funds are held and the note is not minted yet
+    let signature_tx = test.escrow.get_signature_tx_escrow(&signaturit_id);
+    assert_eq!(signature_tx.status, SignatureStatus::PendingRelease);
+    assert_eq!(signature_tx.nft_proof_ids, vec![&test.env]);
+    assert_eq!(test.token.balance(&test.escrow.address), FUNDS);
+    assert_eq!(
+        test.escrow
+            .get_pending_release(&signaturit_id)
+            .release_ledger,
+        release_ledger
+    );
+
+    let res = test.escrow.try_finalize(&signaturit_id);
+    assert_eq!(res, Err(Ok(EscrowError::DisputeWindowOpen.into())));
+
+    test.env.ledger().with_mut(|ledger| {
+        ledger.sequence_number += DISPUTE_WINDOW + 1;
+    });
+
+    // Anyone can finalize it
+    test.escrow.finalize(&signaturit_id);
+
+    let signature_tx = test.escrow.get_signature_tx_escrow(&signaturit_id);
+    assert_eq!(signature_tx.status, SignatureStatus::Completed);
+    assert_eq!(signature_tx.nft_proof_ids, vec![&test.env, 0]);
+    assert_eq!(
+        test.token.balance(&test.alice),
+        balance_before_alice + FUNDS
+    );
+    assert_eq!(test.token.balance(&test.escrow.address), 0);
+    assert_eq!(
+        test.escrow.get_proposal(&stocken_id).status,
+        ProposalStatus::Completed
+    );
+
+    let res = test.escrow.try_get_pending_release(&signaturit_id);
+    assert_eq!(res, Err(Ok(EscrowError::NotPendingRelease.into())));
+}
+
+#[test]
+fn dispute_window_too_large() {
+    let test = EscrowTest::setup();
+
+    test.env.ledger().with_mut(|ledger| {
+        ledger.sequence_number = 1_000;
+    });
+
+    // The window does not overflow, so the signature is still completed
+    let (_, signaturit_id) = complete_with_custom_window(&test, u32::MAX);
+
+    let signature_tx = test.escrow.get_signature_tx_escrow(&signaturit_id);
+    assert_eq!(signature_tx.status, SignatureStatus::PendingRelease);
+    assert_eq!(
+        test.escrow
+            .get_pending_release(&signaturit_id)
+            .release_ledger,
+        u32::MAX
+    );
+
+    let res = test.escrow.try_finalize(&signaturit_id);
+    assert_eq!(res, Err(Ok(EscrowError::DisputeWindowOpen.into())));
+}
+
+#[test]
+fn open_dispute_errors() {
+    let test = EscrowTest::setup();
+
+    let (_, signaturit_id) = complete_with_window(&test);
+
+    // Without arbitrator
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_open_dispute(&signaturit_id, &test.bob);
+
+    assert_eq!(res, Err(Ok(EscrowError::NoArbitrator.into())));
+
+    let arbitrator = Address::generate(&test.env);
+    test.escrow.mock_all_auths().set_arbitrator(&arbitrator);
+
+    // Not a party of the signature
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_open_dispute(&signaturit_id, &arbitrator);
+
+    assert_eq!(res, Err(Ok(EscrowError::NotAParty.into())));
+
+    test.env.ledger().with_mut(|ledger| {
+        ledger.sequence_number += DISPUTE_WINDOW + 1;
+    });
+
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_open_dispute(&signaturit_id, &test.bob);
+
+    assert_eq!(res, Err(Ok(EscrowError::DisputeWindowClosed.into())));
+}
+
+#[test]
+fn dispute_split() {
+    let test = EscrowTest::setup();
+
+    let arbitrator = Address::generate(&test.env);
+    test.escrow.mock_all_auths().set_arbitrator(&arbitrator);
+
+    let balance_before_alice = test.token.balance(&test.alice);
+    let balance_before_bob = test.token.balance(&test.bob);
+    let (stocken_id, signaturit_id) = complete_with_window(&test);
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "open_dispute",
+                args: (signaturit_id.clone(), test.bob.clone()).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .open_dispute(&signaturit_id, &test.bob);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (
+            EscrowEvent::DisputeOpened(signaturit_id.clone(), stocken_id.clone(), test.bob.clone())
+                .name(),
+        )
+            .into_val(&test.env),
+        (signaturit_id.clone(), stocken_id.clone(), test.bob.clone()).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "DisputeOpened event not present"
+    );
+
+    assert_eq!(
+        test.escrow.get_signature_tx_escrow(&signaturit_id).status,
+        SignatureStatus::Disputed
+    );
+    assert_eq!(
+        test.escrow.get_pending_release(&signaturit_id).disputed_by,
+        NullableAddress::Some(test.bob.clone())
+    );
+
+    // A disputed signature is not released after the window
+    test.env.ledger().with_mut(|ledger| {
+        ledger.sequence_number += DISPUTE_WINDOW + 1;
+    });
+
+    let res = test.escrow.try_finalize(&signaturit_id);
+    assert_eq!(res, Err(Ok(EscrowError::NotPendingRelease.into())));
+
+    let receiver_share: i128 = 15_000_000_000_000_000_000; // 15 tokens
+    let buyer_share: i128 = FUNDS - receiver_share;
+
+    // Only the arbitrator resolves it
+    let res = test
+        .escrow
+        .mock_auths(&[MockAuth {
+            address: &test.alice,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "resolve_dispute",
+                args: (signaturit_id.clone(), FUNDS).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_resolve_dispute(&signaturit_id, &FUNDS);
+
+    assert!(res.is_err(), "calling with non-arbitrator not reverted");
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &arbitrator,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "resolve_dispute",
+                args: (signaturit_id.clone(), receiver_share).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .resolve_dispute(&signaturit_id, &receiver_share);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::DisputeResolved(
+            signaturit_id.clone(),
+            stocken_id.clone(),
+            arbitrator.clone(),
+            receiver_share,
+            buyer_share,
+        )
+        .name(),)
+            .into_val(&test.env),
+        (
+            signaturit_id.clone(),
+            stocken_id.clone(),
+            arbitrator.clone(),
+            receiver_share,
+            buyer_share,
+        )
+            .into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "DisputeResolved event not present"
+    );
+
+    assert_eq!(
+        test.token.balance(&test.alice),
+        balance_before_alice + receiver_share
+    );
+    assert_eq!(
+        test.token.balance(&test.bob),
+        balance_before_bob - receiver_share
+    );
+    assert_eq!(test.token.balance(&test.escrow.address), 0);
+
+    // The signature stands, so the note is minted
+    let signature_tx = test.escrow.get_signature_tx_escrow(&signaturit_id);
+    assert_eq!(signature_tx.status, SignatureStatus::Completed);
+    assert_eq!(signature_tx.nft_proof_ids, vec![&test.env, 0]);
+    assert_eq!(test.nft_notes.owner_of(&0), test.bob);
+}
+
+#[test]
+fn dispute_refund() {
+    let test = EscrowTest::setup();
+
+    let arbitrator = Address::generate(&test.env);
+    test.escrow.mock_all_auths().set_arbitrator(&arbitrator);
+
+    let balance_before_bob = test.token.balance(&test.bob);
+    let (stocken_id, signaturit_id) = complete_with_window(&test);
+
+    // The receiver can dispute too
+    test.escrow
+        .mock_all_auths()
+        .open_dispute(&signaturit_id, &test.alice);
+
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_resolve_dispute(&signaturit_id, &(FUNDS + 1));
+
+    assert_eq!(res, Err(Ok(EscrowError::InvalidAmount.into())));
+
+    test.escrow
+        .mock_all_auths()
+        .resolve_dispute(&signaturit_id, &0);
+
+    assert_eq!(test.token.balance(&test.bob), balance_before_bob);
+    assert_eq!(test.token.balance(&test.escrow.address), 0);
+
+    let signature_tx = test.escrow.get_signature_tx_escrow(&signaturit_id);
+    assert_eq!(signature_tx.status, SignatureStatus::Canceled);
+    assert_eq!(signature_tx.nft_proof_ids, vec![&test.env]);
+
+    // The proposal can be picked again
+    assert_eq!(
+        test.escrow.get_proposal(&stocken_id).status,
+        ProposalStatus::Actived
+    );
+}
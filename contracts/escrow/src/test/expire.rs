@@ -0,0 +1,316 @@
+#![cfg(test)]
+
+extern crate std;
+use std::string::ToString;
+
+use crate::{
+    events::EscrowEvent,
+    test::{
+        escrow::{AuctionStatus, EscrowError, ProposalMode, ProposalStatus, ProposalTerms},
+        no_terms,
+        oracle::FailureReason,
+        EscrowTest, STOCKEN_ID_1,
+    },
+};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token::StellarAssetClient,
+    Address, IntoVal, String,
+};
+use uuid::Uuid;
+
+// Ledgers that the proposal can be picked
+const PROPOSAL_LIFETIME: u32 = 100;
+
+// Ledgers that the auctions accept bids, less than the proposal lifetime
+const AUCTION_LEDGERS: u32 = 10;
+
+const FUNDS: i128 = 10_000_000_000_000_000_000; // 10 tokens
+
+fn add_expiring_proposal(test: &EscrowTest, mode: ProposalMode) -> (String, u32) {
+    let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
+    let expires_at_ledger = test.env.ledger().sequence() + PROPOSAL_LIFETIME;
+
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &FUNDS,
+        &mode,
+        &ProposalTerms {
+            expires_at_ledger: Some(expires_at_ledger),
+            ..no_terms()
+        },
+    );
+
+    (stocken_id, expires_at_ledger)
+}
+
+fn expire(test: &EscrowTest) {
+    test.env.ledger().with_mut(|ledger| {
+        ledger.sequence_number += PROPOSAL_LIFETIME + 1;
+    });
+}
+
+#[test]
+fn add_proposal_already_expired() {
+    let test = EscrowTest::setup();
+
+    test.env.ledger().with_mut(|ledger| {
+        ledger.sequence_number = 100;
+    });
+
+    let res = test.escrow.try_add_proposal(
+        &String::from_str(&test.env, STOCKEN_ID_1),
+        &test.alice,
+        &test.token.address,
+        &FUNDS,
+        &ProposalMode::Direct,
+        &ProposalTerms {
+            expires_at_ledger: Some(99),
+            ..no_terms()
+        },
+    );
+
+    assert_eq!(res, Err(Ok(EscrowError::ProposalExpired.into())));
+}
+
+#[test]
+fn register_escrow_expired() {
+    let test = EscrowTest::setup();
+
+    let (stocken_id, expires_at_ledger) = add_expiring_proposal(&test, ProposalMode::Direct);
+
+    assert_eq!(
+        test.escrow.get_proposal(&stocken_id).expires_at_ledger,
+        Some(expires_at_ledger)
+    );
+
+    expire(&test);
+
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+    let res = test.escrow.mock_all_auths().try_register_escrow(
+        &stocken_id,
+        &signaturit_id,
+        &test.bob,
+        &FUNDS,
+        &FUNDS,
+        &1,
+    );
+
+    assert_eq!(res, Err(Ok(EscrowError::ProposalExpired.into())));
+}
+
+#[test]
+fn expire_proposal() {
+    let test = EscrowTest::setup();
+
+    let (stocken_id, expires_at_ledger) = add_expiring_proposal(&test, ProposalMode::Direct);
+
+    let res = test.escrow.try_expire_proposal(&stocken_id);
+    assert_eq!(res, Err(Ok(EscrowError::ProposalNotExpired.into())));
+
+    expire(&test);
+
+    // Anyone can expire it
+    test.escrow.expire_proposal(&stocken_id);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (
+            EscrowEvent::ProposalExpired(stocken_id.clone(), test.alice.clone(), expires_at_ledger)
+                .name(),
+        )
+            .into_val(&test.env),
+        (stocken_id.clone(), test.alice.clone(), expires_at_ledger).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "ProposalExpired event not present"
+    );
+
+    assert_eq!(
+        test.escrow.get_proposal(&stocken_id).status,
+        ProposalStatus::Canceled
+    );
+    assert_eq!(
+        test.escrow
+            .list_proposals(&ProposalStatus::Canceled, &0, &10)
+            .len(),
+        1
+    );
+
+    let res = test.escrow.try_expire_proposal(&stocken_id);
+    assert_eq!(res, Err(Ok(EscrowError::PickedOrCanceled.into())));
+}
+
+#[test]
+fn expire_proposal_without_expiration() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &FUNDS,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
+
+    expire(&test);
+
+    let res = test.escrow.try_expire_proposal(&stocken_id);
+    assert_eq!(res, Err(Ok(EscrowError::ProposalNotExpired.into())));
+}
+
+#[test]
+fn expire_pooled_proposal_refunds() {
+    let test = EscrowTest::setup();
+
+    let (stocken_id, _) = add_expiring_proposal(&test, ProposalMode::Pooled);
+
+    let balance_before_bob = test.token.balance(&test.bob);
+    let amount_bob: i128 = FUNDS / 2;
+    test.escrow
+        .mock_all_auths()
+        .contribute(&stocken_id, &test.bob, &amount_bob);
+
+    expire(&test);
+
+    // No more contributions after the expiration
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_contribute(&stocken_id, &test.bob, &amount_bob);
+
+    assert_eq!(res, Err(Ok(EscrowError::ProposalExpired.into())));
+
+    test.escrow.expire_proposal(&stocken_id);
+
+    assert_eq!(test.token.balance(&test.bob), balance_before_bob);
+    assert_eq!(test.token.balance(&test.escrow.address), 0);
+}
+
+#[test]
+fn expire_auction_proposal_refunds() {
+    let test = EscrowTest::setup();
+
+    let (stocken_id, expires_at_ledger) = add_expiring_proposal(&test, ProposalMode::Auction);
+
+    // The auction would accept bids after the expiration
+    test.escrow.mock_all_auths().open_auction(
+        &stocken_id,
+        &FUNDS,
+        &(expires_at_ledger + PROPOSAL_LIFETIME),
+    );
+
+    let balance_before_bob = test.token.balance(&test.bob);
+    test.escrow
+        .mock_all_auths()
+        .bid(&stocken_id, &test.bob, &FUNDS);
+
+    expire(&test);
+
+    test.escrow.expire_proposal(&stocken_id);
+
+    assert_eq!(test.token.balance(&test.bob), balance_before_bob);
+    assert_eq!(test.token.balance(&test.escrow.address), 0);
+
+    let auction = test.escrow.get_auction(&stocken_id);
+    assert_eq!(auction.status, AuctionStatus::Closed);
+    assert_eq!(auction.bids.len(), 0);
+
+    // The refunded bid can not win the auction
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_close_auction(&stocken_id, &signaturit_id);
+
+    assert_eq!(res, Err(Ok(EscrowError::PickedOrCanceled.into())));
+}
+
+#[test]
+fn accept_offer_expired() {
+    let test = EscrowTest::setup();
+
+    let (stocken_id, _) = add_expiring_proposal(&test, ProposalMode::Auction);
+
+    let carol = Address::generate(&test.env);
+    StellarAssetClient::new(&test.env, &test.token.address)
+        .mock_all_auths()
+        .mint(&carol, &(FUNDS * 2));
+
+    // The auction ends before the proposal expires
+    let end_ledger = test.env.ledger().sequence() + AUCTION_LEDGERS;
+    test.escrow
+        .mock_all_auths()
+        .open_auction(&stocken_id, &FUNDS, &end_ledger);
+    test.escrow
+        .mock_all_auths()
+        .bid(&stocken_id, &carol, &FUNDS);
+    test.escrow
+        .mock_all_auths()
+        .bid(&stocken_id, &test.bob, &(FUNDS * 2));
+
+    test.env.ledger().with_mut(|ledger| {
+        ledger.sequence_number = end_ledger + 1;
+    });
+
+    test.escrow.mock_all_auths().close_auction(
+        &stocken_id,
+        &String::from_str(&test.env, &Uuid::new_v4().to_string()),
+    );
+
+    test.oracle.mock_all_auths().signature_response(
+        &0,
+        &false,
+        &None,
+        &Some(FailureReason::Expired),
+    );
+
+    test.escrow
+        .mock_all_auths()
+        .offer_to_next_bidder(&stocken_id);
+
+    expire(&test);
+
+    // The offer can not be accepted once the proposal expired
+    let res = test.escrow.mock_all_auths().try_accept_offer(
+        &stocken_id,
+        &String::from_str(&test.env, &Uuid::new_v4().to_string()),
+    );
+
+    assert_eq!(res, Err(Ok(EscrowError::ProposalExpired.into())));
+}
+
+#[test]
+fn close_expired_auction() {
+    let test = EscrowTest::setup();
+
+    let (stocken_id, _) = add_expiring_proposal(&test, ProposalMode::Auction);
+
+    let end_ledger = test.env.ledger().sequence() + AUCTION_LEDGERS;
+    test.escrow
+        .mock_all_auths()
+        .open_auction(&stocken_id, &FUNDS, &end_ledger);
+    test.escrow
+        .mock_all_auths()
+        .bid(&stocken_id, &test.bob, &FUNDS);
+
+    expire(&test);
+
+    // The top bid can not pick the expired proposal
+    let res = test.escrow.mock_all_auths().try_close_auction(
+        &stocken_id,
+        &String::from_str(&test.env, &Uuid::new_v4().to_string()),
+    );
+
+    assert_eq!(res, Err(Ok(EscrowError::ProposalExpired.into())));
+    assert_eq!(
+        test.escrow.get_auction(&stocken_id).status,
+        AuctionStatus::Open
+    );
+}
@@ -0,0 +1,252 @@
+#![cfg(test)]
+
+extern crate std;
+use core::ops::Mul;
+use std::string::ToString;
+
+use crate::{
+    events::EscrowEvent,
+    test::{
+        escrow::{EscrowError, ProposalMode},
+        no_terms, EscrowTest, STOCKEN_ID_1,
+    },
+};
+use soroban_sdk::{
+    testutils::{Address as _, Events, MockAuth, MockAuthInvoke},
+    vec, Address, IntoVal, String,
+};
+use uuid::Uuid;
+
+#[test]
+fn set_fee() {
+    let test = EscrowTest::setup();
+
+    let fee_bps: u32 = 250;
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.admin,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "set_fee",
+                args: (fee_bps,).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_fee(&fee_bps);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::FeeUpdated(fee_bps).name(),).into_val(&test.env),
+        (fee_bps,).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "FeeUpdated event not present"
+    );
+
+    assert_eq!(test.escrow.get_fee(), fee_bps);
+
+    // More than the 10% of the funds
+    let res = test.escrow.mock_all_auths().try_set_fee(&1_001);
+
+    assert_eq!(res, Err(Ok(EscrowError::InvalidFee.into())));
+    assert_eq!(test.escrow.get_fee(), fee_bps);
+}
+
+#[test]
+fn set_fee_only_admin() {
+    let test = EscrowTest::setup();
+
+    let fee_bps: u32 = 250;
+
+    let res = test
+        .escrow
+        .mock_auths(&[MockAuth {
+            address: &test.alice,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "set_fee",
+                args: (fee_bps,).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_set_fee(&fee_bps);
+
+    assert!(res.is_err(), "calling with non-admin not reverted");
+    assert_eq!(test.escrow.get_fee(), 0);
+}
+
+#[test]
+fn set_treasury() {
+    let test = EscrowTest::setup();
+
+    let new_treasury = Address::generate(&test.env);
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.admin,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "set_treasury",
+                args: (new_treasury.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_treasury(&new_treasury);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::TreasuryUpdated(new_treasury.clone()).name(),).into_val(&test.env),
+        (new_treasury.clone(),).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "TreasuryUpdated event not present"
+    );
+
+    assert_eq!(test.escrow.get_treasury(), new_treasury);
+
+    // Only the admin can change it
+    let res = test
+        .escrow
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "set_treasury",
+                args: (test.bob.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_set_treasury(&test.bob);
+
+    assert!(res.is_err(), "calling with non-admin not reverted");
+    assert_eq!(test.escrow.get_treasury(), new_treasury);
+}
+
+#[test]
+fn fee_on_completed_signature() {
+    let test = EscrowTest::setup();
+
+    let fee_bps: u32 = 250; // 2.5%
+    test.escrow.mock_all_auths().set_fee(&fee_bps);
+
+    // Add a proposal
+    let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
+    let amount_asked: i128 = 10_000_000_000_000_000_000; // 10 tokens
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &amount_asked,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
+
+    // Pick the proposal
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+    let amount_to_give: i128 = amount_asked.mul(2);
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "register_escrow",
+                args: (
+                    stocken_id.clone(),
+                    signaturit_id.clone(),
+                    test.bob.clone(),
+                    amount_to_give,
+                    amount_to_give,
+                    1_u32,
+                )
+                    .into_val(&test.env),
+                sub_invokes: &[MockAuthInvoke {
+                    contract: &test.token.address,
+                    fn_name: "transfer",
+                    args: (
+                        test.bob.clone(),
+                        test.escrow.address.clone(),
+                        amount_to_give,
+                    )
+                        .into_val(&test.env),
+                    sub_invokes: &[],
+                }],
+            },
+        }])
+        .register_escrow(
+            &stocken_id,
+            &signaturit_id,
+            &test.bob,
+            &amount_to_give,
+            &amount_to_give,
+            &1,
+        );
+
+    // A fee raised after the registration does not apply to the signature
+    test.escrow.mock_all_auths().set_fee(&1_000);
+    assert_eq!(
+        test.escrow.get_signature_tx_escrow(&signaturit_id).fee_bps,
+        fee_bps
+    );
+
+    let balance_before_alice = test.token.balance(&test.alice);
+
+    let oracle_id: u32 = 0;
+    let signature_response = true;
+    let document_hash = Some(String::from_str(&test.env, "Test document hash"));
+
+    test.oracle.mock_all_auths().signature_response(
+        &oracle_id,
+        &signature_response,
+        &document_hash,
+        &None,
+    );
+
+    let fee: i128 = 500_000_000_000_000_000; // 0.5 tokens
+    let net_amount: i128 = amount_to_give - fee;
+    let nft_proof_ids = vec![&test.env, 0_u32];
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::SignedCompleted(
+            signaturit_id.clone(),
+            stocken_id.clone(),
+            test.bob.clone(),
+            test.alice.clone(),
+            amount_to_give,
+            fee,
+            net_amount,
+            nft_proof_ids.clone(),
+        )
+        .name(),)
+            .into_val(&test.env),
+        (
+            signaturit_id.clone(),
+            stocken_id.clone(),
+            test.bob.clone(),
+            test.alice.clone(),
+            amount_to_give,
+            fee,
+            net_amount,
+            nft_proof_ids.clone(),
+        )
+            .into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "SignedCompleted event not present"
+    );
+
+    assert_eq!(
+        test.token.balance(&test.alice),
+        balance_before_alice + net_amount
+    );
+    assert_eq!(test.token.balance(&test.treasury), fee);
+    assert_eq!(test.token.balance(&test.escrow.address), 0);
+}
@@ -0,0 +1,122 @@
+#![cfg(test)]
+
+use crate::test::{
+    escrow::{Checkpoint, EscrowError, NullableCheckpoint, SignatureStatus},
+    oracle::FailureReason,
+    EscrowTest, STOCKEN_ID_1,
+};
+use soroban_sdk::{testutils::Ledger, String};
+
+const FUNDS: i128 = 10_000_000_000_000_000_000; // 10 tokens
+
+fn set_ledger(test: &EscrowTest, sequence: u32, timestamp: u64) -> Checkpoint {
+    test.env.ledger().with_mut(|li| {
+        li.sequence_number = sequence;
+        li.timestamp = timestamp;
+    });
+
+    Checkpoint {
+        ledger: sequence,
+        timestamp,
+    }
+}
+
+#[test]
+fn lifecycle_completed() {
+    let test = EscrowTest::setup();
+
+    let created = set_ledger(&test, 100, 1_000);
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+
+    let lifecycle = test.escrow.get_lifecycle(&stocken_id);
+    assert_eq!(lifecycle.created, NullableCheckpoint::Some(created.clone()));
+    assert_eq!(lifecycle.picked, NullableCheckpoint::None);
+
+    let picked = set_ledger(&test, 200, 2_000);
+    let signaturit_id = test.register_escrow(&stocken_id, FUNDS, 1);
+
+    let completed = set_ledger(&test, 300, 3_000);
+    let document_hash = Some(String::from_str(&test.env, "Test document hash"));
+    test.oracle
+        .mock_all_auths()
+        .signature_response(&0, &true, &document_hash, &None);
+
+    let lifecycle = test.escrow.get_lifecycle(&stocken_id);
+    assert_eq!(lifecycle.created, NullableCheckpoint::Some(created));
+    assert_eq!(lifecycle.picked, NullableCheckpoint::Some(picked.clone()));
+    assert_eq!(
+        lifecycle.completed,
+        NullableCheckpoint::Some(completed.clone())
+    );
+    assert_eq!(lifecycle.failed, NullableCheckpoint::None);
+
+    let signature_process = test.escrow.get_signature_tx_escrow(&signaturit_id);
+    assert_eq!(signature_process.registered, picked);
+    assert_eq!(
+        signature_process.settled,
+        NullableCheckpoint::Some(completed)
+    );
+}
+
+#[test]
+fn history_keeps_failed_attempts() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+
+    let first_picked = set_ledger(&test, 200, 2_000);
+    let first_id = test.register_escrow(&stocken_id, FUNDS, 1);
+
+    let failed = set_ledger(&test, 300, 3_000);
+    test.oracle.mock_all_auths().signature_response(
+        &0,
+        &false,
+        &None,
+        &Some(FailureReason::Expired),
+    );
+
+    let picked = set_ledger(&test, 400, 4_000);
+    let second_id = test.register_escrow(&stocken_id, FUNDS, 1);
+
+    let lifecycle = test.escrow.get_lifecycle(&stocken_id);
+    assert_eq!(lifecycle.picked, NullableCheckpoint::Some(picked.clone()));
+    assert_eq!(lifecycle.failed, NullableCheckpoint::Some(failed.clone()));
+    assert_eq!(lifecycle.completed, NullableCheckpoint::None);
+
+    // Both attempts are listed in the order they were registered
+    let escrows = test.escrow.escrows_by_proposal(&stocken_id, &0, &10);
+    assert_eq!(escrows.len(), 2);
+    assert_eq!(escrows.get(0).unwrap().id, first_id);
+    assert_eq!(escrows.get(0).unwrap().status, SignatureStatus::Canceled);
+    assert_eq!(escrows.get(1).unwrap().id, second_id);
+    assert_eq!(escrows.get(1).unwrap().status, SignatureStatus::Progress);
+
+    // Each attempt keeps its own checkpoints after the proposal is picked again
+    assert_eq!(escrows.get(0).unwrap().registered, first_picked);
+    assert_eq!(
+        escrows.get(0).unwrap().settled,
+        NullableCheckpoint::Some(failed)
+    );
+    assert_eq!(escrows.get(1).unwrap().registered, picked);
+    assert_eq!(escrows.get(1).unwrap().settled, NullableCheckpoint::None);
+
+    let escrows = test.escrow.escrows_by_proposal(&stocken_id, &1, &1);
+    assert_eq!(escrows.len(), 1);
+    assert_eq!(escrows.get(0).unwrap().id, second_id);
+
+    let escrows = test.escrow.escrows_by_proposal(&stocken_id, &2, &10);
+    assert_eq!(escrows.len(), 0);
+}
+
+#[test]
+fn history_proposal_not_found() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
+
+    let res = test.escrow.try_get_lifecycle(&stocken_id);
+    assert_eq!(res, Err(Ok(EscrowError::ProposalNotFound.into())));
+
+    let res = test.escrow.try_escrows_by_proposal(&stocken_id, &0, &10);
+    assert_eq!(res, Err(Ok(EscrowError::ProposalNotFound.into())));
+}
@@ -2,8 +2,11 @@
 
 use crate::{
     events::EscrowEvent,
-    test::{escrow::EscrowError, EscrowTest},
+    test::{escrow::EscrowError, EscrowTest, SIGNATURE_TIMEOUT},
 };
+
+// 2.5% of platform fee
+const FEE_BPS: u32 = 250;
 use soroban_sdk::{testutils::Events, IntoVal};
 
 #[test]
@@ -11,12 +14,19 @@ fn before_initialization() {
     let test = EscrowTest::setup_non_init();
 
     let res_get_oracle = test.escrow.try_get_oracle();
-    let res_get_asset = test.escrow.try_get_asset();
+    let res_get_admin = test.escrow.try_get_admin();
     let res_get_nft_notes = test.escrow.try_get_nft_notes();
+    let res_get_signature_timeout = test.escrow.try_get_signature_timeout();
+    let res_get_treasury = test.escrow.try_get_treasury();
 
     assert_eq!(res_get_oracle, Err(Ok(EscrowError::NotInit.into())));
-    assert_eq!(res_get_asset, Err(Ok(EscrowError::NotInit.into())));
+    assert_eq!(res_get_admin, Err(Ok(EscrowError::NotInit.into())));
     assert_eq!(res_get_nft_notes, Err(Ok(EscrowError::NotInit.into())));
+    assert_eq!(
+        res_get_signature_timeout,
+        Err(Ok(EscrowError::NotInit.into()))
+    );
+    assert_eq!(res_get_treasury, Err(Ok(EscrowError::NotInit.into())));
 }
 
 #[test]
@@ -24,25 +34,37 @@ fn initialization() {
     let test = EscrowTest::setup_non_init();
 
     test.escrow.initialize(
+        &test.admin,
         &test.token.address,
         &test.oracle.address,
         &test.nft_notes.address,
+        &SIGNATURE_TIMEOUT,
+        &test.treasury,
+        &FEE_BPS,
     );
 
     // Check Initialized event
     let event_expected = (
         test.escrow.address.clone(),
         (EscrowEvent::Initialized(
+            test.admin.clone(),
             test.token.address.clone(),
             test.oracle.address.clone(),
             test.nft_notes.address.clone(),
+            SIGNATURE_TIMEOUT,
+            test.treasury.clone(),
+            FEE_BPS,
         )
         .name(),)
             .into_val(&test.env),
         (
+            &test.admin,
             &test.token.address,
             &test.oracle.address,
             &test.nft_notes.address,
+            SIGNATURE_TIMEOUT,
+            &test.treasury,
+            FEE_BPS,
         )
             .into_val(&test.env),
     );
@@ -52,9 +74,13 @@ fn initialization() {
         "initialized event not present"
     );
 
-    assert_eq!(test.escrow.get_asset(), test.token.address);
+    assert_eq!(test.escrow.get_admin(), test.admin);
+    assert!(test.escrow.is_asset_allowed(&test.token.address));
     assert_eq!(test.escrow.get_oracle(), test.oracle.address);
     assert_eq!(test.escrow.get_nft_notes(), test.nft_notes.address);
+    assert_eq!(test.escrow.get_signature_timeout(), SIGNATURE_TIMEOUT);
+    assert_eq!(test.escrow.get_treasury(), test.treasury);
+    assert_eq!(test.escrow.get_fee(), FEE_BPS);
 }
 
 #[test]
@@ -63,16 +89,42 @@ fn double_initialization() {
 
     // First initialization
     test.escrow.initialize(
+        &test.admin,
         &test.token.address,
         &test.oracle.address,
         &test.nft_notes.address,
+        &SIGNATURE_TIMEOUT,
+        &test.treasury,
+        &FEE_BPS,
     );
 
     let res = test.escrow.try_initialize(
+        &test.admin,
         &test.token.address,
         &test.oracle.address,
         &test.nft_notes.address,
+        &SIGNATURE_TIMEOUT,
+        &test.treasury,
+        &FEE_BPS,
     );
 
     assert_eq!(res, Err(Ok(EscrowError::AlreadyInitialized.into())));
 }
+
+#[test]
+fn initialization_invalid_fee() {
+    let test = EscrowTest::setup_non_init();
+
+    // More than the 10% of the funds
+    let res = test.escrow.try_initialize(
+        &test.admin,
+        &test.token.address,
+        &test.oracle.address,
+        &test.nft_notes.address,
+        &SIGNATURE_TIMEOUT,
+        &test.treasury,
+        &1_001,
+    );
+
+    assert_eq!(res, Err(Ok(EscrowError::InvalidFee.into())));
+}
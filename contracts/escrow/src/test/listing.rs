@@ -0,0 +1,168 @@
+#![cfg(test)]
+
+extern crate std;
+use std::string::ToString;
+
+use crate::test::{
+    escrow::ProposalStatus, oracle::FailureReason, EscrowTest, STOCKEN_ID_1, STOCKEN_ID_2,
+};
+use soroban_sdk::String;
+use uuid::Uuid;
+
+const FUNDS: i128 = 10_000_000_000_000_000_000; // 10 tokens
+
+#[test]
+fn list_proposals_by_status() {
+    let test = EscrowTest::setup();
+
+    let stocken_id_1 = test.add_proposal(STOCKEN_ID_1, FUNDS);
+    let stocken_id_2 = test.add_proposal(STOCKEN_ID_2, FUNDS);
+
+    let actived = test
+        .escrow
+        .list_proposals(&ProposalStatus::Actived, &0, &10);
+    assert_eq!(actived.len(), 2);
+    assert_eq!(actived.get(0).unwrap().escrow_id, stocken_id_1);
+    assert_eq!(actived.get(1).unwrap().escrow_id, stocken_id_2);
+
+    // Picking the proposal moves it to the other listing
+    test.register_escrow(&stocken_id_1, FUNDS, 1);
+
+    let actived = test
+        .escrow
+        .list_proposals(&ProposalStatus::Actived, &0, &10);
+    assert_eq!(actived.len(), 1);
+    assert_eq!(actived.get(0).unwrap().escrow_id, stocken_id_2);
+
+    let picked = test.escrow.list_proposals(&ProposalStatus::Picked, &0, &10);
+    assert_eq!(picked.len(), 1);
+    assert_eq!(picked.get(0).unwrap().escrow_id, stocken_id_1);
+
+    // Completed after the signature
+    let document_hash = Some(String::from_str(&test.env, "Test document hash"));
+    test.oracle
+        .mock_all_auths()
+        .signature_response(&0, &true, &document_hash, &None);
+
+    assert_eq!(
+        test.escrow
+            .list_proposals(&ProposalStatus::Picked, &0, &10)
+            .len(),
+        0
+    );
+    assert_eq!(
+        test.escrow
+            .list_proposals(&ProposalStatus::Completed, &0, &10)
+            .get(0)
+            .unwrap()
+            .escrow_id,
+        stocken_id_1
+    );
+
+    // Back to active after a failed signature
+    test.register_escrow(&stocken_id_2, FUNDS, 1);
+    test.oracle.mock_all_auths().signature_response(
+        &1,
+        &false,
+        &None,
+        &Some(FailureReason::Expired),
+    );
+
+    let actived = test
+        .escrow
+        .list_proposals(&ProposalStatus::Actived, &0, &10);
+    assert_eq!(actived.len(), 1);
+    assert_eq!(actived.get(0).unwrap().escrow_id, stocken_id_2);
+
+    // Canceled by the owner
+    test.escrow.mock_all_auths().cancel_proposal(&stocken_id_2);
+
+    assert_eq!(
+        test.escrow
+            .list_proposals(&ProposalStatus::Actived, &0, &10)
+            .len(),
+        0
+    );
+    assert_eq!(
+        test.escrow
+            .list_proposals(&ProposalStatus::Canceled, &0, &10)
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn proposals_by_owner_paginated() {
+    let test = EscrowTest::setup();
+
+    let stocken_id_1 = test.add_proposal(STOCKEN_ID_1, FUNDS);
+    let stocken_id_2 = test.add_proposal(STOCKEN_ID_2, FUNDS);
+
+    let page = test.escrow.proposals_by_owner(&test.alice, &0, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().escrow_id, stocken_id_1);
+
+    let page = test.escrow.proposals_by_owner(&test.alice, &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().escrow_id, stocken_id_2);
+
+    // Past the end of the listing
+    assert_eq!(test.escrow.proposals_by_owner(&test.alice, &2, &1).len(), 0);
+    assert_eq!(test.escrow.proposals_by_owner(&test.bob, &0, &10).len(), 0);
+}
+
+#[test]
+fn list_proposals_stable_cursor() {
+    let test = EscrowTest::setup();
+
+    let stocken_id_1 = test.add_proposal(STOCKEN_ID_1, FUNDS);
+    let stocken_id_2 = test.add_proposal(STOCKEN_ID_2, FUNDS);
+    let stocken_id_3 = test.add_proposal(&Uuid::new_v4().to_string(), FUNDS);
+
+    let page = test.escrow.list_proposals(&ProposalStatus::Actived, &0, &1);
+    assert_eq!(page.get(0).unwrap().escrow_id, stocken_id_1);
+
+    // The first proposal leaves the listing between two pages
+    test.register_escrow(&stocken_id_1, FUNDS, 1);
+
+    let page = test.escrow.list_proposals(&ProposalStatus::Actived, &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().escrow_id, stocken_id_2);
+
+    // Its position is left empty
+    assert_eq!(
+        test.escrow
+            .list_proposals(&ProposalStatus::Actived, &0, &1)
+            .len(),
+        0
+    );
+
+    let page = test
+        .escrow
+        .list_proposals(&ProposalStatus::Actived, &0, &10);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().escrow_id, stocken_id_2);
+    assert_eq!(page.get(1).unwrap().escrow_id, stocken_id_3);
+}
+
+#[test]
+fn escrows_by_buyer() {
+    let test = EscrowTest::setup();
+
+    let stocken_id_1 = test.add_proposal(STOCKEN_ID_1, FUNDS);
+    let stocken_id_2 = test.add_proposal(STOCKEN_ID_2, FUNDS);
+
+    let signaturit_id_1 = test.register_escrow(&stocken_id_1, FUNDS, 1);
+    let signaturit_id_2 = test.register_escrow(&stocken_id_2, FUNDS, 1);
+
+    let escrows = test.escrow.escrows_by_buyer(&test.bob, &0, &10);
+    assert_eq!(escrows.len(), 2);
+    assert_eq!(escrows.get(0).unwrap().id, signaturit_id_1);
+    assert_eq!(escrows.get(1).unwrap().id, signaturit_id_2);
+
+    let escrows = test.escrow.escrows_by_buyer(&test.bob, &1, &10);
+    assert_eq!(escrows.len(), 1);
+    assert_eq!(escrows.get(0).unwrap().id, signaturit_id_2);
+
+    assert_eq!(test.escrow.escrows_by_buyer(&test.alice, &0, &10).len(), 0);
+}
@@ -0,0 +1,116 @@
+#![cfg(test)]
+
+use crate::test::{
+    create_oracle_contract,
+    escrow::{EscrowError, NftRecipients},
+    EscrowTest, STOCKEN_ID_1, STOCKEN_ID_2,
+};
+use soroban_sdk::String;
+
+const FUNDS: i128 = 10_000_000_000_000_000_000; // 10 tokens
+
+fn register_escrow(test: &EscrowTest, recipients: NftRecipients) -> String {
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+    test.escrow.set_nft_recipients(&stocken_id, &recipients);
+
+    // Setting the recipients is the second version of the terms
+    test.register_escrow(&stocken_id, FUNDS, 2)
+}
+
+#[test]
+fn get_escrow_by_oracle_id() {
+    let test = EscrowTest::setup();
+
+    let signaturit_id = register_escrow(&test, NftRecipients::Buyer);
+
+    let signature_tx = test
+        .escrow
+        .get_escrow_by_oracle_id(&test.oracle.address, &0);
+
+    assert_eq!(signature_tx.id, signaturit_id);
+    assert_eq!(signature_tx.oracle_id, 0);
+
+    let res = test
+        .escrow
+        .try_get_escrow_by_oracle_id(&test.oracle.address, &1);
+
+    assert_eq!(res, Err(Ok(EscrowError::SignatureProcessNotFound.into())));
+}
+
+#[test]
+fn get_escrow_by_cancel_oracle_id() {
+    let test = EscrowTest::setup();
+
+    let signaturit_id = register_escrow(&test, NftRecipients::Buyer);
+
+    test.escrow
+        .mock_all_auths()
+        .request_cancel(&signaturit_id, &test.bob);
+
+    let signature_tx = test
+        .escrow
+        .get_escrow_by_oracle_id(&test.oracle.address, &1);
+
+    assert_eq!(signature_tx.id, signaturit_id);
+    assert_eq!(signature_tx.cancel_oracle_id, Some(1));
+
+    // The oracle id of the signature still leads to the same escrow
+    assert_eq!(
+        test.escrow
+            .get_escrow_by_oracle_id(&test.oracle.address, &0)
+            .id,
+        signaturit_id
+    );
+}
+
+#[test]
+fn get_escrow_by_oracle_id_after_set_oracle() {
+    let test = EscrowTest::setup();
+
+    let first_id = register_escrow(&test, NftRecipients::Buyer);
+
+    let new_oracle = create_oracle_contract(&test.env);
+    new_oracle.initialize(&test.admin);
+    test.escrow.mock_all_auths().set_oracle(&new_oracle.address);
+
+    let stocken_id = test.add_proposal(STOCKEN_ID_2, FUNDS);
+    let second_id = test.register_escrow(&stocken_id, FUNDS, 1);
+
+    // The new oracle counts its ids from 0 again
+    let signature_tx = test.escrow.get_escrow_by_oracle_id(&new_oracle.address, &0);
+    assert_eq!(signature_tx.id, second_id);
+    assert_eq!(signature_tx.oracle_id, 0);
+
+    // The ids of the previous oracle still lead to their escrows
+    let signature_tx = test
+        .escrow
+        .get_escrow_by_oracle_id(&test.oracle.address, &0);
+    assert_eq!(signature_tx.id, first_id);
+
+    let res = test
+        .escrow
+        .try_get_escrow_by_oracle_id(&new_oracle.address, &1);
+    assert_eq!(res, Err(Ok(EscrowError::SignatureProcessNotFound.into())));
+}
+
+#[test]
+fn get_escrow_by_nft() {
+    let test = EscrowTest::setup();
+
+    let signaturit_id = register_escrow(&test, NftRecipients::Both);
+
+    // Not minted until the signature is completed
+    let res = test.escrow.try_get_escrow_by_nft(&0);
+
+    assert_eq!(res, Err(Ok(EscrowError::SignatureProcessNotFound.into())));
+
+    let document_hash = Some(String::from_str(&test.env, "Test document hash"));
+    test.oracle
+        .mock_all_auths()
+        .signature_response(&0, &true, &document_hash, &None);
+
+    // Both proofs lead to the same signature tx escrow
+    assert_eq!(test.escrow.get_escrow_by_nft(&0).id, signaturit_id);
+    assert_eq!(test.escrow.get_escrow_by_nft(&1).id, signaturit_id);
+    assert_eq!(test.nft_notes.owner_of(&1), test.alice);
+}
@@ -0,0 +1,465 @@
+#![cfg(test)]
+
+extern crate std;
+use std::string::ToString;
+
+use crate::{
+    events::EscrowEvent,
+    test::{
+        escrow::{
+            EscrowError, MilestoneStatus, NullableAddress, NullableString, ProposalMode,
+            ProposalStatus, SignatureStatus,
+        },
+        no_terms,
+        oracle::FailureReason,
+        EscrowTest, STOCKEN_ID_1,
+    },
+};
+use soroban_sdk::{
+    testutils::{Events, MockAuth, MockAuthInvoke},
+    vec, IntoVal, String,
+};
+use uuid::Uuid;
+
+const MILESTONE_1: i128 = 2_000_000_000_000_000_000; // 2 tokens
+const MILESTONE_2: i128 = 3_000_000_000_000_000_000; // 3 tokens
+const MILESTONE_3: i128 = 5_000_000_000_000_000_000; // 5 tokens
+const TOTAL_FUNDS: i128 = MILESTONE_1 + MILESTONE_2 + MILESTONE_3;
+
+fn add_milestones_proposal(test: &EscrowTest) -> String {
+    let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &TOTAL_FUNDS,
+        &ProposalMode::Milestones,
+        &no_terms(),
+    );
+
+    // The milestones are the second version of the terms
+    test.escrow.mock_all_auths().set_milestones(
+        &stocken_id,
+        &vec![&test.env, MILESTONE_1, MILESTONE_2, MILESTONE_3],
+    );
+
+    stocken_id
+}
+
+fn register_milestone(test: &EscrowTest, stocken_id: &String) -> String {
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "register_milestone",
+                args: (stocken_id.clone(), signaturit_id.clone()).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .register_milestone(stocken_id, &signaturit_id);
+
+    signaturit_id
+}
+
+#[test]
+fn set_milestones() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &TOTAL_FUNDS,
+        &ProposalMode::Milestones,
+        &no_terms(),
+    );
+
+    // Not covering the minimun funds
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_set_milestones(&stocken_id, &vec![&test.env, MILESTONE_1, MILESTONE_2]);
+
+    assert_eq!(res, Err(Ok(EscrowError::InvalidMilestones.into())));
+
+    // A milestone without funds
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_set_milestones(&stocken_id, &vec![&test.env, TOTAL_FUNDS, 0]);
+
+    assert_eq!(res, Err(Ok(EscrowError::InvalidMilestones.into())));
+
+    let amounts = vec![&test.env, MILESTONE_1, MILESTONE_2, MILESTONE_3];
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.alice,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "set_milestones",
+                args: (stocken_id.clone(), amounts.clone()).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_milestones(&stocken_id, &amounts);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::MilestonesSet(stocken_id.clone(), 3, TOTAL_FUNDS).name(),)
+            .into_val(&test.env),
+        (stocken_id.clone(), 3_u32, TOTAL_FUNDS).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "MilestonesSet event not present"
+    );
+
+    let plan = test.escrow.get_milestones(&stocken_id);
+    assert_eq!(plan.buyer, NullableAddress::None);
+    assert_eq!(plan.released, 0);
+    assert_eq!(plan.milestones.len(), 3);
+
+    let milestone = test.escrow.get_milestone(&stocken_id, &1);
+    assert_eq!(milestone.amount, MILESTONE_2);
+    assert_eq!(milestone.signaturit_id, NullableString::None);
+    assert_eq!(milestone.status, MilestoneStatus::Pending);
+
+    let res = test.escrow.try_get_milestone(&stocken_id, &3);
+    assert_eq!(res, Err(Ok(EscrowError::MilestoneNotFound.into())));
+}
+
+#[test]
+fn set_milestones_not_milestones_proposal() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &TOTAL_FUNDS,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
+
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_set_milestones(&stocken_id, &vec![&test.env, TOTAL_FUNDS]);
+
+    assert_eq!(res, Err(Ok(EscrowError::NotMilestonesProposal.into())));
+}
+
+#[test]
+fn update_proposal_above_milestones() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = add_milestones_proposal(&test);
+
+    let res = test.escrow.mock_all_auths().try_update_proposal(
+        &stocken_id,
+        &(TOTAL_FUNDS + 1),
+        &None,
+        &None,
+    );
+
+    assert_eq!(res, Err(Ok(EscrowError::InvalidMilestones.into())));
+    assert_eq!(test.escrow.get_proposal(&stocken_id).version, 2);
+
+    // A price covered by the milestones can be set
+    test.escrow
+        .mock_all_auths()
+        .update_proposal(&stocken_id, &MILESTONE_1, &None, &None);
+
+    assert_eq!(test.escrow.get_proposal(&stocken_id).min_funds, MILESTONE_1);
+}
+
+#[test]
+fn fund_milestones_changed_terms() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = add_milestones_proposal(&test);
+
+    // The owner splits the price again before the buyer funds it
+    test.escrow
+        .mock_all_auths()
+        .set_milestones(&stocken_id, &vec![&test.env, TOTAL_FUNDS]);
+
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_fund_milestones(&stocken_id, &test.bob, &2);
+
+    assert_eq!(res, Err(Ok(EscrowError::VersionMismatch.into())));
+    assert_eq!(test.token.balance(&test.escrow.address), 0);
+
+    test.escrow
+        .mock_all_auths()
+        .fund_milestones(&stocken_id, &test.bob, &3);
+
+    assert_eq!(
+        test.escrow.get_proposal(&stocken_id).status,
+        ProposalStatus::Picked
+    );
+}
+
+#[test]
+fn register_milestone_not_funded() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = add_milestones_proposal(&test);
+
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_register_milestone(&stocken_id, &signaturit_id);
+
+    assert_eq!(res, Err(Ok(EscrowError::MilestonesNotFunded.into())));
+}
+
+#[test]
+fn milestones_released_in_order() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = add_milestones_proposal(&test);
+
+    let balance_before_alice = test.token.balance(&test.alice);
+    let balance_before_bob = test.token.balance(&test.bob);
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "fund_milestones",
+                args: (stocken_id.clone(), test.bob.clone(), 2_u32).into_val(&test.env),
+                sub_invokes: &[MockAuthInvoke {
+                    contract: &test.token.address,
+                    fn_name: "transfer",
+                    args: (test.bob.clone(), test.escrow.address.clone(), TOTAL_FUNDS)
+                        .into_val(&test.env),
+                    sub_invokes: &[],
+                }],
+            },
+        }])
+        .fund_milestones(&stocken_id, &test.bob, &2);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::MilestonesFunded(stocken_id.clone(), test.bob.clone(), TOTAL_FUNDS).name(),)
+            .into_val(&test.env),
+        (stocken_id.clone(), test.bob.clone(), TOTAL_FUNDS).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "MilestonesFunded event not present"
+    );
+
+    assert_eq!(test.token.balance(&test.escrow.address), TOTAL_FUNDS);
+    assert_eq!(
+        test.escrow.get_proposal(&stocken_id).status,
+        ProposalStatus::Picked
+    );
+    assert_eq!(
+        test.escrow.get_milestones(&stocken_id).buyer,
+        NullableAddress::Some(test.bob.clone())
+    );
+
+    // First milestone
+    let signaturit_id_1 = register_milestone(&test, &stocken_id);
+
+    let signature_tx = test.escrow.get_signature_tx_escrow(&signaturit_id_1);
+    assert_eq!(signature_tx.funds, MILESTONE_1);
+    assert_eq!(signature_tx.buyer, test.bob);
+
+    // The next milestone waits for the first one
+    let res = test.escrow.mock_all_auths().try_register_milestone(
+        &stocken_id,
+        &String::from_str(&test.env, &Uuid::new_v4().to_string()),
+    );
+
+    assert_eq!(res, Err(Ok(EscrowError::MilestoneInProgress.into())));
+
+    let document_hash = Some(String::from_str(&test.env, "Test document hash"));
+    test.oracle
+        .mock_all_auths()
+        .signature_response(&0, &true, &document_hash, &None);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::MilestoneReleased(
+            stocken_id.clone(),
+            0,
+            signaturit_id_1.clone(),
+            MILESTONE_1,
+            0,
+            MILESTONE_1,
+            vec![&test.env, 0],
+        )
+        .name(),)
+            .into_val(&test.env),
+        (
+            stocken_id.clone(),
+            0_u32,
+            signaturit_id_1.clone(),
+            MILESTONE_1,
+            0_i128,
+            MILESTONE_1,
+            vec![&test.env, 0_u32],
+        )
+            .into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "MilestoneReleased event not present"
+    );
+
+    // Only the first tranche is released
+    assert_eq!(
+        test.token.balance(&test.alice),
+        balance_before_alice + MILESTONE_1
+    );
+    assert_eq!(
+        test.token.balance(&test.escrow.address),
+        MILESTONE_2 + MILESTONE_3
+    );
+    assert_eq!(
+        test.escrow.get_milestone(&stocken_id, &0).status,
+        MilestoneStatus::Released
+    );
+    assert_eq!(
+        test.escrow.get_milestones(&stocken_id).released,
+        MILESTONE_1
+    );
+    assert_eq!(
+        test.escrow.get_proposal(&stocken_id).status,
+        ProposalStatus::Picked
+    );
+
+    // Second and third milestones
+    let signaturit_id_2 = register_milestone(&test, &stocken_id);
+    test.oracle
+        .mock_all_auths()
+        .signature_response(&1, &true, &document_hash, &None);
+
+    let signaturit_id_3 = register_milestone(&test, &stocken_id);
+    test.oracle
+        .mock_all_auths()
+        .signature_response(&2, &true, &document_hash, &None);
+
+    assert_eq!(
+        test.escrow.get_signature_tx_escrow(&signaturit_id_2).status,
+        SignatureStatus::Completed
+    );
+    assert_eq!(
+        test.escrow
+            .get_signature_tx_escrow(&signaturit_id_3)
+            .nft_proof_ids,
+        vec![&test.env, 2]
+    );
+
+    assert_eq!(
+        test.token.balance(&test.alice),
+        balance_before_alice + TOTAL_FUNDS
+    );
+    assert_eq!(
+        test.token.balance(&test.bob),
+        balance_before_bob - TOTAL_FUNDS
+    );
+    assert_eq!(test.token.balance(&test.escrow.address), 0);
+    assert_eq!(
+        test.escrow.get_proposal(&stocken_id).status,
+        ProposalStatus::Completed
+    );
+
+    // Nothing left to register
+    let res = test.escrow.mock_all_auths().try_register_milestone(
+        &stocken_id,
+        &String::from_str(&test.env, &Uuid::new_v4().to_string()),
+    );
+
+    assert_eq!(res, Err(Ok(EscrowError::NoPendingMilestone.into())));
+}
+
+#[test]
+fn milestone_failed_refunds_remaining() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = add_milestones_proposal(&test);
+
+    let balance_before_alice = test.token.balance(&test.alice);
+    let balance_before_bob = test.token.balance(&test.bob);
+
+    test.escrow
+        .mock_all_auths()
+        .fund_milestones(&stocken_id, &test.bob, &2);
+
+    let document_hash = Some(String::from_str(&test.env, "Test document hash"));
+
+    register_milestone(&test, &stocken_id);
+    test.oracle
+        .mock_all_auths()
+        .signature_response(&0, &true, &document_hash, &None);
+
+    // The second milestone is not signed
+    let signaturit_id_2 = register_milestone(&test, &stocken_id);
+    test.oracle.mock_all_auths().signature_response(
+        &1,
+        &false,
+        &None,
+        &Some(FailureReason::Expired),
+    );
+
+    let refunded = MILESTONE_2 + MILESTONE_3;
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (
+            EscrowEvent::MilestonesRefunded(stocken_id.clone(), 1, test.bob.clone(), refunded)
+                .name(),
+        )
+            .into_val(&test.env),
+        (stocken_id.clone(), 1_u32, test.bob.clone(), refunded).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "MilestonesRefunded event not present"
+    );
+
+    assert_eq!(
+        test.token.balance(&test.alice),
+        balance_before_alice + MILESTONE_1
+    );
+    assert_eq!(
+        test.token.balance(&test.bob),
+        balance_before_bob - MILESTONE_1
+    );
+    assert_eq!(test.token.balance(&test.escrow.address), 0);
+
+    assert_eq!(
+        test.escrow.get_signature_tx_escrow(&signaturit_id_2).status,
+        SignatureStatus::Canceled
+    );
+    assert_eq!(
+        test.escrow.get_milestone(&stocken_id, &1).status,
+        MilestoneStatus::Refunded
+    );
+    assert_eq!(
+        test.escrow.get_milestone(&stocken_id, &2).status,
+        MilestoneStatus::Refunded
+    );
+    assert_eq!(
+        test.escrow.get_proposal(&stocken_id).status,
+        ProposalStatus::Canceled
+    );
+}
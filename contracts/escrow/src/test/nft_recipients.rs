@@ -0,0 +1,162 @@
+#![cfg(test)]
+
+use crate::{
+    events::EscrowEvent,
+    test::{
+        escrow::{EscrowError, NftRecipients},
+        EscrowTest, STOCKEN_ID_1,
+    },
+    types::NftRecipients as ContractNftRecipients,
+};
+use soroban_sdk::{
+    testutils::{Events, MockAuth, MockAuthInvoke},
+    vec, IntoVal, String,
+};
+
+const FUNDS: i128 = 10_000_000_000_000_000_000; // 10 tokens
+
+#[test]
+fn default_recipient_is_buyer() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+
+    assert_eq!(
+        test.escrow.get_nft_recipients(&stocken_id),
+        NftRecipients::Buyer
+    );
+
+    let signaturit_id = test.complete_signature(&stocken_id, FUNDS);
+
+    assert_eq!(
+        test.escrow
+            .get_signature_tx_escrow(&signaturit_id)
+            .nft_proof_ids,
+        vec![&test.env, 0]
+    );
+    assert_eq!(test.nft_notes.owner_of(&0), test.bob);
+}
+
+#[test]
+fn set_nft_recipients_both() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.alice,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "set_nft_recipients",
+                args: (stocken_id.clone(), NftRecipients::Both).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_nft_recipients(&stocken_id, &NftRecipients::Both);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (
+            EscrowEvent::NftRecipientsUpdated(stocken_id.clone(), ContractNftRecipients::Both)
+                .name(),
+        )
+            .into_val(&test.env),
+        (stocken_id.clone(), NftRecipients::Both).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "NftRecipientsUpdated event not present"
+    );
+
+    assert_eq!(
+        test.escrow.get_nft_recipients(&stocken_id),
+        NftRecipients::Both
+    );
+
+    let signaturit_id = test.complete_signature(&stocken_id, FUNDS);
+
+    // Each counterparty receives its own proof
+    assert_eq!(
+        test.escrow
+            .get_signature_tx_escrow(&signaturit_id)
+            .nft_proof_ids,
+        vec![&test.env, 0, 1]
+    );
+    assert_eq!(test.nft_notes.owner_of(&0), test.bob);
+    assert_eq!(test.nft_notes.owner_of(&1), test.alice);
+}
+
+#[test]
+fn set_nft_recipients_receiver() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+
+    test.escrow
+        .mock_all_auths()
+        .set_nft_recipients(&stocken_id, &NftRecipients::Receiver);
+
+    // The buyer that expected the proof on the first terms is rejected
+    assert_eq!(test.escrow.get_proposal(&stocken_id).version, 2);
+
+    let res = test.escrow.mock_all_auths().try_register_escrow(
+        &stocken_id,
+        &String::from_str(&test.env, "signaturit"),
+        &test.bob,
+        &FUNDS,
+        &FUNDS,
+        &1,
+    );
+
+    assert_eq!(res, Err(Ok(EscrowError::VersionMismatch.into())));
+
+    let signaturit_id = test.complete_signature(&stocken_id, FUNDS);
+
+    assert_eq!(
+        test.escrow
+            .get_signature_tx_escrow(&signaturit_id)
+            .nft_proof_ids,
+        vec![&test.env, 0]
+    );
+    assert_eq!(test.nft_notes.owner_of(&0), test.alice);
+}
+
+#[test]
+fn set_nft_recipients_only_owner() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+
+    let res = test
+        .escrow
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "set_nft_recipients",
+                args: (stocken_id.clone(), NftRecipients::Both).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_set_nft_recipients(&stocken_id, &NftRecipients::Both);
+
+    assert!(res.is_err(), "calling with non-owner not reverted");
+}
+
+#[test]
+fn set_nft_recipients_picked() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+
+    test.complete_signature(&stocken_id, FUNDS);
+
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_set_nft_recipients(&stocken_id, &NftRecipients::Both);
+
+    assert_eq!(res, Err(Ok(EscrowError::PickedOrCanceled.into())));
+}
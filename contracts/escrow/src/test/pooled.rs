@@ -0,0 +1,529 @@
+#![cfg(test)]
+
+extern crate std;
+use std::string::ToString;
+
+use crate::{
+    events::EscrowEvent,
+    test::{
+        escrow::{EscrowError, NullableString, ProposalMode, ProposalStatus, SignatureStatus},
+        no_terms,
+        oracle::FailureReason,
+        EscrowTest, STOCKEN_ID_1,
+    },
+};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger, MockAuth, MockAuthInvoke},
+    token::StellarAssetClient,
+    vec, Address, IntoVal, String,
+};
+use uuid::Uuid;
+
+fn add_pooled_proposal(test: &EscrowTest, amount_asked: i128) -> String {
+    let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &amount_asked,
+        &ProposalMode::Pooled,
+        &no_terms(),
+    );
+
+    stocken_id
+}
+
+fn create_contributor(test: &EscrowTest) -> Address {
+    let contributor = Address::generate(&test.env);
+    let amount: i128 = 1_000_000_000_000_000_000_000; // 1K tokens (18 decimals)
+
+    StellarAssetClient::new(&test.env, &test.token.address)
+        .mock_all_auths()
+        .mint(&contributor, &amount);
+
+    contributor
+}
+
+fn register_pooled(test: &EscrowTest, stocken_id: &String) -> String {
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.alice,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "register_pooled_escrow",
+                args: (stocken_id.clone(), signaturit_id.clone()).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .register_pooled_escrow(stocken_id, &signaturit_id);
+
+    signaturit_id
+}
+
+#[test]
+fn contribute() {
+    let test = EscrowTest::setup();
+
+    let amount_asked: i128 = 10_000_000_000_000_000_000; // 10 tokens
+    let stocken_id = add_pooled_proposal(&test, amount_asked);
+
+    let carol = create_contributor(&test);
+    let amount_bob: i128 = 4_000_000_000_000_000_000; // 4 tokens
+    let amount_carol: i128 = 6_000_000_000_000_000_000; // 6 tokens
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "contribute",
+                args: (stocken_id.clone(), test.bob.clone(), amount_bob).into_val(&test.env),
+                sub_invokes: &[MockAuthInvoke {
+                    contract: &test.token.address,
+                    fn_name: "transfer",
+                    args: (test.bob.clone(), test.escrow.address.clone(), amount_bob)
+                        .into_val(&test.env),
+                    sub_invokes: &[],
+                }],
+            },
+        }])
+        .contribute(&stocken_id, &test.bob, &amount_bob);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::ContributionAdded(
+            stocken_id.clone(),
+            test.bob.clone(),
+            amount_bob,
+            amount_bob,
+        )
+        .name(),)
+            .into_val(&test.env),
+        (stocken_id.clone(), test.bob.clone(), amount_bob, amount_bob).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "ContributionAdded event not present"
+    );
+
+    // Not enough funds yet to register the signature process
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_register_pooled_escrow(&stocken_id, &signaturit_id);
+
+    assert_eq!(res, Err(Ok(EscrowError::NoEnoughtFunds.into())));
+
+    test.escrow
+        .mock_all_auths()
+        .contribute(&stocken_id, &carol, &amount_carol);
+
+    let contributions = test.escrow.get_contributions(&stocken_id);
+    assert_eq!(contributions.len(), 2);
+    assert_eq!(contributions.get(0).unwrap().contributor, test.bob);
+    assert_eq!(contributions.get(0).unwrap().amount, amount_bob);
+    assert_eq!(contributions.get(1).unwrap().contributor, carol);
+    assert_eq!(contributions.get(1).unwrap().amount, amount_carol);
+
+    assert_eq!(
+        test.token.balance(&test.escrow.address),
+        amount_bob + amount_carol
+    );
+}
+
+#[test]
+fn contribute_accumulates() {
+    let test = EscrowTest::setup();
+
+    let amount_asked: i128 = 10_000_000_000_000_000_000; // 10 tokens
+    let stocken_id = add_pooled_proposal(&test, amount_asked);
+
+    let amount: i128 = 3_000_000_000_000_000_000; // 3 tokens
+
+    test.escrow
+        .mock_all_auths()
+        .contribute(&stocken_id, &test.bob, &amount);
+    test.escrow
+        .mock_all_auths()
+        .contribute(&stocken_id, &test.bob, &amount);
+
+    let contributions = test.escrow.get_contributions(&stocken_id);
+    assert_eq!(contributions.len(), 1);
+    assert_eq!(contributions.get(0).unwrap().amount, amount * 2);
+}
+
+#[test]
+fn contribute_wrong_mode() {
+    let test = EscrowTest::setup();
+
+    let amount_asked: i128 = 10_000_000_000_000_000_000; // 10 tokens
+
+    // A direct proposal does not accept contributions
+    let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &amount_asked,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
+
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_contribute(&stocken_id, &test.bob, &amount_asked);
+
+    assert_eq!(res, Err(Ok(EscrowError::NotPooledProposal.into())));
+
+    // A pooled proposal can not be picked by a single buyer
+    let test = EscrowTest::setup();
+    let stocken_id = add_pooled_proposal(&test, amount_asked);
+
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+    let res = test.escrow.mock_all_auths().try_register_escrow(
+        &stocken_id,
+        &signaturit_id,
+        &test.bob,
+        &amount_asked,
+        &amount_asked,
+        &1,
+    );
+
+    assert_eq!(res, Err(Ok(EscrowError::NotDirectProposal.into())));
+
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_contribute(&stocken_id, &test.bob, &0);
+
+    assert_eq!(res, Err(Ok(EscrowError::InvalidAmount.into())));
+}
+
+#[test]
+fn register_pooled_only_owner() {
+    let test = EscrowTest::setup();
+
+    let amount_asked: i128 = 10_000_000_000_000_000_000; // 10 tokens
+    let stocken_id = add_pooled_proposal(&test, amount_asked);
+
+    test.escrow
+        .mock_all_auths()
+        .contribute(&stocken_id, &test.bob, &amount_asked);
+
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+    let res = test
+        .escrow
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "register_pooled_escrow",
+                args: (stocken_id.clone(), signaturit_id.clone()).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_register_pooled_escrow(&stocken_id, &signaturit_id);
+
+    assert!(res.is_err(), "calling with non-owner not reverted");
+    assert_eq!(
+        test.escrow.get_proposal(&stocken_id).status,
+        ProposalStatus::Actived
+    );
+}
+
+#[test]
+fn pooled_success_signature() {
+    let test = EscrowTest::setup();
+
+    let amount_asked: i128 = 10_000_000_000_000_000_000; // 10 tokens
+    let stocken_id = add_pooled_proposal(&test, amount_asked);
+
+    let carol = create_contributor(&test);
+    let amount_bob: i128 = 4_000_000_000_000_000_000; // 4 tokens
+    let amount_carol: i128 = 6_000_000_000_000_000_000; // 6 tokens
+
+    test.escrow
+        .mock_all_auths()
+        .contribute(&stocken_id, &test.bob, &amount_bob);
+    test.escrow
+        .mock_all_auths()
+        .contribute(&stocken_id, &carol, &amount_carol);
+
+    let signaturit_id = register_pooled(&test, &stocken_id);
+
+    let signature_process = test.escrow.get_signature_tx_escrow(&signaturit_id);
+    assert_eq!(signature_process.buyer, test.escrow.address);
+
+    // Listed under each contributor and under the escrow
+    for buyer in [&test.bob, &carol, &test.escrow.address] {
+        let escrows = test.escrow.escrows_by_buyer(buyer, &0, &10);
+        assert_eq!(escrows.len(), 1);
+        assert_eq!(escrows.get(0).unwrap().id, signaturit_id);
+    }
+    assert_eq!(signature_process.funds, amount_bob + amount_carol);
+    assert_eq!(
+        test.escrow.get_proposal(&stocken_id).status,
+        ProposalStatus::Picked
+    );
+
+    // No more contributions once the signature process is registered
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_contribute(&stocken_id, &test.bob, &amount_bob);
+
+    assert_eq!(res, Err(Ok(EscrowError::PickedOrCanceled.into())));
+
+    let balance_before_alice = test.token.balance(&test.alice);
+
+    let oracle_id: u32 = 0;
+    let signature_response = true;
+    let document_hash = Some(String::from_str(&test.env, "Test document hash"));
+
+    test.oracle.mock_all_auths().signature_response(
+        &oracle_id,
+        &signature_response,
+        &document_hash,
+        &None,
+    );
+
+    // The notes of the contributors are recorded on the signature process
+    let nft_ids = vec![&test.env, 0_u32, 1_u32];
+    assert_eq!(
+        test.escrow
+            .get_signature_tx_escrow(&signaturit_id)
+            .nft_proof_ids,
+        nft_ids
+    );
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::PooledCompleted(
+            signaturit_id.clone(),
+            stocken_id.clone(),
+            test.alice.clone(),
+            amount_bob + amount_carol,
+            0,
+            amount_bob + amount_carol,
+            nft_ids.clone(),
+        )
+        .name(),)
+            .into_val(&test.env),
+        (
+            signaturit_id.clone(),
+            stocken_id.clone(),
+            test.alice.clone(),
+            amount_bob + amount_carol,
+            0_i128,
+            amount_bob + amount_carol,
+            nft_ids.clone(),
+        )
+            .into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "PooledCompleted event not present"
+    );
+
+    assert_eq!(
+        test.escrow.get_signature_tx_escrow(&signaturit_id).status,
+        SignatureStatus::Completed
+    );
+    assert_eq!(
+        test.escrow.get_proposal(&stocken_id).status,
+        ProposalStatus::Completed
+    );
+
+    assert_eq!(
+        test.token.balance(&test.alice),
+        balance_before_alice + amount_bob + amount_carol
+    );
+    assert_eq!(test.token.balance(&test.escrow.address), 0);
+
+    // Each contributor receives a note
+    let contributions = test.escrow.get_contributions(&stocken_id);
+    for contribution in contributions.iter() {
+        let nft_id = contribution.nft_id.expect("note not minted");
+        assert_eq!(test.nft_notes.owner_of(&nft_id), contribution.contributor);
+    }
+}
+
+#[test]
+fn pooled_failed_signature() {
+    let test = EscrowTest::setup();
+
+    let amount_asked: i128 = 10_000_000_000_000_000_000; // 10 tokens
+    let stocken_id = add_pooled_proposal(&test, amount_asked);
+
+    let carol = create_contributor(&test);
+    let amount_bob: i128 = 4_000_000_000_000_000_000; // 4 tokens
+    let amount_carol: i128 = 6_000_000_000_000_000_000; // 6 tokens
+
+    let balance_before_bob = test.token.balance(&test.bob);
+    let balance_before_carol = test.token.balance(&carol);
+
+    test.escrow
+        .mock_all_auths()
+        .contribute(&stocken_id, &test.bob, &amount_bob);
+    test.escrow
+        .mock_all_auths()
+        .contribute(&stocken_id, &carol, &amount_carol);
+
+    let signaturit_id = register_pooled(&test, &stocken_id);
+
+    let oracle_id: u32 = 0;
+    let signature_response = false;
+    let document_hash: Option<String> = None;
+
+    test.oracle.mock_all_auths().signature_response(
+        &oracle_id,
+        &signature_response,
+        &document_hash,
+        &Some(FailureReason::Expired),
+    );
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (
+            EscrowEvent::ContributionRefunded(stocken_id.clone(), carol.clone(), amount_carol)
+                .name(),
+        )
+            .into_val(&test.env),
+        (stocken_id.clone(), carol.clone(), amount_carol).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "ContributionRefunded event not present"
+    );
+
+    let proposal = test.escrow.get_proposal(&stocken_id);
+    assert_eq!(proposal.status, ProposalStatus::Actived);
+    assert_eq!(proposal.signature_tx_linked, NullableString::None);
+    assert_eq!(
+        test.escrow.get_signature_tx_escrow(&signaturit_id).status,
+        SignatureStatus::Canceled
+    );
+
+    assert_eq!(test.token.balance(&test.bob), balance_before_bob);
+    assert_eq!(test.token.balance(&carol), balance_before_carol);
+    assert_eq!(test.token.balance(&test.escrow.address), 0);
+    assert_eq!(test.escrow.get_contributions(&stocken_id).len(), 0);
+}
+
+#[test]
+fn cancel_pooled_proposal() {
+    let test = EscrowTest::setup();
+
+    let amount_asked: i128 = 10_000_000_000_000_000_000; // 10 tokens
+    let stocken_id = add_pooled_proposal(&test, amount_asked);
+
+    let amount_bob: i128 = 4_000_000_000_000_000_000; // 4 tokens
+    let balance_before_bob = test.token.balance(&test.bob);
+
+    test.escrow
+        .mock_all_auths()
+        .contribute(&stocken_id, &test.bob, &amount_bob);
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.alice,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "cancel_proposal",
+                args: (stocken_id.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .cancel_proposal(&stocken_id);
+
+    assert_eq!(
+        test.escrow.get_proposal(&stocken_id).status,
+        ProposalStatus::Canceled
+    );
+    assert_eq!(test.token.balance(&test.bob), balance_before_bob);
+    assert_eq!(test.token.balance(&test.escrow.address), 0);
+    assert_eq!(test.escrow.get_contributions(&stocken_id).len(), 0);
+}
+
+#[test]
+fn pooled_request_cancel_by_contributor() {
+    let test = EscrowTest::setup();
+
+    let amount_asked: i128 = 10_000_000_000_000_000_000; // 10 tokens
+    let stocken_id = add_pooled_proposal(&test, amount_asked);
+
+    test.escrow
+        .mock_all_auths()
+        .contribute(&stocken_id, &test.bob, &amount_asked);
+
+    let signaturit_id = register_pooled(&test, &stocken_id);
+
+    // Carol did not contribute to the proposal
+    let carol = create_contributor(&test);
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_request_cancel(&signaturit_id, &carol);
+
+    assert_eq!(res, Err(Ok(EscrowError::NotAParty.into())));
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "request_cancel",
+                args: (signaturit_id.clone(), test.bob.clone()).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .request_cancel(&signaturit_id, &test.bob);
+
+    assert_eq!(
+        test.escrow
+            .get_signature_tx_escrow(&signaturit_id)
+            .cancel_oracle_id,
+        Some(1)
+    );
+}
+
+#[test]
+fn pooled_reclaim_funds_by_anyone() {
+    let test = EscrowTest::setup();
+
+    let amount_asked: i128 = 10_000_000_000_000_000_000; // 10 tokens
+    let stocken_id = add_pooled_proposal(&test, amount_asked);
+
+    let balance_before_bob = test.token.balance(&test.bob);
+
+    test.escrow
+        .mock_all_auths()
+        .contribute(&stocken_id, &test.bob, &amount_asked);
+
+    let signaturit_id = register_pooled(&test, &stocken_id);
+
+    let deadline_ledger = test
+        .escrow
+        .get_signature_tx_escrow(&signaturit_id)
+        .deadline_ledger;
+    test.env.ledger().with_mut(|ledger| {
+        ledger.sequence_number = deadline_ledger + 1;
+    });
+
+    // The funds go back to the contributors, so no authorization is needed
+    test.escrow.mock_auths(&[]).reclaim_funds(&signaturit_id);
+
+    assert_eq!(
+        test.escrow.get_signature_tx_escrow(&signaturit_id).status,
+        SignatureStatus::Expired
+    );
+    assert_eq!(test.token.balance(&test.bob), balance_before_bob);
+    assert_eq!(test.token.balance(&test.escrow.address), 0);
+}
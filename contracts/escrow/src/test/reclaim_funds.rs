@@ -0,0 +1,293 @@
+#![cfg(test)]
+
+extern crate std;
+use core::ops::Mul;
+use std::string::ToString;
+
+use crate::{
+    events::EscrowEvent,
+    test::{
+        escrow::{
+            EscrowError, NullableString, ProposalMode, ProposalStatus, ProposalTerms,
+            SignatureStatus,
+        },
+        no_terms, EscrowTest, SIGNATURE_TIMEOUT, STOCKEN_ID_1,
+    },
+};
+use soroban_sdk::{
+    testutils::{Events, Ledger, MockAuth, MockAuthInvoke},
+    IntoVal, String,
+};
+use uuid::Uuid;
+
+fn pick_proposal(
+    test: &EscrowTest,
+    signature_timeout: Option<u32>,
+    amount_to_give: i128,
+) -> (String, String) {
+    // Add a proposal
+    let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
+    let amount_asked: i128 = 10_000_000_000_000_000_000; // 10 tokens
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &amount_asked,
+        &ProposalMode::Direct,
+        &ProposalTerms {
+            signature_timeout,
+            ..no_terms()
+        },
+    );
+
+    // Pick the proposal
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "register_escrow",
+                args: (
+                    stocken_id.clone(),
+                    signaturit_id.clone(),
+                    test.bob.clone(),
+                    amount_to_give,
+                    amount_to_give,
+                    1_u32,
+                )
+                    .into_val(&test.env),
+                sub_invokes: &[MockAuthInvoke {
+                    contract: &test.token.address,
+                    fn_name: "transfer",
+                    args: (
+                        test.bob.clone(),
+                        test.escrow.address.clone(),
+                        amount_to_give,
+                    )
+                        .into_val(&test.env),
+                    sub_invokes: &[],
+                }],
+            },
+        }])
+        .register_escrow(
+            &stocken_id,
+            &signaturit_id,
+            &test.bob,
+            &amount_to_give,
+            &amount_to_give,
+            &1,
+        );
+
+    (stocken_id, signaturit_id)
+}
+
+#[test]
+fn deadline_from_timeouts() {
+    let test = EscrowTest::setup();
+
+    let current_ledger = test.env.ledger().sequence();
+    let amount_to_give: i128 = 10_000_000_000_000_000_000_i128.mul(2);
+
+    // Without a timeout on the proposal, the global one is used
+    let (_, signaturit_id) = pick_proposal(&test, None, amount_to_give);
+    let signature_process = test.escrow.get_signature_tx_escrow(&signaturit_id);
+
+    assert_eq!(
+        signature_process.deadline_ledger,
+        current_ledger + SIGNATURE_TIMEOUT
+    );
+
+    // The proposal timeout takes precedence over the global one
+    let test = EscrowTest::setup();
+
+    let proposal_timeout: u32 = 100;
+    let (_, signaturit_id) = pick_proposal(&test, Some(proposal_timeout), amount_to_give);
+    let signature_process = test.escrow.get_signature_tx_escrow(&signaturit_id);
+
+    assert_eq!(
+        signature_process.deadline_ledger,
+        current_ledger + proposal_timeout
+    );
+
+    // A timeout too large for the ledger sequence never expires
+    let test = EscrowTest::setup();
+
+    let (_, signaturit_id) = pick_proposal(&test, Some(u32::MAX), amount_to_give);
+    let signature_process = test.escrow.get_signature_tx_escrow(&signaturit_id);
+
+    assert_eq!(signature_process.deadline_ledger, u32::MAX);
+}
+
+#[test]
+fn reclaim_funds() {
+    let test = EscrowTest::setup();
+
+    let proposal_timeout: u32 = 100;
+    let amount_to_give: i128 = 10_000_000_000_000_000_000_i128.mul(2);
+    let (stocken_id, signaturit_id) = pick_proposal(&test, Some(proposal_timeout), amount_to_give);
+
+    let balance_before_bob = test.token.balance(&test.bob);
+
+    // The oracle never answered
+    test.env.ledger().with_mut(|ledger| {
+        ledger.sequence_number += proposal_timeout + 1;
+    });
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "reclaim_funds",
+                args: (signaturit_id.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .reclaim_funds(&signaturit_id);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::FundsReclaimed(
+            signaturit_id.clone(),
+            stocken_id.clone(),
+            test.bob.clone(),
+            amount_to_give,
+        )
+        .name(),)
+            .into_val(&test.env),
+        (
+            signaturit_id.clone(),
+            stocken_id.clone(),
+            test.bob.clone(),
+            amount_to_give,
+        )
+            .into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "FundsReclaimed event not present"
+    );
+
+    let proposal = test.escrow.get_proposal(&stocken_id);
+    let signature_process = test.escrow.get_signature_tx_escrow(&signaturit_id);
+
+    assert_eq!(proposal.status, ProposalStatus::Actived);
+    assert_eq!(proposal.signature_tx_linked, NullableString::None);
+    assert_eq!(signature_process.status, SignatureStatus::Expired);
+
+    assert_eq!(
+        test.token.balance(&test.bob),
+        balance_before_bob + amount_to_give
+    );
+    assert_eq!(test.token.balance(&test.escrow.address), 0);
+
+    // A late response from the oracle is rejected
+    let oracle_id: u32 = 0;
+    let signature_response = true;
+    let document_hash = Some(String::from_str(&test.env, "Test document hash"));
+
+    let res = test.oracle.mock_all_auths().try_signature_response(
+        &oracle_id,
+        &signature_response,
+        &document_hash,
+        &None,
+    );
+
+    assert!(res.is_err(), "late oracle response not reverted");
+
+    assert_eq!(
+        test.escrow.get_signature_tx_escrow(&signaturit_id).status,
+        SignatureStatus::Expired
+    );
+    assert_eq!(
+        test.token.balance(&test.bob),
+        balance_before_bob + amount_to_give
+    );
+}
+
+#[test]
+fn reclaim_funds_before_deadline() {
+    let test = EscrowTest::setup();
+
+    let proposal_timeout: u32 = 100;
+    let amount_to_give: i128 = 10_000_000_000_000_000_000_i128.mul(2);
+    let (_, signaturit_id) = pick_proposal(&test, Some(proposal_timeout), amount_to_give);
+
+    // Exactly on the deadline ledger the oracle can still answer
+    test.env.ledger().with_mut(|ledger| {
+        ledger.sequence_number += proposal_timeout;
+    });
+
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_reclaim_funds(&signaturit_id);
+
+    assert_eq!(res, Err(Ok(EscrowError::DeadlineNotReached.into())));
+    assert_eq!(test.token.balance(&test.escrow.address), amount_to_give);
+}
+
+#[test]
+fn reclaim_funds_only_buyer() {
+    let test = EscrowTest::setup();
+
+    let proposal_timeout: u32 = 100;
+    let amount_to_give: i128 = 10_000_000_000_000_000_000_i128.mul(2);
+    let (_, signaturit_id) = pick_proposal(&test, Some(proposal_timeout), amount_to_give);
+
+    test.env.ledger().with_mut(|ledger| {
+        ledger.sequence_number += proposal_timeout + 1;
+    });
+
+    // Alice is the owner of the proposal, not the buyer
+    let res = test
+        .escrow
+        .mock_auths(&[MockAuth {
+            address: &test.alice,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "reclaim_funds",
+                args: (signaturit_id.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_reclaim_funds(&signaturit_id);
+
+    assert!(res.is_err(), "calling with non-buyer not reverted");
+    assert_eq!(test.token.balance(&test.escrow.address), amount_to_give);
+}
+
+#[test]
+fn reclaim_funds_after_response() {
+    let test = EscrowTest::setup();
+
+    let proposal_timeout: u32 = 100;
+    let amount_to_give: i128 = 10_000_000_000_000_000_000_i128.mul(2);
+    let (_, signaturit_id) = pick_proposal(&test, Some(proposal_timeout), amount_to_give);
+
+    // The oracle answered on time
+    let oracle_id: u32 = 0;
+    let signature_response = true;
+    let document_hash = Some(String::from_str(&test.env, "Test document hash"));
+
+    test.oracle.mock_all_auths().signature_response(
+        &oracle_id,
+        &signature_response,
+        &document_hash,
+        &None,
+    );
+
+    test.env.ledger().with_mut(|ledger| {
+        ledger.sequence_number += proposal_timeout + 1;
+    });
+
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_reclaim_funds(&signaturit_id);
+
+    assert_eq!(res, Err(Ok(EscrowError::SignatureNotInProgress.into())));
+}
@@ -0,0 +1,222 @@
+#![cfg(test)]
+
+use crate::{
+    events::EscrowEvent,
+    test::{
+        escrow::{EscrowError, Reconciliation},
+        oracle::FailureReason,
+        EscrowTest, STOCKEN_ID_1,
+    },
+    types::DataKey,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Events, MockAuth, MockAuthInvoke},
+    Address, IntoVal, String,
+};
+use storage::Storage;
+
+const FUNDS: i128 = 10_000_000_000_000_000_000; // 10 tokens
+const MISTAKEN: i128 = 1_000_000_000_000_000_000; // 1 token
+
+fn register_escrow(test: &EscrowTest) {
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+    test.register_escrow(&stocken_id, FUNDS, 1);
+}
+
+fn sweep_as(test: &EscrowTest, caller: &Address, to: &Address) {
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: caller,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "sweep_surplus",
+                args: (test.token.address.clone(), to.clone()).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .sweep_surplus(&test.token.address, to);
+}
+
+#[test]
+fn liability_follows_signature() {
+    let test = EscrowTest::setup();
+
+    register_escrow(&test);
+
+    assert_eq!(
+        test.escrow.reconcile(&test.token.address),
+        Reconciliation {
+            liability: FUNDS,
+            balance: FUNDS,
+            surplus: 0,
+        }
+    );
+
+    let document_hash = Some(String::from_str(&test.env, "Test document hash"));
+    test.oracle
+        .mock_all_auths()
+        .signature_response(&0, &true, &document_hash, &None);
+
+    // The funds are released to the seller and the treasury
+    assert_eq!(
+        test.escrow.reconcile(&test.token.address),
+        Reconciliation {
+            liability: 0,
+            balance: 0,
+            surplus: 0,
+        }
+    );
+}
+
+#[test]
+fn liability_follows_failed_signature() {
+    let test = EscrowTest::setup();
+
+    register_escrow(&test);
+
+    test.oracle.mock_all_auths().signature_response(
+        &0,
+        &false,
+        &None,
+        &Some(FailureReason::Expired),
+    );
+
+    // The funds are returned to the buyer
+    assert_eq!(
+        test.escrow.reconcile(&test.token.address),
+        Reconciliation {
+            liability: 0,
+            balance: 0,
+            surplus: 0,
+        }
+    );
+}
+
+#[test]
+fn sweep_surplus() {
+    let test = EscrowTest::setup();
+
+    register_escrow(&test);
+
+    // Bob sends tokens to the escrow by mistake
+    test.token
+        .mock_all_auths()
+        .transfer(&test.bob, &test.escrow.address, &MISTAKEN);
+
+    assert_eq!(
+        test.escrow.reconcile(&test.token.address),
+        Reconciliation {
+            liability: FUNDS,
+            balance: FUNDS + MISTAKEN,
+            surplus: MISTAKEN,
+        }
+    );
+
+    let balance_before_bob = test.token.balance(&test.bob);
+
+    sweep_as(&test, &test.admin, &test.bob);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (
+            EscrowEvent::SurplusSwept(test.token.address.clone(), test.bob.clone(), MISTAKEN)
+                .name(),
+        )
+            .into_val(&test.env),
+        (test.token.address.clone(), test.bob.clone(), MISTAKEN).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "SurplusSwept event not present"
+    );
+
+    // The funds of the signature process stay in the escrow
+    assert_eq!(test.token.balance(&test.bob), balance_before_bob + MISTAKEN);
+    assert_eq!(test.token.balance(&test.escrow.address), FUNDS);
+
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_sweep_surplus(&test.token.address, &test.bob);
+
+    assert_eq!(res, Err(Ok(EscrowError::NothingToClaim.into())));
+}
+
+#[test]
+fn sweep_surplus_only_admin() {
+    let test = EscrowTest::setup();
+
+    test.token
+        .mock_all_auths()
+        .transfer(&test.bob, &test.escrow.address, &MISTAKEN);
+
+    let thief = Address::generate(&test.env);
+
+    let res = test
+        .escrow
+        .mock_auths(&[MockAuth {
+            address: &thief,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "sweep_surplus",
+                args: (test.token.address.clone(), thief.clone()).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_sweep_surplus(&test.token.address, &thief);
+
+    assert!(res.is_err(), "calling with non-admin not reverted");
+
+    assert_eq!(test.token.balance(&test.escrow.address), MISTAKEN);
+}
+
+#[test]
+fn sweep_surplus_without_surplus() {
+    let test = EscrowTest::setup();
+
+    register_escrow(&test);
+
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_sweep_surplus(&test.token.address, &test.admin);
+
+    assert_eq!(res, Err(Ok(EscrowError::NothingToClaim.into())));
+
+    assert_eq!(test.token.balance(&test.escrow.address), FUNDS);
+}
+
+#[test]
+fn sweep_surplus_pending_migration() {
+    let test = EscrowTest::setup();
+
+    register_escrow(&test);
+
+    test.token
+        .mock_all_auths()
+        .transfer(&test.bob, &test.escrow.address, &MISTAKEN);
+
+    // An upgrade whose records are not migrated yet
+    test.env.as_contract(&test.escrow.address, || {
+        DataKey::SchemaVersion.set(&test.env, &0_u32);
+    });
+
+    assert_eq!(
+        test.escrow.reconcile(&test.token.address),
+        Reconciliation {
+            liability: FUNDS + MISTAKEN,
+            balance: FUNDS + MISTAKEN,
+            surplus: 0,
+        }
+    );
+
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_sweep_surplus(&test.token.address, &test.admin);
+
+    assert_eq!(res, Err(Ok(EscrowError::NothingToClaim.into())));
+
+    assert_eq!(test.token.balance(&test.escrow.address), FUNDS + MISTAKEN);
+}
@@ -6,7 +6,10 @@ use std::string::ToString;
 
 use crate::{
     events::EscrowEvent,
-    test::{escrow::EscrowError, EscrowTest, STOCKEN_ID_1, STOCKEN_ID_2},
+    test::{
+        escrow::{EscrowError, ProposalMode},
+        no_terms, EscrowTest, STOCKEN_ID_1, STOCKEN_ID_2,
+    },
 };
 use soroban_sdk::{
     testutils::{Events, MockAuth, MockAuthInvoke},
@@ -29,6 +32,8 @@ fn new_register_not_initialized() {
         &signaturit_id,
         &test.bob,
         &amount_to_give,
+        &amount_to_give,
+        &1,
     );
 
     assert_eq!(res, Err(Ok(EscrowError::NotInit.into())));
@@ -61,6 +66,8 @@ fn new_register_proposal_not_found() {
         &signaturit_id,
         &test.bob,
         &amount_to_give,
+        &amount_to_give,
+        &1,
     );
 
     assert_eq!(res, Err(Ok(EscrowError::ProposalNotFound.into())));
@@ -85,8 +92,14 @@ fn new_register_not_enough_funds() {
     // Add a proposal
     let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
     let amount_asked: i128 = 10_000_000_000_000_000_000; // 10 tokens
-    test.escrow
-        .add_proposal(&stocken_id, &test.alice, &amount_asked);
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &amount_asked,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
 
     // Pick a escrow
     // The sginaturit ID is an UUID
@@ -101,6 +114,8 @@ fn new_register_not_enough_funds() {
         &signaturit_id,
         &test.bob,
         &amount_to_give,
+        &amount_asked,
+        &1,
     );
 
     assert_eq!(res, Err(Ok(EscrowError::NoEnoughtFunds.into())));
@@ -130,8 +145,14 @@ fn new_register_proposal_already_picked() {
     // Add a proposal
     let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
     let amount_asked: i128 = 10_000_000_000_000_000_000; // 10 tokens
-    test.escrow
-        .add_proposal(&stocken_id, &test.alice, &amount_asked);
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &amount_asked,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
 
     // Pick a escrow
     // The sginaturit ID is an UUID
@@ -145,18 +166,38 @@ fn new_register_proposal_already_picked() {
         .mock_auths(&[MockAuth {
             address: &test.bob,
             invoke: &MockAuthInvoke {
-                contract: &test.token.address,
-                fn_name: "transfer",
+                contract: &test.escrow.address,
+                fn_name: "register_escrow",
                 args: (
+                    stocken_id.clone(),
+                    signaturit_id.clone(),
                     test.bob.clone(),
-                    test.escrow.address.clone(),
-                    amount_to_give.clone(),
+                    amount_to_give,
+                    amount_to_give,
+                    1_u32,
                 )
                     .into_val(&test.env),
-                sub_invokes: &[],
+                sub_invokes: &[MockAuthInvoke {
+                    contract: &test.token.address,
+                    fn_name: "transfer",
+                    args: (
+                        test.bob.clone(),
+                        test.escrow.address.clone(),
+                        amount_to_give,
+                    )
+                        .into_val(&test.env),
+                    sub_invokes: &[],
+                }],
             },
         }])
-        .register_escrow(&stocken_id, &signaturit_id, &test.bob, &amount_to_give);
+        .register_escrow(
+            &stocken_id,
+            &signaturit_id,
+            &test.bob,
+            &amount_to_give,
+            &amount_to_give,
+            &1,
+        );
 
     // The proposal owner should not receive the funds until the signature
     // process is completed
@@ -215,6 +256,8 @@ fn new_register_proposal_already_picked() {
         &signaturit_id_2,
         &test.bob,
         &amount_to_give_2,
+        &amount_to_give_2,
+        &1,
     );
 
     assert_eq!(res, Err(Ok(EscrowError::PickedOrCanceled.into())));
@@ -227,8 +270,14 @@ fn new_register_signature_process_exist() {
     // Add a proposal
     let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
     let amount_asked: i128 = 10_000_000_000_000_000_000; // 10 tokens
-    test.escrow
-        .add_proposal(&stocken_id, &test.alice, &amount_asked);
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &amount_asked,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
 
     // Pick a escrow
     // The sginaturit ID is an UUID
@@ -242,18 +291,38 @@ fn new_register_signature_process_exist() {
         .mock_auths(&[MockAuth {
             address: &test.bob,
             invoke: &MockAuthInvoke {
-                contract: &test.token.address,
-                fn_name: "transfer",
+                contract: &test.escrow.address,
+                fn_name: "register_escrow",
                 args: (
+                    stocken_id.clone(),
+                    signaturit_id.clone(),
                     test.bob.clone(),
-                    test.escrow.address.clone(),
-                    amount_to_give.clone(),
+                    amount_to_give,
+                    amount_to_give,
+                    1_u32,
                 )
                     .into_val(&test.env),
-                sub_invokes: &[],
+                sub_invokes: &[MockAuthInvoke {
+                    contract: &test.token.address,
+                    fn_name: "transfer",
+                    args: (
+                        test.bob.clone(),
+                        test.escrow.address.clone(),
+                        amount_to_give,
+                    )
+                        .into_val(&test.env),
+                    sub_invokes: &[],
+                }],
             },
         }])
-        .register_escrow(&stocken_id, &signaturit_id, &test.bob, &amount_to_give);
+        .register_escrow(
+            &stocken_id,
+            &signaturit_id,
+            &test.bob,
+            &amount_to_give,
+            &amount_to_give,
+            &1,
+        );
 
     // The proposal owner should not receive the funds until the signature
     // process is completed
@@ -307,8 +376,14 @@ fn new_register_signature_process_exist() {
     let stocken_id = String::from_str(&test.env, STOCKEN_ID_2);
     let amount_asked: i128 = 30_000_000_000_000_000_000; // 30 tokens
 
-    test.escrow
-        .add_proposal(&stocken_id, &test.alice, &amount_asked);
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &amount_asked,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
 
     //
 
@@ -326,15 +401,28 @@ fn new_register_signature_process_exist() {
         .mock_auths(&[MockAuth {
             address: &test.bob,
             invoke: &MockAuthInvoke {
-                contract: &test.token.address,
-                fn_name: "transfer",
+                contract: &test.escrow.address,
+                fn_name: "register_escrow",
                 args: (
+                    stocken_id.clone(),
+                    same_signaturit_id.clone(),
                     test.bob.clone(),
-                    test.escrow.address.clone(),
-                    amount_to_give_2.clone(),
+                    amount_to_give_2,
+                    amount_to_give_2,
+                    1_u32,
                 )
                     .into_val(&test.env),
-                sub_invokes: &[],
+                sub_invokes: &[MockAuthInvoke {
+                    contract: &test.token.address,
+                    fn_name: "transfer",
+                    args: (
+                        test.bob.clone(),
+                        test.escrow.address.clone(),
+                        amount_to_give_2,
+                    )
+                        .into_val(&test.env),
+                    sub_invokes: &[],
+                }],
             },
         }])
         .try_register_escrow(
@@ -342,6 +430,8 @@ fn new_register_signature_process_exist() {
             &same_signaturit_id,
             &test.bob,
             &amount_to_give_2,
+            &amount_to_give_2,
+            &1,
         );
 
     // The contract call will fail since the signaturit id already exist
@@ -373,8 +463,14 @@ fn new_register() {
     // Add a proposal
     let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
     let amount_asked: i128 = 10_000_000_000_000_000_000; // 10 tokens
-    test.escrow
-        .add_proposal(&stocken_id, &test.alice, &amount_asked);
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &amount_asked,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
 
     // Pick a escrow
     // The sginaturit ID is an UUID
@@ -388,18 +484,38 @@ fn new_register() {
         .mock_auths(&[MockAuth {
             address: &test.bob,
             invoke: &MockAuthInvoke {
-                contract: &test.token.address,
-                fn_name: "transfer",
+                contract: &test.escrow.address,
+                fn_name: "register_escrow",
                 args: (
+                    stocken_id.clone(),
+                    signaturit_id.clone(),
                     test.bob.clone(),
-                    test.escrow.address.clone(),
-                    amount_to_give.clone(),
+                    amount_to_give,
+                    amount_to_give,
+                    1_u32,
                 )
                     .into_val(&test.env),
-                sub_invokes: &[],
+                sub_invokes: &[MockAuthInvoke {
+                    contract: &test.token.address,
+                    fn_name: "transfer",
+                    args: (
+                        test.bob.clone(),
+                        test.escrow.address.clone(),
+                        amount_to_give,
+                    )
+                        .into_val(&test.env),
+                    sub_invokes: &[],
+                }],
             },
         }])
-        .register_escrow(&stocken_id, &signaturit_id, &test.bob, &amount_to_give);
+        .register_escrow(
+            &stocken_id,
+            &signaturit_id,
+            &test.bob,
+            &amount_to_give,
+            &amount_to_give,
+            &1,
+        );
 
     // The proposal owner should not receive the funds until the signature
     // process is completed
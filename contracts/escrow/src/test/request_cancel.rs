@@ -0,0 +1,296 @@
+#![cfg(test)]
+
+extern crate std;
+use core::ops::Mul;
+use std::string::ToString;
+
+use crate::{
+    events::EscrowEvent,
+    test::{
+        escrow::{EscrowError, NullableString, ProposalMode, ProposalStatus, SignatureStatus},
+        no_terms,
+        oracle::FailureReason,
+        EscrowTest, STOCKEN_ID_1,
+    },
+};
+use soroban_sdk::{
+    testutils::{Events, MockAuth, MockAuthInvoke},
+    IntoVal, String,
+};
+use uuid::Uuid;
+
+fn pick_proposal(test: &EscrowTest, amount_to_give: i128) -> (String, String) {
+    // Add a proposal
+    let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
+    let amount_asked: i128 = 10_000_000_000_000_000_000; // 10 tokens
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &amount_asked,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
+
+    // Pick the proposal
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "register_escrow",
+                args: (
+                    stocken_id.clone(),
+                    signaturit_id.clone(),
+                    test.bob.clone(),
+                    amount_to_give,
+                    amount_to_give,
+                    1_u32,
+                )
+                    .into_val(&test.env),
+                sub_invokes: &[MockAuthInvoke {
+                    contract: &test.token.address,
+                    fn_name: "transfer",
+                    args: (
+                        test.bob.clone(),
+                        test.escrow.address.clone(),
+                        amount_to_give,
+                    )
+                        .into_val(&test.env),
+                    sub_invokes: &[],
+                }],
+            },
+        }])
+        .register_escrow(
+            &stocken_id,
+            &signaturit_id,
+            &test.bob,
+            &amount_to_give,
+            &amount_to_give,
+            &1,
+        );
+
+    (stocken_id, signaturit_id)
+}
+
+#[test]
+fn request_cancel() {
+    let test = EscrowTest::setup();
+
+    let amount_to_give: i128 = 10_000_000_000_000_000_000_i128.mul(2);
+    let (stocken_id, signaturit_id) = pick_proposal(&test, amount_to_give);
+
+    let balance_before_bob = test.token.balance(&test.bob);
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "request_cancel",
+                args: (signaturit_id.clone(), test.bob.clone()).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .request_cancel(&signaturit_id, &test.bob);
+
+    // The register took the oracle id 0, so the cancel request is the next one
+    let cancel_oracle_id: u32 = 1;
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::CancelRequested(
+            signaturit_id.clone(),
+            stocken_id.clone(),
+            cancel_oracle_id,
+            test.bob.clone(),
+        )
+        .name(),)
+            .into_val(&test.env),
+        (
+            signaturit_id.clone(),
+            stocken_id.clone(),
+            cancel_oracle_id,
+            test.bob.clone(),
+        )
+            .into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "CancelRequested event not present"
+    );
+
+    let signature_process = test.escrow.get_signature_tx_escrow(&signaturit_id);
+    assert_eq!(signature_process.cancel_oracle_id, Some(cancel_oracle_id));
+    assert_eq!(signature_process.status, SignatureStatus::Progress);
+
+    // The listener canceled the process on Signaturit
+    let is_canceled = true;
+
+    test.oracle
+        .mock_auths(&[MockAuth {
+            address: &test.admin, // Oracle admin
+            invoke: &MockAuthInvoke {
+                contract: &test.oracle.address,
+                fn_name: "cancel_response",
+                args: (cancel_oracle_id, is_canceled).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .cancel_response(&cancel_oracle_id, &is_canceled);
+
+    let proposal = test.escrow.get_proposal(&stocken_id);
+    let signature_process = test.escrow.get_signature_tx_escrow(&signaturit_id);
+
+    assert_eq!(proposal.status, ProposalStatus::Actived);
+    assert_eq!(proposal.signature_tx_linked, NullableString::None);
+    assert_eq!(signature_process.status, SignatureStatus::Canceled);
+
+    assert_eq!(
+        test.token.balance(&test.bob),
+        balance_before_bob + amount_to_give
+    );
+    assert_eq!(test.token.balance(&test.escrow.address), 0);
+}
+
+#[test]
+fn request_cancel_only_buyer() {
+    let test = EscrowTest::setup();
+
+    let amount_to_give: i128 = 10_000_000_000_000_000_000_i128.mul(2);
+    let (_, signaturit_id) = pick_proposal(&test, amount_to_give);
+
+    // Alice is the owner of the proposal, not the buyer
+    let res = test
+        .escrow
+        .mock_auths(&[MockAuth {
+            address: &test.alice,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "request_cancel",
+                args: (signaturit_id.clone(), test.alice.clone()).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_request_cancel(&signaturit_id, &test.alice);
+
+    assert_eq!(res, Err(Ok(EscrowError::NotAParty.into())));
+
+    let signature_process = test.escrow.get_signature_tx_escrow(&signaturit_id);
+    assert_eq!(signature_process.cancel_oracle_id, None);
+}
+
+#[test]
+fn request_cancel_twice() {
+    let test = EscrowTest::setup();
+
+    let amount_to_give: i128 = 10_000_000_000_000_000_000_i128.mul(2);
+    let (_, signaturit_id) = pick_proposal(&test, amount_to_give);
+
+    test.escrow
+        .mock_all_auths()
+        .request_cancel(&signaturit_id, &test.bob);
+
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_request_cancel(&signaturit_id, &test.bob);
+
+    assert_eq!(res, Err(Ok(EscrowError::CancelAlreadyRequested.into())));
+}
+
+#[test]
+fn request_cancel_after_rejected() {
+    let test = EscrowTest::setup();
+
+    let amount_to_give: i128 = 10_000_000_000_000_000_000_i128.mul(2);
+    let (stocken_id, signaturit_id) = pick_proposal(&test, amount_to_give);
+
+    test.escrow
+        .mock_all_auths()
+        .request_cancel(&signaturit_id, &test.bob);
+
+    // The process could not be canceled on Signaturit
+    let cancel_oracle_id: u32 = 1;
+    let is_canceled = false;
+
+    test.oracle
+        .mock_all_auths()
+        .cancel_response(&cancel_oracle_id, &is_canceled);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::CancelRejected(
+            signaturit_id.clone(),
+            stocken_id.clone(),
+            cancel_oracle_id,
+            test.bob.clone(),
+        )
+        .name(),)
+            .into_val(&test.env),
+        (
+            signaturit_id.clone(),
+            stocken_id.clone(),
+            cancel_oracle_id,
+            test.bob.clone(),
+        )
+            .into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "CancelRejected event not present"
+    );
+
+    let signature_process = test.escrow.get_signature_tx_escrow(&signaturit_id);
+    assert_eq!(signature_process.cancel_oracle_id, None);
+    assert_eq!(signature_process.status, SignatureStatus::Progress);
+
+    // The buyer can ask for the cancel again
+    test.escrow
+        .mock_all_auths()
+        .request_cancel(&signaturit_id, &test.bob);
+
+    let cancel_oracle_id: u32 = 2;
+
+    let signature_process = test.escrow.get_signature_tx_escrow(&signaturit_id);
+    assert_eq!(signature_process.cancel_oracle_id, Some(cancel_oracle_id));
+
+    test.oracle
+        .mock_all_auths()
+        .cancel_response(&cancel_oracle_id, &true);
+
+    let signature_process = test.escrow.get_signature_tx_escrow(&signaturit_id);
+    assert_eq!(signature_process.status, SignatureStatus::Canceled);
+    assert_eq!(test.token.balance(&test.escrow.address), 0);
+}
+
+#[test]
+fn request_cancel_after_response() {
+    let test = EscrowTest::setup();
+
+    let amount_to_give: i128 = 10_000_000_000_000_000_000_i128.mul(2);
+    let (_, signaturit_id) = pick_proposal(&test, amount_to_give);
+
+    // The signature process failed before the cancel request
+    let oracle_id: u32 = 0;
+    let signature_response = false;
+    let document_hash: Option<String> = None;
+
+    test.oracle.mock_all_auths().signature_response(
+        &oracle_id,
+        &signature_response,
+        &document_hash,
+        &Some(FailureReason::Expired),
+    );
+
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_request_cancel(&signaturit_id, &test.bob);
+
+    assert_eq!(res, Err(Ok(EscrowError::SignatureNotInProgress.into())));
+}
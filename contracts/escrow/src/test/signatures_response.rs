@@ -7,13 +7,15 @@ use std::string::ToString;
 use crate::{
     events::EscrowEvent,
     test::{
-        escrow::{NullableString, ProposalStatus, SignatureStatus},
+        escrow::{NullableString, ProposalMode, ProposalStatus, SignatureStatus},
+        no_terms,
+        oracle::FailureReason,
         EscrowTest, STOCKEN_ID_1,
     },
 };
 use soroban_sdk::{
     testutils::{Events, MockAuth, MockAuthInvoke},
-    IntoVal, String,
+    vec, IntoVal, String,
 };
 use uuid::Uuid;
 
@@ -24,8 +26,14 @@ fn failed_signature() {
     // Add a proposal
     let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
     let amount_asked: i128 = 10_000_000_000_000_000_000; // 10 tokens
-    test.escrow
-        .add_proposal(&stocken_id, &test.alice, &amount_asked);
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &amount_asked,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
 
     // Pick a escrow
     // The sginaturit ID is an UUID
@@ -39,18 +47,38 @@ fn failed_signature() {
         .mock_auths(&[MockAuth {
             address: &test.bob,
             invoke: &MockAuthInvoke {
-                contract: &test.token.address,
-                fn_name: "transfer",
+                contract: &test.escrow.address,
+                fn_name: "register_escrow",
                 args: (
+                    stocken_id.clone(),
+                    signaturit_id.clone(),
                     test.bob.clone(),
-                    test.escrow.address.clone(),
-                    amount_to_give.clone(),
+                    amount_to_give,
+                    amount_to_give,
+                    1_u32,
                 )
                     .into_val(&test.env),
-                sub_invokes: &[],
+                sub_invokes: &[MockAuthInvoke {
+                    contract: &test.token.address,
+                    fn_name: "transfer",
+                    args: (
+                        test.bob.clone(),
+                        test.escrow.address.clone(),
+                        amount_to_give,
+                    )
+                        .into_val(&test.env),
+                    sub_invokes: &[],
+                }],
             },
         }])
-        .register_escrow(&stocken_id, &signaturit_id, &test.bob, &amount_to_give);
+        .register_escrow(
+            &stocken_id,
+            &signaturit_id,
+            &test.bob,
+            &amount_to_give,
+            &amount_to_give,
+            &1,
+        );
 
     // The proposal owner should not receive the funds until the signature
     // process is completed
@@ -90,11 +118,22 @@ fn failed_signature() {
             invoke: &MockAuthInvoke {
                 contract: &test.oracle.address,
                 fn_name: "signature_response",
-                args: (oracle_id, signature_response, document_hash.clone()).into_val(&test.env),
+                args: (
+                    oracle_id,
+                    signature_response,
+                    document_hash.clone(),
+                    Some(FailureReason::Expired),
+                )
+                    .into_val(&test.env),
                 sub_invokes: &[],
             },
         }])
-        .signature_response(&oracle_id, &signature_response, &document_hash);
+        .signature_response(
+            &oracle_id,
+            &signature_response,
+            &document_hash,
+            &Some(FailureReason::Expired),
+        );
 
     // Check SignedFailed event
     let event_expected = (
@@ -150,8 +189,14 @@ fn success_signature() {
     // Add a proposal
     let stocken_id = String::from_str(&test.env, STOCKEN_ID_1);
     let amount_asked: i128 = 10_000_000_000_000_000_000; // 10 tokens
-    test.escrow
-        .add_proposal(&stocken_id, &test.alice, &amount_asked);
+    test.escrow.add_proposal(
+        &stocken_id,
+        &test.alice,
+        &test.token.address,
+        &amount_asked,
+        &ProposalMode::Direct,
+        &no_terms(),
+    );
 
     // Pick a escrow
     // The sginaturit ID is an UUID
@@ -165,18 +210,38 @@ fn success_signature() {
         .mock_auths(&[MockAuth {
             address: &test.bob,
             invoke: &MockAuthInvoke {
-                contract: &test.token.address,
-                fn_name: "transfer",
+                contract: &test.escrow.address,
+                fn_name: "register_escrow",
                 args: (
+                    stocken_id.clone(),
+                    signaturit_id.clone(),
                     test.bob.clone(),
-                    test.escrow.address.clone(),
-                    amount_to_give.clone(),
+                    amount_to_give,
+                    amount_to_give,
+                    1_u32,
                 )
                     .into_val(&test.env),
-                sub_invokes: &[],
+                sub_invokes: &[MockAuthInvoke {
+                    contract: &test.token.address,
+                    fn_name: "transfer",
+                    args: (
+                        test.bob.clone(),
+                        test.escrow.address.clone(),
+                        amount_to_give,
+                    )
+                        .into_val(&test.env),
+                    sub_invokes: &[],
+                }],
             },
         }])
-        .register_escrow(&stocken_id, &signaturit_id, &test.bob, &amount_to_give);
+        .register_escrow(
+            &stocken_id,
+            &signaturit_id,
+            &test.bob,
+            &amount_to_give,
+            &amount_to_give,
+            &1,
+        );
 
     // The proposal owner should not receive the funds until the signature
     // process is completed
@@ -203,7 +268,7 @@ fn success_signature() {
     let oracle_id: u32 = 0;
     let signature_response = true; // FAILED
     let document_hash = Some(String::from_str(&test.env, "Test document hash"));
-    let token_nft_id: u32 = 0;
+    let nft_proof_ids = vec![&test.env, 0_u32];
 
     let before_response_alice_balance = test.token.balance(&test.alice);
     let before_response_bob_balance = test.token.balance(&test.bob);
@@ -217,11 +282,17 @@ fn success_signature() {
             invoke: &MockAuthInvoke {
                 contract: &test.oracle.address,
                 fn_name: "signature_response",
-                args: (oracle_id, signature_response, document_hash.clone()).into_val(&test.env),
+                args: (
+                    oracle_id,
+                    signature_response,
+                    document_hash.clone(),
+                    None::<FailureReason>,
+                )
+                    .into_val(&test.env),
                 sub_invokes: &[],
             },
         }])
-        .signature_response(&oracle_id, &signature_response, &document_hash);
+        .signature_response(&oracle_id, &signature_response, &document_hash, &None);
 
     // Check SignedCompleted event
     let event_expected = (
@@ -231,8 +302,10 @@ fn success_signature() {
             stocken_id.clone(),
             test.bob.clone(),
             test.alice.clone(),
-            amount_to_give.clone(),
-            token_nft_id.clone(),
+            amount_to_give,
+            0_i128, // No platform fee
+            amount_to_give,
+            nft_proof_ids.clone(),
         )
         .name(),)
             .into_val(&test.env),
@@ -241,8 +314,10 @@ fn success_signature() {
             stocken_id.clone(),
             test.bob.clone(),
             test.alice.clone(),
-            amount_to_give.clone(),
-            token_nft_id.clone(),
+            amount_to_give,
+            0_i128, // No platform fee
+            amount_to_give,
+            nft_proof_ids.clone(),
         )
             .into_val(&test.env),
     );
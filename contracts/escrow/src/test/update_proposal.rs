@@ -0,0 +1,145 @@
+#![cfg(test)]
+
+extern crate std;
+use std::string::ToString;
+
+use crate::{
+    events::EscrowEvent,
+    test::{
+        escrow::{EscrowError, ProposalStatus},
+        EscrowTest, STOCKEN_ID_1,
+    },
+};
+use soroban_sdk::{
+    testutils::{Address as _, Events, MockAuth, MockAuthInvoke},
+    Address, IntoVal, String,
+};
+use uuid::Uuid;
+
+const FUNDS: i128 = 10_000_000_000_000_000_000; // 10 tokens
+const NEW_FUNDS: i128 = 15_000_000_000_000_000_000; // 15 tokens
+
+#[test]
+fn update_proposal() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+
+    assert_eq!(test.escrow.get_proposal(&stocken_id).version, 1);
+
+    test.escrow
+        .mock_all_auths()
+        .update_proposal(&stocken_id, &NEW_FUNDS, &Some(100), &None);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::ProposalUpdated(stocken_id.clone(), 2, NEW_FUNDS).name(),)
+            .into_val(&test.env),
+        (stocken_id.clone(), 2_u32, NEW_FUNDS).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "ProposalUpdated event not present"
+    );
+
+    let propose = test.escrow.get_proposal(&stocken_id);
+
+    assert_eq!(propose.version, 2);
+    assert_eq!(propose.min_funds, NEW_FUNDS);
+    assert_eq!(propose.signature_timeout, Some(100));
+    assert_eq!(propose.status, ProposalStatus::Actived);
+}
+
+#[test]
+fn update_proposal_only_owner() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+    let random_user = Address::generate(&test.env);
+
+    let res = test
+        .escrow
+        .mock_auths(&[MockAuth {
+            address: &random_user,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "update_proposal",
+                args: (stocken_id.clone(), NEW_FUNDS, None::<u32>, None::<u32>).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_update_proposal(&stocken_id, &NEW_FUNDS, &None, &None);
+
+    assert!(res.is_err());
+    assert_eq!(test.escrow.get_proposal(&stocken_id).version, 1);
+}
+
+#[test]
+fn update_proposal_picked() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+    test.register_escrow(&stocken_id, FUNDS, 1);
+
+    let res =
+        test.escrow
+            .mock_all_auths()
+            .try_update_proposal(&stocken_id, &NEW_FUNDS, &None, &None);
+
+    assert_eq!(res, Err(Ok(EscrowError::PickedOrCanceled.into())));
+}
+
+#[test]
+fn register_escrow_version_mismatch() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+
+    test.escrow
+        .mock_all_auths()
+        .update_proposal(&stocken_id, &FUNDS, &None, &None);
+
+    let balance_before_bob = test.token.balance(&test.bob);
+
+    // The buyer agreed with the first version of the terms
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+    let res = test.escrow.mock_all_auths().try_register_escrow(
+        &stocken_id,
+        &signaturit_id,
+        &test.bob,
+        &FUNDS,
+        &FUNDS,
+        &1,
+    );
+
+    assert_eq!(res, Err(Ok(EscrowError::VersionMismatch.into())));
+    assert_eq!(test.token.balance(&test.bob), balance_before_bob);
+
+    // With the current version it can be picked
+    test.register_escrow(&stocken_id, FUNDS, 2);
+
+    assert_eq!(
+        test.escrow.get_proposal(&stocken_id).status,
+        ProposalStatus::Picked
+    );
+}
+
+#[test]
+fn register_escrow_price_above_max() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+    let res = test.escrow.mock_all_auths().try_register_escrow(
+        &stocken_id,
+        &signaturit_id,
+        &test.bob,
+        &NEW_FUNDS,
+        &(FUNDS - 1),
+        &1,
+    );
+
+    assert_eq!(res, Err(Ok(EscrowError::PriceAboveMax.into())));
+}
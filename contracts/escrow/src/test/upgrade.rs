@@ -0,0 +1,151 @@
+#![cfg(test)]
+
+use crate::{
+    events::EscrowEvent,
+    test::{
+        escrow::{self, EscrowError},
+        notes_nft, EscrowTest, STOCKEN_ID_1, STOCKEN_ID_2,
+    },
+};
+use soroban_sdk::{
+    testutils::{Events, MockAuth, MockAuthInvoke},
+    vec, IntoVal, String,
+};
+
+const FUNDS: i128 = 10_000_000_000_000_000_000; // 10 tokens
+
+#[test]
+fn upgrade() {
+    let test = EscrowTest::setup();
+
+    // Store records with the deployed code
+    let stocken_id_1 = test.add_proposal(STOCKEN_ID_1, FUNDS);
+    let stocken_id_2 = test.add_proposal(STOCKEN_ID_2, FUNDS);
+    let signaturit_id = test.register_escrow(&stocken_id_1, FUNDS, 1);
+
+    let new_wasm_hash = test.env.deployer().upload_contract_wasm(escrow::WASM);
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.admin,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "upgrade",
+                args: (new_wasm_hash.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .upgrade(&new_wasm_hash);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::Upgraded(new_wasm_hash.clone()).name(),).into_val(&test.env),
+        (new_wasm_hash,).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "Upgraded event not present"
+    );
+
+    // The upgraded code reads the stored records
+    assert_eq!(test.escrow.version(), 1);
+    assert_eq!(test.escrow.get_admin(), test.admin);
+
+    assert_eq!(
+        test.escrow.get_proposal(&stocken_id_1).status,
+        escrow::ProposalStatus::Picked
+    );
+
+    let proposals = test
+        .escrow
+        .list_proposals(&escrow::ProposalStatus::Actived, &0, &10);
+    assert_eq!(proposals.len(), 1);
+    assert_eq!(proposals.get(0).unwrap().escrow_id, stocken_id_2);
+
+    let escrows = test.escrow.escrows_by_buyer(&test.bob, &0, &10);
+    assert_eq!(escrows.len(), 1);
+    assert_eq!(escrows.get(0).unwrap().id, signaturit_id);
+
+    // The signature process registered before the upgrade completes
+    let document_hash = Some(String::from_str(&test.env, "Test document hash"));
+    test.oracle
+        .mock_all_auths()
+        .signature_response(&0, &true, &document_hash, &None);
+
+    let signature_tx = test.escrow.get_signature_tx_escrow(&signaturit_id);
+    assert_eq!(signature_tx.status, escrow::SignatureStatus::Completed);
+    assert_eq!(test.token.balance(&test.escrow.address), 0);
+}
+
+#[test]
+fn upgrade_only_admin() {
+    let test = EscrowTest::setup();
+
+    let new_wasm_hash = test.env.deployer().upload_contract_wasm(escrow::WASM);
+
+    let res = test
+        .escrow
+        .mock_auths(&[MockAuth {
+            address: &test.alice,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "upgrade",
+                args: (new_wasm_hash.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_upgrade(&new_wasm_hash);
+
+    assert!(res.is_err(), "calling with non-admin not reverted");
+}
+
+#[test]
+fn migrate_already_migrated() {
+    let test = EscrowTest::setup();
+
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_migrate(&vec![&test.env], &vec![&test.env]);
+
+    assert_eq!(res, Err(Ok(EscrowError::AlreadyMigrated.into())));
+
+    let res = test.escrow.mock_all_auths().try_finish_migration();
+
+    assert_eq!(res, Err(Ok(EscrowError::AlreadyMigrated.into())));
+}
+
+#[test]
+fn upgrade_nft_notes() {
+    let test = EscrowTest::setup();
+
+    let new_wasm_hash = test.env.deployer().upload_contract_wasm(notes_nft::WASM);
+
+    // The escrow is the admin of the NFT contract
+    test.escrow
+        .mock_all_auths()
+        .upgrade_nft_notes(&new_wasm_hash);
+
+    assert_eq!(test.nft_notes.version(), 1);
+
+    let res = test.escrow.mock_all_auths().try_migrate_nft_notes();
+
+    assert!(res.is_err(), "NFT notes already migrated not reverted");
+
+    // Only the admin of the escrow can upgrade it
+    let res = test
+        .escrow
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "upgrade_nft_notes",
+                args: (new_wasm_hash.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_upgrade_nft_notes(&new_wasm_hash);
+
+    assert!(res.is_err(), "calling with non-admin not reverted");
+}
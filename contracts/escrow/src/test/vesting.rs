@@ -0,0 +1,243 @@
+#![cfg(test)]
+
+use crate::{
+    events::EscrowEvent,
+    test::{
+        escrow::{EscrowError, VestingSchedule},
+        EscrowTest, STOCKEN_ID_1,
+    },
+};
+use soroban_sdk::{
+    testutils::{Events, Ledger, MockAuth, MockAuthInvoke},
+    IntoVal,
+};
+
+const FUNDS: i128 = 1_000;
+
+const CLIFF: u32 = 20;
+const DURATION: u32 = 100;
+const INTERVAL: u32 = 10;
+
+const START_LEDGER: u32 = 100;
+
+fn advance_to(test: &EscrowTest, elapsed: u32) {
+    test.env.ledger().with_mut(|ledger| {
+        ledger.sequence_number = START_LEDGER + elapsed;
+    });
+}
+
+#[test]
+fn set_vesting() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+
+    assert_eq!(test.escrow.get_vesting_schedule(&stocken_id), None);
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.alice,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "set_vesting",
+                args: (stocken_id.clone(), CLIFF, DURATION, INTERVAL).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_vesting(&stocken_id, &CLIFF, &DURATION, &INTERVAL);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::VestingSet(stocken_id.clone(), CLIFF, DURATION, INTERVAL).name(),)
+            .into_val(&test.env),
+        (stocken_id.clone(), CLIFF, DURATION, INTERVAL).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "VestingSet event not present"
+    );
+
+    assert_eq!(
+        test.escrow.get_vesting_schedule(&stocken_id),
+        Some(VestingSchedule {
+            cliff: CLIFF,
+            duration: DURATION,
+            interval: INTERVAL,
+        })
+    );
+
+    // A duration of 0 removes it
+    test.escrow
+        .mock_all_auths()
+        .set_vesting(&stocken_id, &0, &0, &0);
+
+    assert_eq!(test.escrow.get_vesting_schedule(&stocken_id), None);
+}
+
+#[test]
+fn set_vesting_invalid() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_set_vesting(&stocken_id, &CLIFF, &DURATION, &0);
+
+    assert_eq!(res, Err(Ok(EscrowError::InvalidVesting.into())));
+
+    let res = test.escrow.mock_all_auths().try_set_vesting(
+        &stocken_id,
+        &(DURATION + 1),
+        &DURATION,
+        &INTERVAL,
+    );
+
+    assert_eq!(res, Err(Ok(EscrowError::InvalidVesting.into())));
+}
+
+#[test]
+fn claim_vested() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+
+    test.escrow
+        .mock_all_auths()
+        .set_vesting(&stocken_id, &CLIFF, &DURATION, &INTERVAL);
+
+    let balance_before_alice = test.token.balance(&test.alice);
+
+    advance_to(&test, 0);
+
+    let signaturit_id = test.complete_signature(&stocken_id, FUNDS);
+
+    // The funds are held on the vesting position
+    assert_eq!(test.token.balance(&test.alice), balance_before_alice);
+    assert_eq!(test.token.balance(&test.escrow.address), FUNDS);
+
+    let vesting = test.escrow.get_vesting(&signaturit_id);
+
+    assert_eq!(vesting.total, FUNDS);
+    assert_eq!(vesting.claimed, 0);
+    assert_eq!(vesting.start_ledger, START_LEDGER);
+
+    // Nothing is unlocked before the cliff
+    advance_to(&test, CLIFF - 1);
+    assert_eq!(test.escrow.get_claimable(&signaturit_id), 0);
+
+    let res = test
+        .escrow
+        .mock_all_auths()
+        .try_claim_vested(&signaturit_id);
+    assert_eq!(res, Err(Ok(EscrowError::NothingToClaim.into())));
+
+    // After the cliff, the funds unlock once per interval
+    advance_to(&test, 25);
+    assert_eq!(test.escrow.get_claimable(&signaturit_id), 200);
+
+    test.escrow
+        .mock_auths(&[MockAuth {
+            address: &test.alice,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "claim_vested",
+                args: (signaturit_id.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .claim_vested(&signaturit_id);
+
+    let event_expected = (
+        test.escrow.address.clone(),
+        (EscrowEvent::VestedClaimed(signaturit_id.clone(), test.alice.clone(), 200, 200).name(),)
+            .into_val(&test.env),
+        (
+            signaturit_id.clone(),
+            test.alice.clone(),
+            200_i128,
+            200_i128,
+        )
+            .into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "VestedClaimed event not present"
+    );
+
+    assert_eq!(test.token.balance(&test.alice), balance_before_alice + 200);
+
+    advance_to(&test, 55);
+    test.escrow.mock_all_auths().claim_vested(&signaturit_id);
+
+    assert_eq!(test.token.balance(&test.alice), balance_before_alice + 500);
+
+    // Everything is unlocked after the duration
+    advance_to(&test, DURATION + 1);
+    test.escrow.mock_all_auths().claim_vested(&signaturit_id);
+
+    assert_eq!(
+        test.token.balance(&test.alice),
+        balance_before_alice + FUNDS
+    );
+    assert_eq!(test.token.balance(&test.escrow.address), 0);
+    assert_eq!(test.escrow.get_vesting(&signaturit_id).claimed, FUNDS);
+}
+
+#[test]
+fn claim_vested_only_receiver() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+
+    test.escrow
+        .mock_all_auths()
+        .set_vesting(&stocken_id, &CLIFF, &DURATION, &INTERVAL);
+
+    advance_to(&test, 0);
+
+    let signaturit_id = test.complete_signature(&stocken_id, FUNDS);
+
+    advance_to(&test, DURATION);
+
+    let res = test
+        .escrow
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.escrow.address,
+                fn_name: "claim_vested",
+                args: (signaturit_id.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_claim_vested(&signaturit_id);
+
+    assert!(res.is_err());
+    assert_eq!(test.token.balance(&test.escrow.address), FUNDS);
+}
+
+#[test]
+fn no_vesting() {
+    let test = EscrowTest::setup();
+
+    let stocken_id = test.add_proposal(STOCKEN_ID_1, FUNDS);
+
+    let balance_before_alice = test.token.balance(&test.alice);
+
+    advance_to(&test, 0);
+
+    let signaturit_id = test.complete_signature(&stocken_id, FUNDS);
+
+    // Without schedule the funds are released at once
+    assert_eq!(
+        test.token.balance(&test.alice),
+        balance_before_alice + FUNDS
+    );
+
+    let res = test.escrow.try_get_vesting(&signaturit_id);
+    assert_eq!(res, Err(Ok(EscrowError::NoVesting.into())));
+}
@@ -1,46 +1,157 @@
-use soroban_sdk::{contracterror, contracttype, Address, Env, IntoVal, String, TryFromVal, Val};
+use soroban_sdk::{
+    contracterror, contracttype, Address, Env, IntoVal, String, TryFromVal, Val, Vec,
+};
 
 #[contracttype]
 pub enum DataKey {
-    AssetAddress,
+    Admin,
+    PendingAdmin,
+    Paused,
+    SchemaVersion,
     OracleAddress,
     NFTNotesAddress,
+    SignatureTimeout,
+    Treasury,
+    FeeBps,
+    DisputeWindow,
+    Arbitrator,
+    Relayer,
+    AllowedAsset(Address),
     Proposal(String),
     SignatureProcess(String),
+    Contributions(String),
+    Milestones(String),
+    Auction(String),
+    PendingRelease(String),
+    Beneficiaries(String),
+    VestingSchedule(String),
+    Vesting(String),
+    Delivery(String),
+    NftRecipients(String),
+    Liability(Address),
+    Lifecycle(String),
+    OracleIdToEscrow(Address, u32),
+    NftToEscrow(u32),
+    ProposalSchema(String),
+    SignatureProcessSchema(String),
+    ListingSize(Listing),
+    ListingEntry(Listing, u32),
+    ListingPosition(Listing, String),
 }
 
 impl storage::Storage for DataKey {
     fn get<V: TryFromVal<Env, Val>>(&self, env: &Env) -> Option<V> {
         match self {
-            DataKey::Proposal(_) | DataKey::SignatureProcess(_) => {
-                storage::Persistent::get(env, self)
-            }
-            &DataKey::AssetAddress | &DataKey::OracleAddress | DataKey::NFTNotesAddress => {
-                storage::Instance::get(env, self)
-            }
+            DataKey::AllowedAsset(_)
+            | DataKey::Proposal(_)
+            | DataKey::SignatureProcess(_)
+            | DataKey::Contributions(_)
+            | DataKey::Milestones(_)
+            | DataKey::Auction(_)
+            | DataKey::PendingRelease(_)
+            | DataKey::Beneficiaries(_)
+            | DataKey::VestingSchedule(_)
+            | DataKey::Vesting(_)
+            | DataKey::Delivery(_)
+            | DataKey::NftRecipients(_)
+            | DataKey::Liability(_)
+            | DataKey::Lifecycle(_)
+            | DataKey::OracleIdToEscrow(_, _)
+            | DataKey::NftToEscrow(_)
+            | DataKey::ProposalSchema(_)
+            | DataKey::SignatureProcessSchema(_)
+            | DataKey::ListingSize(_)
+            | DataKey::ListingEntry(_, _)
+            | DataKey::ListingPosition(_, _) => storage::Persistent::get(env, self),
+            &DataKey::Admin
+            | DataKey::PendingAdmin
+            | DataKey::Paused
+            | DataKey::SchemaVersion
+            | &DataKey::OracleAddress
+            | DataKey::NFTNotesAddress
+            | DataKey::SignatureTimeout
+            | DataKey::Treasury
+            | DataKey::FeeBps
+            | DataKey::DisputeWindow
+            | DataKey::Arbitrator
+            | DataKey::Relayer => storage::Instance::get(env, self),
         }
     }
 
     fn set<V: IntoVal<Env, Val>>(&self, env: &Env, val: &V) {
         match self {
-            DataKey::Proposal(_) | DataKey::SignatureProcess(_) => {
-                storage::Persistent::set(env, self, val)
-            }
+            DataKey::AllowedAsset(_)
+            | DataKey::Proposal(_)
+            | DataKey::SignatureProcess(_)
+            | DataKey::Contributions(_)
+            | DataKey::Milestones(_)
+            | DataKey::Auction(_)
+            | DataKey::PendingRelease(_)
+            | DataKey::Beneficiaries(_)
+            | DataKey::VestingSchedule(_)
+            | DataKey::Vesting(_)
+            | DataKey::Delivery(_)
+            | DataKey::NftRecipients(_)
+            | DataKey::Liability(_)
+            | DataKey::Lifecycle(_)
+            | DataKey::OracleIdToEscrow(_, _)
+            | DataKey::NftToEscrow(_)
+            | DataKey::ProposalSchema(_)
+            | DataKey::SignatureProcessSchema(_)
+            | DataKey::ListingSize(_)
+            | DataKey::ListingEntry(_, _)
+            | DataKey::ListingPosition(_, _) => storage::Persistent::set(env, self, val),
 
-            &DataKey::AssetAddress | &DataKey::OracleAddress | DataKey::NFTNotesAddress => {
-                storage::Instance::set(env, self, val)
-            }
+            &DataKey::Admin
+            | DataKey::PendingAdmin
+            | DataKey::Paused
+            | DataKey::SchemaVersion
+            | &DataKey::OracleAddress
+            | DataKey::NFTNotesAddress
+            | DataKey::SignatureTimeout
+            | DataKey::Treasury
+            | DataKey::FeeBps
+            | DataKey::DisputeWindow
+            | DataKey::Arbitrator
+            | DataKey::Relayer => storage::Instance::set(env, self, val),
         }
     }
 
     fn has(&self, env: &Env) -> bool {
         match self {
-            DataKey::Proposal(_) | DataKey::SignatureProcess(_) => {
-                storage::Persistent::has(env, self)
-            }
-            &DataKey::AssetAddress | &DataKey::OracleAddress | DataKey::NFTNotesAddress => {
-                storage::Instance::has(env, self)
-            }
+            DataKey::AllowedAsset(_)
+            | DataKey::Proposal(_)
+            | DataKey::SignatureProcess(_)
+            | DataKey::Contributions(_)
+            | DataKey::Milestones(_)
+            | DataKey::Auction(_)
+            | DataKey::PendingRelease(_)
+            | DataKey::Beneficiaries(_)
+            | DataKey::VestingSchedule(_)
+            | DataKey::Vesting(_)
+            | DataKey::Delivery(_)
+            | DataKey::NftRecipients(_)
+            | DataKey::Liability(_)
+            | DataKey::Lifecycle(_)
+            | DataKey::OracleIdToEscrow(_, _)
+            | DataKey::NftToEscrow(_)
+            | DataKey::ProposalSchema(_)
+            | DataKey::SignatureProcessSchema(_)
+            | DataKey::ListingSize(_)
+            | DataKey::ListingEntry(_, _)
+            | DataKey::ListingPosition(_, _) => storage::Persistent::has(env, self),
+            &DataKey::Admin
+            | DataKey::PendingAdmin
+            | DataKey::Paused
+            | DataKey::SchemaVersion
+            | &DataKey::OracleAddress
+            | DataKey::NFTNotesAddress
+            | DataKey::SignatureTimeout
+            | DataKey::Treasury
+            | DataKey::FeeBps
+            | DataKey::DisputeWindow
+            | DataKey::Arbitrator
+            | DataKey::Relayer => storage::Instance::has(env, self),
         }
     }
 
@@ -50,10 +161,41 @@ impl storage::Storage for DataKey {
         }
 
         match self {
-            DataKey::Proposal(_) | DataKey::SignatureProcess(_) => {
+            DataKey::AllowedAsset(_)
+            | DataKey::Proposal(_)
+            | DataKey::SignatureProcess(_)
+            | DataKey::Contributions(_)
+            | DataKey::Milestones(_)
+            | DataKey::Auction(_)
+            | DataKey::PendingRelease(_)
+            | DataKey::Beneficiaries(_)
+            | DataKey::VestingSchedule(_)
+            | DataKey::Vesting(_)
+            | DataKey::Delivery(_)
+            | DataKey::NftRecipients(_)
+            | DataKey::Liability(_)
+            | DataKey::Lifecycle(_)
+            | DataKey::OracleIdToEscrow(_, _)
+            | DataKey::NftToEscrow(_)
+            | DataKey::ProposalSchema(_)
+            | DataKey::SignatureProcessSchema(_)
+            | DataKey::ListingSize(_)
+            | DataKey::ListingEntry(_, _)
+            | DataKey::ListingPosition(_, _) => {
                 storage::Persistent::extend(env, self, min_ledger_to_live)
             }
-            &DataKey::AssetAddress | &DataKey::OracleAddress | DataKey::NFTNotesAddress => {
+            &DataKey::Admin
+            | DataKey::PendingAdmin
+            | DataKey::Paused
+            | DataKey::SchemaVersion
+            | &DataKey::OracleAddress
+            | DataKey::NFTNotesAddress
+            | DataKey::SignatureTimeout
+            | DataKey::Treasury
+            | DataKey::FeeBps
+            | DataKey::DisputeWindow
+            | DataKey::Arbitrator
+            | DataKey::Relayer => {
                 storage::Instance::extend(env, min_ledger_to_live);
             }
         };
@@ -62,16 +204,56 @@ impl storage::Storage for DataKey {
 
     fn remove(&self, env: &Env) {
         match self {
-            DataKey::Proposal(_) | DataKey::SignatureProcess(_) => {
-                storage::Persistent::remove(env, self)
-            }
-            &DataKey::AssetAddress | &DataKey::OracleAddress | DataKey::NFTNotesAddress => {
-                storage::Instance::remove(env, self)
-            }
+            DataKey::AllowedAsset(_)
+            | DataKey::Proposal(_)
+            | DataKey::SignatureProcess(_)
+            | DataKey::Contributions(_)
+            | DataKey::Milestones(_)
+            | DataKey::Auction(_)
+            | DataKey::PendingRelease(_)
+            | DataKey::Beneficiaries(_)
+            | DataKey::VestingSchedule(_)
+            | DataKey::Vesting(_)
+            | DataKey::Delivery(_)
+            | DataKey::NftRecipients(_)
+            | DataKey::Liability(_)
+            | DataKey::Lifecycle(_)
+            | DataKey::OracleIdToEscrow(_, _)
+            | DataKey::NftToEscrow(_)
+            | DataKey::ProposalSchema(_)
+            | DataKey::SignatureProcessSchema(_)
+            | DataKey::ListingSize(_)
+            | DataKey::ListingEntry(_, _)
+            | DataKey::ListingPosition(_, _) => storage::Persistent::remove(env, self),
+            &DataKey::Admin
+            | DataKey::PendingAdmin
+            | DataKey::Paused
+            | DataKey::SchemaVersion
+            | &DataKey::OracleAddress
+            | DataKey::NFTNotesAddress
+            | DataKey::SignatureTimeout
+            | DataKey::Treasury
+            | DataKey::FeeBps
+            | DataKey::DisputeWindow
+            | DataKey::Arbitrator
+            | DataKey::Relayer => storage::Instance::remove(env, self),
         }
     }
 }
 
+/**
+ * The listings of the records, each one stored as an entry per record (in the
+ * order they were added) with the amount of positions used
+ */
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Listing {
+    ProposalsByStatus(ProposalStatus),
+    ProposalsByOwner(Address),
+    EscrowsByBuyer(Address),
+    EscrowsByProposal(String),
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum NullableString {
@@ -79,6 +261,13 @@ pub enum NullableString {
     None,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum NullableAddress {
+    Some(Address),
+    None,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct EscrowProposal {
@@ -97,6 +286,11 @@ pub struct EscrowProposal {
      */
     pub owner: Address,
 
+    /**
+     * The asset (SEP-41 token) used to settle the proposal
+     */
+    pub asset: Address,
+
     /**
      * The minimun funds asked by the proposal
      */
@@ -106,6 +300,98 @@ pub struct EscrowProposal {
      * The current signature transaction process linked to this propsal
      */
     pub signature_tx_linked: NullableString,
+
+    /**
+     * Ledgers to wait for the signature process before the buyer can reclaim
+     * the funds. If `None`, the global timeout is used.
+     */
+    pub signature_timeout: Option<u32>,
+
+    /**
+     * If the proposal is picked by a single buyer or funded by several
+     * contributors
+     */
+    pub mode: ProposalMode,
+
+    /**
+     * Last ledger in which the proposal can be picked. If `None`, the
+     * proposal does not expire.
+     */
+    pub expires_at_ledger: Option<u32>,
+
+    /**
+     * Version of the terms, increased each time the owner updates them
+     */
+    pub version: u32,
+
+    /**
+     * Performance bond posted by the owner, held until the proposal is
+     * closed
+     */
+    pub bond: i128,
+
+    /**
+     * Basis points of `min_funds` posted as the bond, kept when the terms are
+     * updated
+     */
+    pub bond_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Contribution {
+    /**
+     * Address that gave the funds to the pooled proposal
+     */
+    pub contributor: Address,
+
+    /**
+     * Funds given by the contributor, in the asset of the proposal
+     */
+    pub amount: i128,
+
+    /**
+     * ID of the NFT minted to the contributor if the signature is succesful
+     */
+    pub nft_id: Option<u32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestonePlan {
+    /**
+     * Address that funded all the milestones, once the proposal is picked
+     */
+    pub buyer: NullableAddress,
+
+    /**
+     * Funds already released to the owner of the proposal
+     */
+    pub released: i128,
+
+    /**
+     * The milestones, registered and released in this order
+     */
+    pub milestones: Vec<Milestone>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Milestone {
+    /**
+     * Funds released to the owner of the proposal when this milestone is signed
+     */
+    pub amount: i128,
+
+    /**
+     * The signature process registered for this milestone, if any
+     */
+    pub signaturit_id: NullableString,
+
+    /**
+     * Current status of the milestone
+     */
+    pub status: MilestoneStatus,
 }
 
 #[contracttype]
@@ -127,13 +413,18 @@ pub struct SignatureTxEscrow {
      */
     pub oracle_id: u32,
     /**
-     * Address of the user that pick the propose
+     * Address of the user that pick the propose. On pooled proposals it is
+     * the escrow itself, which holds the funds of the contributors
      */
     pub buyer: Address,
     /**
      * Address of the owner of the propose and who will receive the funds if success
      */
     pub receiver: Address,
+    /**
+     * Asset of the funds, taken from the propose
+     */
+    pub asset: Address,
     /**
      * Funds that the seller provide to the propose
      */
@@ -144,9 +435,293 @@ pub struct SignatureTxEscrow {
     pub status: SignatureStatus,
 
     /**
-     * ID of the NFT related to this signature tx if succesful
+     * IDs of the proof NFTs minted when the signature was completed
+     */
+    pub nft_proof_ids: Vec<u32>,
+
+    /**
+     * The oracle identifier of the cancel request, if the buyer asked for it
      */
-    pub nft_proof_id: Option<u32>,
+    pub cancel_oracle_id: Option<u32>,
+
+    /**
+     * Ledger after which the buyer can reclaim the funds if the oracle did not
+     * answer
+     */
+    pub deadline_ledger: u32,
+
+    /**
+     * Platform fee (basis points) at the time the signature was registered,
+     * taken when the funds are released
+     */
+    pub fee_bps: u32,
+
+    /**
+     * When the signature process was registered
+     */
+    pub registered: Checkpoint,
+
+    /**
+     * When the signature process was completed, or failed and its funds were
+     * returned
+     */
+    pub settled: NullableCheckpoint,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Auction {
+    /**
+     * The minimun bid accepted
+     */
+    pub reserve_price: i128,
+
+    /**
+     * Last ledger in which bids are accepted
+     */
+    pub end_ledger: u32,
+
+    /**
+     * Current status of the auction
+     */
+    pub status: AuctionStatus,
+
+    /**
+     * The bids placed, from the lowest to the highest. Only the funds of the
+     * highest one are held by the escrow
+     */
+    pub bids: Vec<Bid>,
+
+    /**
+     * Bidder that the deal is offered to after the signature of the winner
+     * failed, if any
+     */
+    pub offered_to: NullableAddress,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Bid {
+    pub bidder: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingRelease {
+    /**
+     * Hash of the signed document, for the notes minted at the release
+     */
+    pub document_hash: String,
+
+    /**
+     * Last ledger in which a dispute can be opened
+     */
+    pub release_ledger: u32,
+
+    /**
+     * Party that opened a dispute, if any
+     */
+    pub disputed_by: NullableAddress,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Beneficiary {
+    pub address: Address,
+
+    /**
+     * Share of the released funds, in basis points
+     */
+    pub bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BeneficiarySplit {
+    /**
+     * Addresses that receive the released funds instead of the owner. Their
+     * shares sum 10_000 basis points
+     */
+    pub beneficiaries: Vec<Beneficiary>,
+
+    /**
+     * Beneficiary that receives the dust left by the rounding of the shares
+     */
+    pub dust_beneficiary: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingSchedule {
+    /**
+     * Ledgers after the release before anything can be claimed
+     */
+    pub cliff: u32,
+
+    /**
+     * Ledgers after the release until everything can be claimed
+     */
+    pub duration: u32,
+
+    /**
+     * Ledgers between each unlock of the funds
+     */
+    pub interval: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingPosition {
+    /**
+     * Funds released to the receiver, after the platform fee
+     */
+    pub total: i128,
+
+    /**
+     * Funds already claimed by the receiver
+     */
+    pub claimed: i128,
+
+    /**
+     * Ledger in which the funds were released
+     */
+    pub start_ledger: u32,
+
+    /**
+     * Schedule of the proposal when the funds were released
+     */
+    pub schedule: VestingSchedule,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum DeliveryStatus {
+    Held = 0,
+    Delivered = 1,
+    Returned = 2,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Delivery {
+    /**
+     * The tokenized asset (SEP-41 token) sold by the proposal
+     */
+    pub asset: Address,
+
+    /**
+     * Amount of the asset delivered to the buyer
+     */
+    pub amount: i128,
+
+    /**
+     * `Held` by the escrow until it is delivered to the buyer or returned to
+     * the owner
+     */
+    pub status: DeliveryStatus,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum NullableDelivery {
+    Some(Delivery),
+    None,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalTerms {
+    /**
+     * Ledgers that the buyer waits for the signature process before
+     * reclaiming the funds. The global timeout is used if it is not given.
+     */
+    pub signature_timeout: Option<u32>,
+
+    /**
+     * Ledger after which the proposal can not be picked, if given
+     */
+    pub expires_at_ledger: Option<u32>,
+
+    /**
+     * Bond posted by the owner, in basis points of `min_funds`
+     */
+    pub bond_bps: u32,
+
+    /**
+     * Tokenized asset deposited by the owner and delivered to the buyer
+     */
+    pub delivery: NullableDelivery,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum NftRecipients {
+    Buyer = 0,
+    Receiver = 1,
+    Both = 2,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reconciliation {
+    /**
+     * Amount of the asset that the escrow owes: the funds, bonds, bids and
+     * deliveries that it holds until they are released or returned
+     */
+    pub liability: i128,
+
+    /**
+     * Actual balance of the asset held by the escrow
+     */
+    pub balance: i128,
+
+    /**
+     * Balance above the liability, which can be swept. A negative surplus is
+     * a deficit (for example, a token that charged a fee on a transfer).
+     */
+    pub surplus: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Checkpoint {
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum NullableCheckpoint {
+    Some(Checkpoint),
+    None,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Lifecycle {
+    /**
+     * When the proposal was added
+     */
+    pub created: NullableCheckpoint,
+
+    /**
+     * When the proposal was last picked by a buyer
+     */
+    pub picked: NullableCheckpoint,
+
+    /**
+     * When the proposal was completed
+     */
+    pub completed: NullableCheckpoint,
+
+    /**
+     * When the last signature process of the proposal failed and its funds
+     * were returned
+     */
+    pub failed: NullableCheckpoint,
 }
 
 #[contracterror]
@@ -163,6 +738,46 @@ pub enum EscrowError {
     NoEnoughtFunds = 7,
     AlreadyInitialized = 8,
     SignatureProcessExist = 9,
+    SignatureNotInProgress = 10,
+    CancelAlreadyRequested = 11,
+    DeadlineNotReached = 12,
+    AssetNotAllowed = 13,
+    NotPooledProposal = 14,
+    NotDirectProposal = 15,
+    InvalidAmount = 16,
+    InvalidFee = 17,
+    NoPendingAdmin = 18,
+    ContractPaused = 19,
+    AlreadyMigrated = 20,
+    NotMilestonesProposal = 21,
+    InvalidMilestones = 22,
+    MilestonesNotSet = 23,
+    MilestoneInProgress = 24,
+    NoPendingMilestone = 25,
+    MilestoneNotFound = 26,
+    MilestonesNotFunded = 27,
+    NotPendingRelease = 28,
+    DisputeWindowClosed = 29,
+    DisputeWindowOpen = 30,
+    NoArbitrator = 31,
+    NotDisputed = 32,
+    NotAParty = 33,
+    NotAuctionProposal = 34,
+    InvalidAuction = 35,
+    AuctionOpen = 36,
+    AuctionNotOpen = 37,
+    AuctionNotEnded = 38,
+    BidTooLow = 39,
+    NoBids = 40,
+    NoOffer = 41,
+    ProposalExpired = 42,
+    ProposalNotExpired = 43,
+    VersionMismatch = 44,
+    PriceAboveMax = 45,
+    InvalidBeneficiaries = 46,
+    InvalidVesting = 47,
+    NoVesting = 48,
+    NothingToClaim = 49,
 }
 
 #[contracttype]
@@ -175,6 +790,16 @@ pub enum ProposalStatus {
     Completed = 3,
 }
 
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ProposalMode {
+    Direct = 0,
+    Pooled = 1,
+    Milestones = 2,
+    Auction = 3,
+}
+
 #[contracttype]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[repr(u32)]
@@ -182,4 +807,25 @@ pub enum SignatureStatus {
     Canceled = 0,
     Completed = 1,
     Progress = 2,
+    Expired = 3,
+    PendingRelease = 4,
+    Disputed = 5,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum AuctionStatus {
+    Open = 0,
+    Closed = 1,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum MilestoneStatus {
+    Pending = 0,
+    Progress = 1,
+    Released = 2,
+    Refunded = 3,
 }
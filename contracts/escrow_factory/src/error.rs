@@ -0,0 +1,11 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum FactoryError {
+    NotInit = 0,
+    AlreadyInit = 1,
+    TenantAlreadyExist = 2,
+    TenantNotFound = 3,
+}
@@ -0,0 +1,42 @@
+use soroban_sdk::{Address, BytesN, Env, IntoVal, String, Val, Vec};
+
+pub enum FactoryEvent {
+    Initialized(Address),
+    WasmHashesUpdated(BytesN<32>, BytesN<32>, BytesN<32>),
+    TenantDeployed(String, Address, Address, Address, Address),
+}
+
+impl FactoryEvent {
+    pub fn name(&self) -> &'static str {
+        match self {
+            FactoryEvent::Initialized(..) => stringify!(Initialized),
+            FactoryEvent::WasmHashesUpdated(..) => stringify!(WasmHashesUpdated),
+            FactoryEvent::TenantDeployed(..) => stringify!(TenantDeployed),
+        }
+    }
+    pub fn publish(&self, env: &Env) {
+        let mut v: Vec<Val> = Vec::new(env);
+
+        match self {
+            FactoryEvent::Initialized(admin_address) => {
+                v.push_back(admin_address.into_val(env));
+            }
+
+            FactoryEvent::WasmHashesUpdated(escrow_hash, oracle_hash, nft_notes_hash) => {
+                v.push_back(escrow_hash.into_val(env));
+                v.push_back(oracle_hash.into_val(env));
+                v.push_back(nft_notes_hash.into_val(env));
+            }
+
+            FactoryEvent::TenantDeployed(tenant_id, admin, escrow, oracle, nft_notes) => {
+                v.push_back(tenant_id.into_val(env));
+                v.push_back(admin.into_val(env));
+                v.push_back(escrow.into_val(env));
+                v.push_back(oracle.into_val(env));
+                v.push_back(nft_notes.into_val(env));
+            }
+        }
+
+        env.events().publish((self.name(),), v)
+    }
+}
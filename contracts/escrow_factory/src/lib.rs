@@ -0,0 +1,253 @@
+#![no_std]
+mod error;
+mod events;
+mod types;
+
+#[allow(clippy::too_many_arguments)]
+pub mod escrow {
+    soroban_sdk::contractimport!(file = "../../target/wasm32-unknown-unknown/release/escrow.wasm");
+    pub type EscrowClient<'a> = Client<'a>;
+}
+
+pub mod oracle {
+    soroban_sdk::contractimport!(
+        file = "../../target/wasm32-unknown-unknown/release/signaturit_oracle.wasm"
+    );
+    pub type OracleClient<'a> = Client<'a>;
+}
+
+pub mod notes_nft {
+    soroban_sdk::contractimport!(
+        file = "../../target/wasm32-unknown-unknown/release/notes_nft.wasm"
+    );
+    pub type NotesNFTClient<'a> = Client<'a>;
+}
+
+use error::FactoryError;
+use events::FactoryEvent;
+use soroban_sdk::{
+    contract, contractimpl, panic_with_error, xdr::ToXdr, Address, BytesN, Env, String, Vec,
+};
+use storage::Storage;
+use types::{DataKey, Tenant, TenantConfig, WasmHashes};
+
+// Maximum amount of records returned by a page of the listings
+const MAX_PAGE_SIZE: u32 = 50;
+
+// Appended to the tenant ID, so each contract of a tenant has its own salt
+const ESCROW_SALT: u8 = 0;
+const ORACLE_SALT: u8 = 1;
+const NFT_NOTES_SALT: u8 = 2;
+
+fn check_initialization(env: &Env) {
+    if !DataKey::Admin.has(env) {
+        panic_with_error!(env, FactoryError::NotInit);
+    }
+}
+
+fn get_admin(env: &Env) -> Address {
+    DataKey::Admin.get(env).unwrap()
+}
+
+fn get_wasm_hashes(env: &Env) -> WasmHashes {
+    DataKey::WasmHashes.get(env).unwrap()
+}
+
+fn get_tenant(env: &Env, tenant_id: &String) -> Tenant {
+    if !DataKey::Tenant(tenant_id.clone()).has(env) {
+        panic_with_error!(env, FactoryError::TenantNotFound);
+    }
+
+    DataKey::Tenant(tenant_id.clone()).get(env).unwrap()
+}
+
+fn get_tenants_size(env: &Env) -> u32 {
+    DataKey::TenantsSize.get(env).unwrap_or(0)
+}
+
+/**
+Add a tenant at the end of the registry. Each position is its own entry, so
+the registry can grow without rewriting the previous tenants.
+*/
+fn add_to_registry(env: &Env, tenant_id: &String) {
+    let size = get_tenants_size(env);
+
+    DataKey::TenantEntry(size).set(env, tenant_id);
+    DataKey::TenantsSize.set(env, &(size + 1));
+}
+
+fn set_wasm_hashes(env: &Env, wasm_hashes: &WasmHashes) {
+    DataKey::WasmHashes.set(env, wasm_hashes);
+
+    FactoryEvent::WasmHashesUpdated(
+        wasm_hashes.escrow.clone(),
+        wasm_hashes.oracle.clone(),
+        wasm_hashes.nft_notes.clone(),
+    )
+    .publish(env);
+}
+
+/**
+Salt of one of the contracts of a tenant. It only depends on the tenant ID,
+so the addresses of the contracts are known before the deployment.
+*/
+fn get_salt(env: &Env, tenant_id: &String, contract: u8) -> BytesN<32> {
+    let mut data = tenant_id.clone().to_xdr(env);
+    data.push_back(contract);
+
+    env.crypto().sha256(&data)
+}
+
+fn deploy(env: &Env, tenant_id: &String, contract: u8, wasm_hash: &BytesN<32>) -> Address {
+    env.deployer()
+        .with_current_contract(get_salt(env, tenant_id, contract))
+        .deploy(wasm_hash.clone())
+}
+
+#[contract]
+pub struct EscrowFactory;
+
+#[contractimpl]
+impl EscrowFactory {
+    /**
+    Initialize the factory with the code that will be deployed for each
+    tenant.
+
+    ### Arguments
+    * `admin`: The address that will be authorized to deploy the tenants.
+    * `wasm_hashes`: The hashes of the installed code of the escrow, the
+      oracle and the NFT notes contracts.
+    */
+    pub fn initialize(env: Env, admin: Address, wasm_hashes: WasmHashes) {
+        if DataKey::Admin.has(&env) {
+            panic_with_error!(env, FactoryError::AlreadyInit);
+        }
+
+        DataKey::Admin.set(&env, &admin);
+        set_wasm_hashes(&env, &wasm_hashes);
+
+        FactoryEvent::Initialized(admin).publish(&env);
+    }
+
+    pub fn get_admin(env: Env) -> Address {
+        check_initialization(&env);
+        get_admin(&env)
+    }
+
+    pub fn get_wasm_hashes(env: Env) -> WasmHashes {
+        check_initialization(&env);
+        get_wasm_hashes(&env)
+    }
+
+    /**
+    Replace the code deployed for the new tenants. The tenants already
+    deployed are not changed, each one is upgraded by its own admin.
+    */
+    pub fn update_wasm_hashes(env: Env, wasm_hashes: WasmHashes) {
+        check_initialization(&env);
+        get_admin(&env).require_auth();
+
+        set_wasm_hashes(&env, &wasm_hashes);
+    }
+
+    /**
+    Deploy the escrow, the oracle and the NFT notes of a new tenant, and wire
+    them up. The NFT notes have the new escrow as admin, so only it can mint
+    the proofs of the signatures.
+
+    ### Arguments
+    * `tenant_id`: Identifier of the platform, which can only be deployed once.
+    * `config`: The initialization arguments of the contracts of the tenant.
+    */
+    pub fn deploy_tenant(env: Env, tenant_id: String, config: TenantConfig) -> Tenant {
+        check_initialization(&env);
+        get_admin(&env).require_auth();
+
+        if DataKey::Tenant(tenant_id.clone()).has(&env) {
+            panic_with_error!(&env, FactoryError::TenantAlreadyExist);
+        }
+
+        let wasm_hashes = get_wasm_hashes(&env);
+
+        let escrow_address = deploy(&env, &tenant_id, ESCROW_SALT, &wasm_hashes.escrow);
+        let oracle_address = deploy(&env, &tenant_id, ORACLE_SALT, &wasm_hashes.oracle);
+        let nft_notes_address = deploy(&env, &tenant_id, NFT_NOTES_SALT, &wasm_hashes.nft_notes);
+
+        // The oracle and the NFT notes are ready before the escrow points to them
+        oracle::OracleClient::new(&env, &oracle_address).initialize(&config.admin);
+
+        notes_nft::NotesNFTClient::new(&env, &nft_notes_address).initialize(
+            &escrow_address,
+            &config.nft_name,
+            &config.nft_symbol,
+        );
+
+        escrow::EscrowClient::new(&env, &escrow_address).initialize(
+            &config.admin,
+            &config.asset_address,
+            &oracle_address,
+            &nft_notes_address,
+            &config.signature_timeout,
+            &config.treasury,
+            &config.fee_bps,
+        );
+
+        let tenant = Tenant {
+            id: tenant_id.clone(),
+            admin: config.admin,
+            escrow: escrow_address,
+            oracle: oracle_address,
+            nft_notes: nft_notes_address,
+            deployed_at_ledger: env.ledger().sequence(),
+        };
+
+        DataKey::Tenant(tenant_id.clone()).set(&env, &tenant);
+
+        add_to_registry(&env, &tenant_id);
+
+        FactoryEvent::TenantDeployed(
+            tenant_id,
+            tenant.admin.clone(),
+            tenant.escrow.clone(),
+            tenant.oracle.clone(),
+            tenant.nft_notes.clone(),
+        )
+        .publish(&env);
+
+        tenant
+    }
+
+    pub fn get_tenant(env: Env, tenant_id: String) -> Tenant {
+        check_initialization(&env);
+        get_tenant(&env, &tenant_id)
+    }
+
+    /**
+    Deployed tenants in order of deployment, paginated from `cursor` (the
+    position on the registry) with at most `limit` tenants per page.
+    */
+    pub fn list_tenants(env: Env, cursor: u32, limit: u32) -> Vec<Tenant> {
+        check_initialization(&env);
+
+        let size = get_tenants_size(&env);
+
+        let start = cursor.min(size);
+        let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(size);
+
+        let mut tenants = Vec::new(&env);
+        for position in start..end {
+            let tenant_id: String = DataKey::TenantEntry(position).get(&env).unwrap();
+            tenants.push_back(get_tenant(&env, &tenant_id));
+        }
+
+        tenants
+    }
+
+    pub fn tenants_count(env: Env) -> u32 {
+        check_initialization(&env);
+
+        get_tenants_size(&env)
+    }
+}
+
+mod test;
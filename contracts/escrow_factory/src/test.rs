@@ -0,0 +1,92 @@
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+use crate::{escrow, notes_nft, oracle};
+
+pub mod escrow_factory {
+    soroban_sdk::contractimport!(
+        file = "../../target/wasm32-unknown-unknown/release/escrow_factory.wasm"
+    );
+    pub type EscrowFactoryClient<'a> = Client<'a>;
+}
+use escrow_factory::{EscrowFactoryClient, TenantConfig, WasmHashes};
+
+fn create_factory_contract<'a>(env: &Env) -> EscrowFactoryClient<'a> {
+    let contract_id = env.register_contract_wasm(None, escrow_factory::WASM);
+    EscrowFactoryClient::new(env, &contract_id)
+}
+
+// Ledgers that a buyer waits for the oracle before reclaiming the funds
+pub const SIGNATURE_TIMEOUT: u32 = 17_280;
+
+pub struct FactoryTest<'a> {
+    env: Env,
+    factory: EscrowFactoryClient<'a>,
+    wasm_hashes: WasmHashes,
+    admin: Address,
+    tenant_admin: Address,
+    asset: Address,
+    treasury: Address,
+}
+
+impl<'a> FactoryTest<'a> {
+    fn setup() -> Self {
+        let test_setup = Self::setup_non_init();
+
+        test_setup
+            .factory
+            .initialize(&test_setup.admin, &test_setup.wasm_hashes);
+
+        test_setup
+    }
+
+    fn setup_non_init() -> Self {
+        let env = Env::default();
+
+        // Deploying a tenant instantiates its three contracts, which exceeds
+        // the default budget
+        env.budget().reset_unlimited();
+
+        // Generate the accounts (users)
+        let admin = Address::generate(&env);
+        let tenant_admin = Address::generate(&env);
+        let asset = Address::generate(&env);
+        let treasury = Address::generate(&env);
+        assert_ne!(admin, tenant_admin);
+
+        // Install the code of the tenant contracts
+        let wasm_hashes = WasmHashes {
+            escrow: env.deployer().upload_contract_wasm(escrow::WASM),
+            oracle: env.deployer().upload_contract_wasm(oracle::WASM),
+            nft_notes: env.deployer().upload_contract_wasm(notes_nft::WASM),
+        };
+
+        let factory_client = create_factory_contract(&env);
+
+        FactoryTest {
+            env,
+            factory: factory_client,
+            wasm_hashes,
+            admin,
+            tenant_admin,
+            asset,
+            treasury,
+        }
+    }
+
+    fn tenant_config(&self) -> TenantConfig {
+        TenantConfig {
+            admin: self.tenant_admin.clone(),
+            asset_address: self.asset.clone(),
+            signature_timeout: SIGNATURE_TIMEOUT,
+            treasury: self.treasury.clone(),
+            fee_bps: 100,
+            nft_name: String::from_str(&self.env, "Signaturit Notes NFT"),
+            nft_symbol: String::from_str(&self.env, "SN_NFT"),
+        }
+    }
+}
+
+mod deploy_tenant;
+mod initialize;
@@ -0,0 +1,187 @@
+#![cfg(test)]
+
+use crate::{
+    escrow::EscrowClient,
+    events::FactoryEvent,
+    notes_nft::NotesNFTClient,
+    oracle::OracleClient,
+    test::{escrow_factory::FactoryError, FactoryTest, SIGNATURE_TIMEOUT},
+    types::DataKey,
+};
+use soroban_sdk::{
+    testutils::{Events, Ledger, MockAuth, MockAuthInvoke},
+    IntoVal, String,
+};
+use storage::Storage;
+
+#[test]
+fn deploy_tenant() {
+    let test = FactoryTest::setup();
+
+    let tenant_id = String::from_str(&test.env, "platform-a");
+    let config = test.tenant_config();
+
+    test.env.ledger().with_mut(|li| li.sequence_number = 100);
+
+    let tenant = test
+        .factory
+        .mock_auths(&[MockAuth {
+            address: &test.admin,
+            invoke: &MockAuthInvoke {
+                contract: &test.factory.address,
+                fn_name: "deploy_tenant",
+                args: (tenant_id.clone(), config.clone()).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .deploy_tenant(&tenant_id, &config);
+
+    let event_expected = (
+        test.factory.address.clone(),
+        (FactoryEvent::TenantDeployed(
+            tenant_id.clone(),
+            tenant.admin.clone(),
+            tenant.escrow.clone(),
+            tenant.oracle.clone(),
+            tenant.nft_notes.clone(),
+        )
+        .name(),)
+            .into_val(&test.env),
+        (
+            tenant_id.clone(),
+            tenant.admin.clone(),
+            tenant.escrow.clone(),
+            tenant.oracle.clone(),
+            tenant.nft_notes.clone(),
+        )
+            .into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "TenantDeployed event not present"
+    );
+
+    assert_eq!(tenant.id, tenant_id);
+    assert_eq!(tenant.admin, test.tenant_admin);
+    assert_eq!(tenant.deployed_at_ledger, 100);
+    assert_eq!(test.factory.get_tenant(&tenant_id), tenant);
+
+    // The contracts are wired up
+    let escrow = EscrowClient::new(&test.env, &tenant.escrow);
+    assert_eq!(escrow.get_admin(), test.tenant_admin);
+    assert_eq!(escrow.get_oracle(), tenant.oracle);
+    assert_eq!(escrow.get_nft_notes(), tenant.nft_notes);
+    assert_eq!(escrow.get_treasury(), test.treasury);
+    assert_eq!(escrow.get_fee(), 100);
+    assert!(escrow.is_asset_allowed(&test.asset));
+    assert_eq!(escrow.get_signature_timeout(), SIGNATURE_TIMEOUT);
+
+    let oracle = OracleClient::new(&test.env, &tenant.oracle);
+    assert_eq!(oracle.get_admin(), test.tenant_admin);
+
+    // Only the escrow of the tenant can mint its proofs
+    let nft_notes = NotesNFTClient::new(&test.env, &tenant.nft_notes);
+    assert_eq!(nft_notes.admin(), tenant.escrow);
+}
+
+#[test]
+fn deploy_tenant_twice() {
+    let test = FactoryTest::setup();
+
+    let tenant_id = String::from_str(&test.env, "platform-a");
+    let config = test.tenant_config();
+
+    test.factory
+        .mock_all_auths()
+        .deploy_tenant(&tenant_id, &config);
+
+    let res = test
+        .factory
+        .mock_all_auths()
+        .try_deploy_tenant(&tenant_id, &config);
+
+    assert_eq!(res, Err(Ok(FactoryError::TenantAlreadyExist.into())));
+}
+
+#[test]
+fn deploy_tenant_only_admin() {
+    let test = FactoryTest::setup();
+
+    let tenant_id = String::from_str(&test.env, "platform-a");
+    let config = test.tenant_config();
+
+    let res = test
+        .factory
+        .mock_auths(&[MockAuth {
+            address: &test.tenant_admin,
+            invoke: &MockAuthInvoke {
+                contract: &test.factory.address,
+                fn_name: "deploy_tenant",
+                args: (tenant_id.clone(), config.clone()).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_deploy_tenant(&tenant_id, &config);
+
+    assert!(res.is_err(), "calling with non-admin not reverted");
+}
+
+#[test]
+fn list_tenants() {
+    let test = FactoryTest::setup();
+
+    let tenant_a = test.factory.mock_all_auths().deploy_tenant(
+        &String::from_str(&test.env, "platform-a"),
+        &test.tenant_config(),
+    );
+    let tenant_b = test.factory.mock_all_auths().deploy_tenant(
+        &String::from_str(&test.env, "platform-b"),
+        &test.tenant_config(),
+    );
+
+    // Each tenant has its own contracts
+    assert_ne!(tenant_a.escrow, tenant_b.escrow);
+    assert_ne!(tenant_a.oracle, tenant_b.oracle);
+    assert_ne!(tenant_a.nft_notes, tenant_b.nft_notes);
+
+    assert_eq!(test.factory.tenants_count(), 2);
+
+    let tenants = test.factory.list_tenants(&0, &10);
+    assert_eq!(tenants.len(), 2);
+    assert_eq!(tenants.get(0).unwrap(), tenant_a);
+    assert_eq!(tenants.get(1).unwrap(), tenant_b);
+
+    let tenants = test.factory.list_tenants(&1, &10);
+    assert_eq!(tenants.len(), 1);
+    assert_eq!(tenants.get(0).unwrap(), tenant_b);
+
+    let tenants = test.factory.list_tenants(&0, &1);
+    assert_eq!(tenants.len(), 1);
+    assert_eq!(tenants.get(0).unwrap(), tenant_a);
+
+    let tenants = test.factory.list_tenants(&2, &10);
+    assert_eq!(tenants.len(), 0);
+
+    // Each position of the registry is its own entry
+    test.env.as_contract(&test.factory.address, || {
+        let size: u32 = DataKey::TenantsSize.get(&test.env).unwrap();
+        let first: String = DataKey::TenantEntry(0).get(&test.env).unwrap();
+        let second: String = DataKey::TenantEntry(1).get(&test.env).unwrap();
+
+        assert_eq!(size, 2);
+        assert_eq!(first, tenant_a.id);
+        assert_eq!(second, tenant_b.id);
+    });
+}
+
+#[test]
+fn get_tenant_not_found() {
+    let test = FactoryTest::setup();
+
+    let res = test
+        .factory
+        .try_get_tenant(&String::from_str(&test.env, "platform-a"));
+
+    assert_eq!(res, Err(Ok(FactoryError::TenantNotFound.into())));
+}
@@ -0,0 +1,116 @@
+#![cfg(test)]
+
+use crate::{
+    events::FactoryEvent,
+    test::{escrow_factory::FactoryError, FactoryTest},
+};
+use soroban_sdk::{
+    testutils::{Events, MockAuth, MockAuthInvoke},
+    IntoVal,
+};
+
+#[test]
+fn before_initialization() {
+    let test = FactoryTest::setup_non_init();
+
+    let res = test.factory.try_get_admin();
+
+    assert_eq!(res, Err(Ok(FactoryError::NotInit.into())));
+}
+
+#[test]
+fn initialization() {
+    let test = FactoryTest::setup_non_init();
+
+    test.factory.initialize(&test.admin, &test.wasm_hashes);
+
+    // Check Initialized event
+    let event_expected = (
+        test.factory.address.clone(),
+        (FactoryEvent::Initialized(test.admin.clone()).name(),).into_val(&test.env),
+        (&test.admin,).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "initialized event not present"
+    );
+
+    assert_eq!(test.factory.get_admin(), test.admin);
+    assert_eq!(test.factory.get_wasm_hashes(), test.wasm_hashes);
+    assert_eq!(test.factory.tenants_count(), 0);
+}
+
+#[test]
+fn double_initialization() {
+    let test = FactoryTest::setup();
+
+    let res = test.factory.try_initialize(&test.admin, &test.wasm_hashes);
+
+    assert_eq!(res, Err(Ok(FactoryError::AlreadyInit.into())));
+}
+
+#[test]
+fn update_wasm_hashes() {
+    let test = FactoryTest::setup();
+
+    // The escrow code is replaced for the next tenants
+    let mut wasm_hashes = test.wasm_hashes.clone();
+    wasm_hashes.escrow = wasm_hashes.oracle.clone();
+
+    test.factory
+        .mock_auths(&[MockAuth {
+            address: &test.admin,
+            invoke: &MockAuthInvoke {
+                contract: &test.factory.address,
+                fn_name: "update_wasm_hashes",
+                args: (wasm_hashes.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .update_wasm_hashes(&wasm_hashes);
+
+    let event_expected = (
+        test.factory.address.clone(),
+        (FactoryEvent::WasmHashesUpdated(
+            wasm_hashes.escrow.clone(),
+            wasm_hashes.oracle.clone(),
+            wasm_hashes.nft_notes.clone(),
+        )
+        .name(),)
+            .into_val(&test.env),
+        (
+            wasm_hashes.escrow.clone(),
+            wasm_hashes.oracle.clone(),
+            wasm_hashes.nft_notes.clone(),
+        )
+            .into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "WasmHashesUpdated event not present"
+    );
+
+    assert_eq!(test.factory.get_wasm_hashes(), wasm_hashes);
+}
+
+#[test]
+fn update_wasm_hashes_only_admin() {
+    let test = FactoryTest::setup();
+
+    let res = test
+        .factory
+        .mock_auths(&[MockAuth {
+            address: &test.tenant_admin,
+            invoke: &MockAuthInvoke {
+                contract: &test.factory.address,
+                fn_name: "update_wasm_hashes",
+                args: (test.wasm_hashes.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_update_wasm_hashes(&test.wasm_hashes);
+
+    assert!(res.is_err(), "calling with non-admin not reverted");
+}
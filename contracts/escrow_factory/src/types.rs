@@ -0,0 +1,94 @@
+use soroban_sdk::{contracttype, Address, BytesN, Env, IntoVal, String, TryFromVal, Val};
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    WasmHashes,
+    Tenant(String),
+    TenantsSize,
+    TenantEntry(u32),
+}
+
+impl storage::Storage for DataKey {
+    fn get<V: TryFromVal<Env, Val>>(&self, env: &Env) -> Option<V> {
+        storage::Persistent::get(env, self)
+    }
+
+    fn set<V: IntoVal<Env, Val>>(&self, env: &Env, val: &V) {
+        storage::Persistent::set(env, self, val)
+    }
+
+    fn has(&self, env: &Env) -> bool {
+        storage::Persistent::has(env, self)
+    }
+
+    fn extend(&self, env: &Env, min_ledger_to_live: u32) -> &Self {
+        if !self.has(env) {
+            return self;
+        }
+
+        storage::Persistent::extend(env, self, min_ledger_to_live);
+
+        self
+    }
+
+    fn remove(&self, env: &Env) {
+        storage::Persistent::remove(env, self)
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WasmHashes {
+    /**
+    Hash of the installed code of the Escrow contract
+     */
+    pub escrow: BytesN<32>,
+    /**
+    Hash of the installed code of the Signaturit Oracle contract
+     */
+    pub oracle: BytesN<32>,
+    /**
+    Hash of the installed code of the NFT Notes contract
+     */
+    pub nft_notes: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TenantConfig {
+    /**
+    Admin of the escrow, and the address that sends the callbacks of the oracle
+     */
+    pub admin: Address,
+    /**
+    First asset allowed on the escrow
+     */
+    pub asset_address: Address,
+    /**
+    Ledgers that a buyer waits for the oracle before reclaiming the funds
+     */
+    pub signature_timeout: u32,
+    pub treasury: Address,
+    pub fee_bps: u32,
+    /**
+    Name and symbol of the NFT collection of the tenant
+     */
+    pub nft_name: String,
+    pub nft_symbol: String,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tenant {
+    /**
+    Identifier of the platform, from which the salts of its contracts are
+    derived
+     */
+    pub id: String,
+    pub admin: Address,
+    pub escrow: Address,
+    pub oracle: Address,
+    pub nft_notes: Address,
+    pub deployed_at_ledger: u32,
+}
@@ -7,7 +7,7 @@ pub mod oracle {
     pub type OracleClient<'a> = Client<'a>;
 }
 
-use oracle_traits::OracleConsumer;
+use oracle_traits::{FailureReason, OracleConsumer};
 use soroban_sdk::{
     auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
     contract, contractimpl, symbol_short, vec, Address, Env, IntoVal, String, Symbol,
@@ -23,6 +23,7 @@ pub enum EscrowTestEvent {
 
 pub const COMPLETED_TOPIC: Symbol = symbol_short!("COMP_TEST");
 pub const FAILED_TOPIC: Symbol = symbol_short!("FAIL_TEST");
+pub const REJECTED_TOPIC: Symbol = symbol_short!("REJ_TEST");
 const ORACLE: Symbol = symbol_short!("ORACLE");
 
 fn get_oracle(env: &Env) -> Address {
@@ -62,6 +63,27 @@ impl EscrowTest {
 
         return oracle_id;
     }
+
+    pub fn oracle_request_cancel(env: Env, signaturit_id: String) -> u32 {
+        // Get the oracle client
+        let oracle_client = oracle::OracleClient::new(&env, &get_oracle(&env));
+
+        // Grant auth for calling the function
+        env.authorize_as_current_contract(vec![
+            &env,
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: get_oracle(&env),
+                    fn_name: Symbol::new(&env, "request_cancel"),
+                    args: (env.current_contract_address(), signaturit_id.clone()).into_val(&env),
+                },
+                sub_invocations: vec![&env],
+            }),
+        ]);
+
+        // Get the oracle id for this cancel request
+        oracle_client.request_cancel(&env.current_contract_address(), &signaturit_id)
+    }
 }
 
 #[contractimpl]
@@ -71,7 +93,12 @@ impl OracleConsumer for EscrowTest {
             .publish((COMPLETED_TOPIC,), (signaturit_id, document_hash));
     }
 
-    fn failed_signature(env: Env, signaturit_id: String) {
-        env.events().publish((FAILED_TOPIC,), signaturit_id);
+    fn failed_signature(env: Env, signaturit_id: String, reason: FailureReason) {
+        env.events()
+            .publish((FAILED_TOPIC,), (signaturit_id, reason));
+    }
+
+    fn rejected_cancel(env: Env, signaturit_id: String) {
+        env.events().publish((REJECTED_TOPIC,), (signaturit_id,));
     }
 }
@@ -9,4 +9,5 @@ pub enum Error {
     NotNFT = 3,
     NotAuthorized = 4,
     OutOfBounds = 5,
+    AlreadyMigrated = 6,
 }
@@ -10,6 +10,8 @@ pub enum Event {
     Transfer,
     Approve,
     ApproveForAll,
+    Upgraded,
+    Migrated,
 }
 impl Event {
     pub fn name(&self) -> &'static str {
@@ -18,6 +20,8 @@ impl Event {
             Event::Transfer => stringify!(Transfer),
             Event::Approve => stringify!(Approve),
             Event::ApproveForAll => stringify!(ApproveForAll),
+            Event::Upgraded => stringify!(Upgraded),
+            Event::Migrated => stringify!(Migrated),
         }
     }
     pub fn publish<D>(&self, env: &Env, value: D)
@@ -16,13 +16,19 @@ pub use events::*;
 pub use types::*;
 
 use soroban_sdk::{
-    contract, contractimpl, panic_with_error, Address, Env, IntoVal, Map, String, Val, Vec,
+    contract, contractimpl, panic_with_error, Address, BytesN, Env, IntoVal, Map, String, Val, Vec,
 };
 
+const SCHEMA_VERSION: u32 = 1;
+
 fn get_admin(env: &Env) -> Address {
     Admin::Admin.get(env).unwrap()
 }
 
+fn get_schema_version(env: &Env) -> u32 {
+    storage::schema_version(env, &DataKeyVersion::SchemaVersion)
+}
+
 #[contract]
 pub struct NotesNFTContract;
 
@@ -36,6 +42,8 @@ impl NotesNFTContract {
 
         env.storage().instance().extend_ttl(10000, 10000);
 
+        DataKeyVersion::SchemaVersion.set(&env, &SCHEMA_VERSION);
+
         DatakeyMetadata::Name.set(&env, &name);
         DatakeyMetadata::Symbol.set(&env, &symbol);
 
@@ -51,6 +59,43 @@ impl NotesNFTContract {
         get_admin(&env)
     }
 
+    /**
+     * Schema version of the stored records
+     */
+    pub fn version(env: Env) -> u32 {
+        get_schema_version(&env)
+    }
+
+    /**
+     * Replace the code of the contract keeping its storage. `migrate` has to
+     * be called after the upgrade if the stored layout changed.
+     */
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        get_admin(&env).require_auth();
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        Event::Upgraded.publish(&env, new_wasm_hash);
+    }
+
+    /**
+     * Bring the stored records to the schema version of the current code.
+     */
+    pub fn migrate(env: Env) {
+        get_admin(&env).require_auth();
+
+        let from_version = get_schema_version(&env);
+
+        if from_version >= SCHEMA_VERSION {
+            panic_with_error!(&env, Error::AlreadyMigrated);
+        }
+
+        DataKeyVersion::SchemaVersion.set(&env, &SCHEMA_VERSION);
+
+        Event::Migrated.publish(&env, (from_version, SCHEMA_VERSION));
+    }
+
     pub fn owner_of(env: Env, token_id: u32) -> Address {
         if let Some(addr) = DataKey::TokenOwner(token_id).get::<Address>(&env) {
             addr
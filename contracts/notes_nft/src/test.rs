@@ -63,3 +63,4 @@ impl<'a> NotesNFTTest<'a> {
 pub mod initialize;
 pub mod mint;
 pub mod transfer;
+pub mod upgrade;
@@ -0,0 +1,84 @@
+#![cfg(test)]
+
+use crate::{
+    events::Event,
+    test::{
+        notes_nft::{self, Error},
+        NotesNFTTest,
+    },
+};
+use soroban_sdk::{
+    testutils::{Events, MockAuth, MockAuthInvoke},
+    IntoVal, String,
+};
+
+#[test]
+fn upgrade() {
+    let test = NotesNFTTest::setup();
+
+    // Mint a note with the deployed code
+    let doc_hash = String::from_bytes(&test.env, &[0; 32]);
+    test.notes_nft.mock_all_auths().mint(&test.alice, &doc_hash);
+
+    let new_wasm_hash = test.env.deployer().upload_contract_wasm(notes_nft::WASM);
+
+    test.notes_nft
+        .mock_auths(&[MockAuth {
+            address: &test.admin_escrow,
+            invoke: &MockAuthInvoke {
+                contract: &test.notes_nft.address,
+                fn_name: "upgrade",
+                args: (new_wasm_hash.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .upgrade(&new_wasm_hash);
+
+    let event_expected = (
+        test.notes_nft.address.clone(),
+        (Event::Upgraded.name(),).into_val(&test.env),
+        new_wasm_hash.into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "Upgraded event not present"
+    );
+
+    // The upgraded code reads the stored note
+    assert_eq!(test.notes_nft.version(), 1);
+    assert_eq!(test.notes_nft.owner_of(&0), test.alice);
+    assert_eq!(test.notes_nft.token_uri(&0), doc_hash);
+}
+
+#[test]
+fn upgrade_only_admin() {
+    let test = NotesNFTTest::setup();
+
+    let new_wasm_hash = test.env.deployer().upload_contract_wasm(notes_nft::WASM);
+
+    let res = test
+        .notes_nft
+        .mock_auths(&[MockAuth {
+            address: &test.alice,
+            invoke: &MockAuthInvoke {
+                contract: &test.notes_nft.address,
+                fn_name: "upgrade",
+                args: (new_wasm_hash.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_upgrade(&new_wasm_hash);
+
+    assert!(res.is_err(), "calling with non-admin not reverted");
+}
+
+#[test]
+fn migrate() {
+    let test = NotesNFTTest::setup();
+
+    // Up to date since the initialization
+    let res = test.notes_nft.mock_all_auths().try_migrate();
+
+    assert_eq!(res, Err(Ok(Error::AlreadyMigrated.into())));
+}
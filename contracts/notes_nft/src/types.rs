@@ -26,6 +26,33 @@ impl storage::Storage for Admin {
         storage::Persistent::remove(env, self)
     }
 }
+#[contracttype]
+pub enum DataKeyVersion {
+    SchemaVersion, // instance
+}
+impl storage::Storage for DataKeyVersion {
+    fn get<V: TryFromVal<Env, Val>>(&self, env: &Env) -> Option<V> {
+        storage::Instance::get(env, self)
+    }
+
+    fn set<V: IntoVal<Env, Val>>(&self, env: &Env, val: &V) {
+        storage::Instance::set(env, self, val)
+    }
+
+    fn has(&self, env: &Env) -> bool {
+        storage::Instance::has(env, self)
+    }
+
+    fn extend(&self, env: &Env, min_ledger_to_live: u32) -> &Self {
+        storage::Instance::extend(env, min_ledger_to_live);
+        self
+    }
+
+    fn remove(&self, env: &Env) {
+        storage::Instance::remove(env, self)
+    }
+}
+
 #[contracttype]
 pub enum DataKey {
     Balance(Address),           // instance
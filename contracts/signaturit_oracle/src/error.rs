@@ -10,4 +10,9 @@ pub enum OracleError {
     SignatureIdAlredyExist = 3,
     MissingDocHash = 4,
     ProcessNotFound = 5,
+    OnlyConsumer = 6,
+    ProcessResolved = 7,
+    CancelAlreadyRequested = 8,
+    AlreadyMigrated = 9,
+    MissingFailureReason = 10,
 }
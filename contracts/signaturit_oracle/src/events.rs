@@ -1,9 +1,13 @@
-use soroban_sdk::{Address, Env, IntoVal, String, Val, Vec};
+use soroban_sdk::{Address, BytesN, Env, IntoVal, String, Val, Vec};
 
 pub enum OracleEvent {
     Initialized(Address),
     NewSignatureProcess(String, u32),
     SignatureResponse(String, u32, bool),
+    CancelRequest(String, u32),
+    CancelResponse(String, u32, bool),
+    Upgraded(BytesN<32>),
+    Migrated(u32, u32),
 }
 
 impl OracleEvent {
@@ -12,6 +16,10 @@ impl OracleEvent {
             OracleEvent::Initialized(..) => stringify!(Initialized),
             OracleEvent::NewSignatureProcess(..) => stringify!(NewSignatureProcess),
             OracleEvent::SignatureResponse(..) => stringify!(SignatureResponse),
+            OracleEvent::CancelRequest(..) => stringify!(CancelRequest),
+            OracleEvent::CancelResponse(..) => stringify!(CancelResponse),
+            OracleEvent::Upgraded(..) => stringify!(Upgraded),
+            OracleEvent::Migrated(..) => stringify!(Migrated),
         }
     }
     pub fn publish(&self, env: &Env) {
@@ -31,6 +39,26 @@ impl OracleEvent {
                 v.push_back(oracle_id.into_val(env));
                 v.push_back(is_success.into_val(env));
             }
+
+            OracleEvent::CancelRequest(signaturit_id, oracle_id) => {
+                v.push_back(signaturit_id.into_val(env));
+                v.push_back(oracle_id.into_val(env));
+            }
+
+            OracleEvent::CancelResponse(signaturit_id, oracle_id, is_canceled) => {
+                v.push_back(signaturit_id.into_val(env));
+                v.push_back(oracle_id.into_val(env));
+                v.push_back(is_canceled.into_val(env));
+            }
+
+            OracleEvent::Upgraded(new_wasm_hash) => {
+                v.push_back(new_wasm_hash.into_val(env));
+            }
+
+            OracleEvent::Migrated(from_version, to_version) => {
+                v.push_back(from_version.into_val(env));
+                v.push_back(to_version.into_val(env));
+            }
         }
 
         env.events().publish((self.name(),), v)
@@ -5,13 +5,15 @@ mod types;
 
 use error::OracleError;
 use events::OracleEvent;
-use oracle_traits::OracleConsumerClient;
+use oracle_traits::{FailureReason, OracleConsumerClient};
 use soroban_sdk::{
     auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
-    contract, contractimpl, panic_with_error, vec, Address, Env, IntoVal, String, Symbol,
+    contract, contractimpl, panic_with_error, vec, Address, BytesN, Env, IntoVal, String, Symbol,
 };
 use storage::Storage;
-use types::{DataKey, SignatureResponse, SignaturitProcess};
+use types::{CancelProcess, DataKey, SignatureResponse, SignaturitProcess};
+
+const SCHEMA_VERSION: u32 = 1;
 
 fn check_initialization(env: &Env) {
     if !DataKey::Admin.has(env) {
@@ -23,6 +25,10 @@ fn get_admin(env: &Env) -> Address {
     DataKey::Admin.get(env).unwrap()
 }
 
+fn get_schema_version(env: &Env) -> u32 {
+    storage::schema_version(env, &DataKey::SchemaVersion)
+}
+
 fn get_process_by_id(env: &Env, oracle_id: &u32) -> SignaturitProcess {
     if !DataKey::OracleProcess(*oracle_id).has(&env) {
         panic_with_error!(&env, OracleError::ProcessNotFound);
@@ -43,6 +49,54 @@ fn get_process_by_signature_id(env: &Env, signature_id: &String) -> SignaturitPr
         .unwrap()
 }
 
+fn get_cancel_by_id(env: &Env, oracle_id: &u32) -> CancelProcess {
+    if !DataKey::CancelProcess(*oracle_id).has(env) {
+        panic_with_error!(env, OracleError::ProcessNotFound);
+    }
+
+    DataKey::CancelProcess(*oracle_id).get(env).unwrap()
+}
+
+fn send_failed_signature(env: &Env, signature_process: &SignaturitProcess, reason: FailureReason) {
+    // The contract should implement the Trait
+    let consumer_client = OracleConsumerClient::new(env, &signature_process.send_to);
+
+    env.authorize_as_current_contract(vec![
+        env,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: signature_process.send_to.clone(),
+                fn_name: Symbol::new(env, "failed_signature"),
+                args: (signature_process.id.clone(), reason).into_val(env),
+            },
+            sub_invocations: vec![env],
+        }),
+    ]);
+
+    // Call the implementer with failed
+    consumer_client.failed_signature(&signature_process.id, &reason);
+}
+
+fn send_rejected_cancel(env: &Env, signature_process: &SignaturitProcess) {
+    // The contract should implement the Trait
+    let consumer_client = OracleConsumerClient::new(env, &signature_process.send_to);
+
+    env.authorize_as_current_contract(vec![
+        env,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: signature_process.send_to.clone(),
+                fn_name: Symbol::new(env, "rejected_cancel"),
+                args: (signature_process.id.clone(),).into_val(env),
+            },
+            sub_invocations: vec![env],
+        }),
+    ]);
+
+    // Call the implementer with the rejected cancel
+    consumer_client.rejected_cancel(&signature_process.id);
+}
+
 #[contract]
 pub struct SignaturitOracle;
 
@@ -61,6 +115,10 @@ impl SignaturitOracle {
         get_process_by_signature_id(&env, &signature_id)
     }
 
+    pub fn get_cancel_by_id(env: Env, oracle_id: u32) -> CancelProcess {
+        get_cancel_by_id(&env, &oracle_id)
+    }
+
     /**
     Initialize the contract with the given arguments, making the oracle ready
     to be used.
@@ -75,10 +133,57 @@ impl SignaturitOracle {
         }
 
         DataKey::Admin.set(&env, &admin);
+        DataKey::SchemaVersion.set(&env, &SCHEMA_VERSION);
 
         OracleEvent::Initialized(admin).publish(&env);
     }
 
+    /**
+    Schema version of the stored records.
+    */
+    pub fn version(env: Env) -> u32 {
+        check_initialization(&env);
+        get_schema_version(&env)
+    }
+
+    /**
+    Replace the code of the oracle keeping its storage. If the new code
+    changes the layout of the stored records, `migrate` has to be called after
+    the upgrade.
+
+    ### Arguments
+    * `new_wasm_hash`: The hash of the new code, already installed on the network.
+    */
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        check_initialization(&env);
+        get_admin(&env).require_auth();
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        OracleEvent::Upgraded(new_wasm_hash).publish(&env);
+    }
+
+    /**
+    Bring the stored records to the schema version of the current code. The
+    layout has not changed since the first upgradeable version, so there is no
+    step to run yet.
+    */
+    pub fn migrate(env: Env) {
+        check_initialization(&env);
+        get_admin(&env).require_auth();
+
+        let from_version = get_schema_version(&env);
+
+        if from_version >= SCHEMA_VERSION {
+            panic_with_error!(&env, OracleError::AlreadyMigrated);
+        }
+
+        DataKey::SchemaVersion.set(&env, &SCHEMA_VERSION);
+
+        OracleEvent::Migrated(from_version, SCHEMA_VERSION).publish(&env);
+    }
+
     /**
     Register the signaturit ID (UUID) on the oracle to be observed and make
     callbacks based on their status.
@@ -104,6 +209,7 @@ impl SignaturitOracle {
             oracle_id: oracle_id.clone(),
             send_to: caller,
             status: SignatureResponse::Wait,
+            cancel_id: None,
         };
 
         OracleEvent::NewSignatureProcess(signaturit_id.clone(), oracle_id).publish(&env);
@@ -120,12 +226,17 @@ impl SignaturitOracle {
         oracle_id: u32,
         is_success: bool,
         document_hash: Option<String>,
+        failure_reason: Option<FailureReason>,
     ) {
         check_initialization(&env);
         get_admin(&env).require_auth();
 
         let mut signature_process = get_process_by_id(&env, &oracle_id);
 
+        if signature_process.status != SignatureResponse::Wait {
+            panic_with_error!(env, OracleError::ProcessResolved);
+        }
+
         // The contract should implement the Trait
         let consumer_client = OracleConsumerClient::new(&env, &signature_process.send_to);
 
@@ -159,21 +270,12 @@ impl SignaturitOracle {
             signature_process.status = SignatureResponse::Completed;
         } else {
             // The signature process has failed (the staus is expired, canceled or declined)
+            let reason = match failure_reason {
+                Some(reason) => reason,
+                None => panic_with_error!(env, OracleError::MissingFailureReason),
+            };
 
-            env.authorize_as_current_contract(vec![
-                &env,
-                InvokerContractAuthEntry::Contract(SubContractInvocation {
-                    context: ContractContext {
-                        contract: signature_process.send_to.clone(),
-                        fn_name: Symbol::new(&env, "failed_signature"),
-                        args: (signature_process.id.clone(),).into_val(&env),
-                    },
-                    sub_invocations: vec![&env],
-                }),
-            ]);
-
-            // Call the implementer with failed
-            consumer_client.failed_signature(&signature_process.id);
+            send_failed_signature(&env, &signature_process, reason);
 
             // Update status
             signature_process.status = SignatureResponse::Failed;
@@ -184,6 +286,112 @@ impl SignaturitOracle {
 
         OracleEvent::SignatureResponse(signature_process.id, oracle_id, is_success).publish(&env);
     }
+
+    /**
+    Ask the oracle to cancel a signature process that is still waiting for a
+    response. The request is stored so the listener can cancel the process on
+    Signaturit, and the answer will come through `cancel_response`.
+
+    ### Arguments
+    * `caller`: the address that registered the signature process.
+    * `signaturit_id`: the UUID of the signature process to cancel.
+
+    Returns the `oracle_id` assigned to the cancel request.
+    */
+    pub fn request_cancel(env: Env, caller: Address, signaturit_id: String) -> u32 {
+        check_initialization(&env);
+        caller.require_auth();
+
+        let mut signature_process = get_process_by_signature_id(&env, &signaturit_id);
+
+        if signature_process.send_to != caller {
+            panic_with_error!(env, OracleError::OnlyConsumer);
+        }
+
+        if signature_process.status != SignatureResponse::Wait {
+            panic_with_error!(env, OracleError::ProcessResolved);
+        }
+
+        if signature_process.cancel_id.is_some() {
+            panic_with_error!(env, OracleError::CancelAlreadyRequested);
+        }
+
+        // Cancel requests share the counter with the signature processes
+        let oracle_id = DataKey::RegisterCounter.get(&env).unwrap_or(0);
+
+        let cancel_process = CancelProcess {
+            oracle_id,
+            signaturit_id: signaturit_id.clone(),
+            send_to: caller,
+            status: SignatureResponse::Wait,
+        };
+
+        signature_process.cancel_id = Some(oracle_id);
+
+        OracleEvent::CancelRequest(signaturit_id.clone(), oracle_id).publish(&env);
+
+        DataKey::CancelProcess(oracle_id).set(&env, &cancel_process);
+        DataKey::SignaturitProcess(signaturit_id).set(&env, &signature_process);
+        DataKey::RegisterCounter.set(&env, &(oracle_id + 1));
+
+        oracle_id
+    }
+
+    /**
+    Answer a cancel request. If the signature process was canceled on
+    Signaturit, the consumer receives a `failed_signature` callback. Otherwise,
+    if the signature process is still waiting for a response, the consumer
+    receives a `rejected_cancel` callback and can request the cancel again.
+
+    ### Arguments
+    * `oracle_id`: the identifier of the cancel request.
+    * `is_canceled`: whether the signature process was canceled or not.
+    */
+    pub fn cancel_response(env: Env, oracle_id: u32, is_canceled: bool) {
+        check_initialization(&env);
+        get_admin(&env).require_auth();
+
+        let mut cancel_process = get_cancel_by_id(&env, &oracle_id);
+
+        if cancel_process.status != SignatureResponse::Wait {
+            panic_with_error!(env, OracleError::ProcessResolved);
+        }
+
+        if is_canceled {
+            let mut signature_process =
+                get_process_by_signature_id(&env, &cancel_process.signaturit_id);
+
+            if signature_process.status != SignatureResponse::Wait {
+                panic_with_error!(env, OracleError::ProcessResolved);
+            }
+
+            send_failed_signature(&env, &signature_process, FailureReason::Canceled);
+
+            signature_process.status = SignatureResponse::Failed;
+            DataKey::SignaturitProcess(signature_process.id.clone()).set(&env, &signature_process);
+
+            cancel_process.status = SignatureResponse::Completed;
+        } else {
+            let mut signature_process =
+                get_process_by_signature_id(&env, &cancel_process.signaturit_id);
+
+            // Allow a new cancel request while the signature is not answered
+            if signature_process.status == SignatureResponse::Wait {
+                signature_process.cancel_id = None;
+                DataKey::SignaturitProcess(signature_process.id.clone())
+                    .set(&env, &signature_process);
+
+                send_rejected_cancel(&env, &signature_process);
+            }
+
+            cancel_process.status = SignatureResponse::Failed;
+        }
+
+        DataKey::CancelProcess(oracle_id).set(&env, &cancel_process);
+
+        OracleEvent::CancelResponse(cancel_process.signaturit_id, oracle_id, is_canceled)
+            .publish(&env);
+    }
 }
 
 mod test;
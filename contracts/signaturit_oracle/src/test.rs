@@ -32,6 +32,7 @@ fn create_escrow_contract<'a>(env: &Env) -> EscrowClient<'a> {
 // These topics are from EscrowTest
 pub const COMPLETED_TOPIC: Symbol = symbol_short!("COMP_TEST");
 pub const FAILED_TOPIC: Symbol = symbol_short!("FAIL_TEST");
+pub const REJECTED_TOPIC: Symbol = symbol_short!("REJ_TEST");
 
 pub struct OracleTest<'a> {
     env: Env,
@@ -82,6 +83,8 @@ impl<'a> OracleTest<'a> {
     }
 }
 
+mod cancel;
 mod initialize;
 mod register;
 mod signature_response;
+mod upgrade;
@@ -0,0 +1,336 @@
+#![cfg(test)]
+
+extern crate std;
+use soroban_sdk::{
+    testutils::{Address as _, Events, MockAuth, MockAuthInvoke},
+    Address, IntoVal, String,
+};
+use std::string::ToString;
+use uuid::Uuid;
+
+use crate::{
+    events::OracleEvent,
+    test::{
+        oracle::{FailureReason, OracleError, SignatureResponse},
+        OracleTest, FAILED_TOPIC, REJECTED_TOPIC,
+    },
+};
+
+#[test]
+fn request_cancel() {
+    let test = OracleTest::setup();
+
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+
+    // Register the process to the Oracle
+    let oracle_id = test.escrow.oracle_register(&signaturit_id);
+
+    // Ask for the cancelation of the process
+    let cancel_id = test.escrow.oracle_request_cancel(&signaturit_id);
+
+    assert_ne!(
+        oracle_id, cancel_id,
+        "cancel request should have its own id"
+    );
+
+    // Check CancelRequest event
+    let event_expected = (
+        test.oracle.address.clone(),
+        (OracleEvent::CancelRequest(signaturit_id.clone(), cancel_id).name(),).into_val(&test.env),
+        (signaturit_id.clone(), cancel_id).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "CancelRequest event not present"
+    );
+
+    let cancel_process = test.oracle.get_cancel_by_id(&cancel_id);
+    assert_eq!(cancel_process.signaturit_id, signaturit_id);
+    assert_eq!(cancel_process.send_to, test.escrow.address);
+    assert_eq!(cancel_process.status, SignatureResponse::Wait);
+
+    let signature_process = test.oracle.get_process_by_signature_id(&signaturit_id);
+    assert_eq!(signature_process.cancel_id, Some(cancel_id));
+    assert_eq!(signature_process.status, SignatureResponse::Wait);
+
+    // The cancel request is not a signature process
+    let res = test.oracle.try_get_process_by_id(&cancel_id);
+    assert_eq!(res, Err(Ok(OracleError::ProcessNotFound.into())));
+}
+
+#[test]
+fn request_cancel_twice() {
+    let test = OracleTest::setup();
+
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+
+    test.escrow.oracle_register(&signaturit_id);
+    test.escrow.oracle_request_cancel(&signaturit_id);
+
+    let res = test.escrow.try_oracle_request_cancel(&signaturit_id);
+
+    assert!(res.is_err(), "second cancel request not reverted");
+}
+
+#[test]
+fn request_cancel_only_consumer() {
+    let test = OracleTest::setup();
+
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+
+    test.escrow.oracle_register(&signaturit_id);
+
+    // Alice did not register the process
+    let res = test
+        .oracle
+        .mock_auths(&[MockAuth {
+            address: &test.alice,
+            invoke: &MockAuthInvoke {
+                contract: &test.oracle.address,
+                fn_name: "request_cancel",
+                args: (test.alice.clone(), signaturit_id.clone()).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_request_cancel(&test.alice, &signaturit_id);
+
+    assert_eq!(res, Err(Ok(OracleError::OnlyConsumer.into())));
+}
+
+#[test]
+fn request_cancel_inexisting_process() {
+    let test = OracleTest::setup();
+
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+    let caller = Address::generate(&test.env);
+
+    let res = test
+        .oracle
+        .mock_all_auths()
+        .try_request_cancel(&caller, &signaturit_id);
+
+    assert_eq!(res, Err(Ok(OracleError::ProcessNotFound.into())));
+}
+
+#[test]
+fn cancel_response_canceled() {
+    let test = OracleTest::setup();
+
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+
+    let oracle_id = test.escrow.oracle_register(&signaturit_id);
+    let cancel_id = test.escrow.oracle_request_cancel(&signaturit_id);
+
+    let is_canceled = true;
+
+    test.oracle
+        .mock_auths(&[MockAuth {
+            address: &test.admin,
+            invoke: &MockAuthInvoke {
+                contract: &test.oracle.address,
+                fn_name: "cancel_response",
+                args: (cancel_id, is_canceled).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .cancel_response(&cancel_id, &is_canceled);
+
+    // Check CancelResponse event
+    let event_expected = (
+        test.oracle.address.clone(),
+        (OracleEvent::CancelResponse(signaturit_id.clone(), cancel_id, is_canceled).name(),)
+            .into_val(&test.env),
+        (signaturit_id.clone(), cancel_id, is_canceled).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "CancelResponse event not present"
+    );
+
+    // The implementer receive the failed callback
+    let event_expected = (
+        test.escrow.address.clone(),
+        (FAILED_TOPIC,).into_val(&test.env),
+        (signaturit_id.clone(), FailureReason::Canceled).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "Implementer event not present"
+    );
+
+    assert_eq!(
+        test.oracle.get_cancel_by_id(&cancel_id).status,
+        SignatureResponse::Completed
+    );
+    assert_eq!(
+        test.oracle.get_process_by_id(&oracle_id).status,
+        SignatureResponse::Failed
+    );
+
+    // The signature process can not receive a response anymore
+    let signature_response = true;
+    let document_hash = Some(String::from_str(&test.env, "Test document hash"));
+
+    let res = test
+        .oracle
+        .mock_auths(&[MockAuth {
+            address: &test.admin,
+            invoke: &MockAuthInvoke {
+                contract: &test.oracle.address,
+                fn_name: "signature_response",
+                args: (
+                    oracle_id,
+                    signature_response,
+                    document_hash.clone(),
+                    None::<FailureReason>,
+                )
+                    .into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_signature_response(&oracle_id, &signature_response, &document_hash, &None);
+
+    assert_eq!(res, Err(Ok(OracleError::ProcessResolved.into())));
+}
+
+#[test]
+fn cancel_response_not_canceled() {
+    let test = OracleTest::setup();
+
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+
+    let oracle_id = test.escrow.oracle_register(&signaturit_id);
+    let cancel_id = test.escrow.oracle_request_cancel(&signaturit_id);
+
+    let is_canceled = false;
+
+    test.oracle
+        .mock_auths(&[MockAuth {
+            address: &test.admin,
+            invoke: &MockAuthInvoke {
+                contract: &test.oracle.address,
+                fn_name: "cancel_response",
+                args: (cancel_id, is_canceled).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .cancel_response(&cancel_id, &is_canceled);
+
+    // The implementer does not receive the failed callback
+    let event_not_expected = (
+        test.escrow.address.clone(),
+        (FAILED_TOPIC,).into_val(&test.env),
+        (signaturit_id.clone(), FailureReason::Canceled).into_val(&test.env),
+    );
+
+    assert!(
+        !test.env.events().all().contains(event_not_expected),
+        "Implementer event present"
+    );
+
+    // The implementer is told that the cancel was rejected
+    let event_expected = (
+        test.escrow.address.clone(),
+        (REJECTED_TOPIC,).into_val(&test.env),
+        (signaturit_id.clone(),).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "Implementer event not present"
+    );
+
+    assert_eq!(
+        test.oracle.get_cancel_by_id(&cancel_id).status,
+        SignatureResponse::Failed
+    );
+    let signature_process = test.oracle.get_process_by_id(&oracle_id);
+    assert_eq!(signature_process.status, SignatureResponse::Wait);
+    assert_eq!(signature_process.cancel_id, None);
+
+    // The cancel request was already answered
+    let res = test
+        .oracle
+        .mock_auths(&[MockAuth {
+            address: &test.admin,
+            invoke: &MockAuthInvoke {
+                contract: &test.oracle.address,
+                fn_name: "cancel_response",
+                args: (cancel_id, is_canceled).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_cancel_response(&cancel_id, &is_canceled);
+
+    assert_eq!(res, Err(Ok(OracleError::ProcessResolved.into())));
+
+    // The cancel can be requested again
+    let new_cancel_id = test.escrow.oracle_request_cancel(&signaturit_id);
+
+    assert_ne!(new_cancel_id, cancel_id);
+    assert_eq!(
+        test.oracle.get_process_by_id(&oracle_id).cancel_id,
+        Some(new_cancel_id)
+    );
+    assert_eq!(
+        test.oracle.get_cancel_by_id(&new_cancel_id).status,
+        SignatureResponse::Wait
+    );
+}
+
+#[test]
+fn cancel_response_only_admin() {
+    let test = OracleTest::setup();
+
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+
+    test.escrow.oracle_register(&signaturit_id);
+    let cancel_id = test.escrow.oracle_request_cancel(&signaturit_id);
+
+    let is_canceled = true;
+
+    let res = test
+        .oracle
+        .mock_auths(&[MockAuth {
+            address: &test.bob,
+            invoke: &MockAuthInvoke {
+                contract: &test.oracle.address,
+                fn_name: "cancel_response",
+                args: (cancel_id, is_canceled).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_cancel_response(&cancel_id, &is_canceled);
+
+    assert!(res.is_err(), "calling with non-admin not reverted");
+}
+
+#[test]
+fn cancel_response_inexisting_id() {
+    let test = OracleTest::setup();
+
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+
+    // The oracle id of a signature process is not a cancel request
+    let oracle_id = test.escrow.oracle_register(&signaturit_id);
+
+    let is_canceled = true;
+
+    let res = test
+        .oracle
+        .mock_auths(&[MockAuth {
+            address: &test.admin,
+            invoke: &MockAuthInvoke {
+                contract: &test.oracle.address,
+                fn_name: "cancel_response",
+                args: (oracle_id, is_canceled).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_cancel_response(&oracle_id, &is_canceled);
+
+    assert_eq!(res, Err(Ok(OracleError::ProcessNotFound.into())));
+}
@@ -10,7 +10,10 @@ use uuid::Uuid;
 
 use crate::{
     events::OracleEvent,
-    test::{oracle::OracleError, OracleTest, COMPLETED_TOPIC, FAILED_TOPIC},
+    test::{
+        oracle::{FailureReason, OracleError},
+        OracleTest, COMPLETED_TOPIC, FAILED_TOPIC,
+    },
 };
 
 #[test]
@@ -32,11 +35,22 @@ fn signature_response_call_fail() {
             invoke: &MockAuthInvoke {
                 contract: &test.oracle.address,
                 fn_name: "signature_response",
-                args: (oracle_id, signature_response, document_hash.clone()).into_val(&test.env),
+                args: (
+                    oracle_id,
+                    signature_response,
+                    document_hash.clone(),
+                    Some(FailureReason::Expired),
+                )
+                    .into_val(&test.env),
                 sub_invokes: &[],
             },
         }])
-        .signature_response(&oracle_id, &signature_response, &document_hash);
+        .signature_response(
+            &oracle_id,
+            &signature_response,
+            &document_hash,
+            &Some(FailureReason::Expired),
+        );
 
     // Check SignatureResponse event
     let event_expected = (
@@ -58,7 +72,7 @@ fn signature_response_call_fail() {
     let event_expected = (
         test.escrow.address,
         (FAILED_TOPIC,).into_val(&test.env),
-        (signaturit_id).into_val(&test.env),
+        (signaturit_id, FailureReason::Expired).into_val(&test.env),
     );
 
     assert!(
@@ -86,12 +100,17 @@ fn signature_response_call_success() {
             invoke: &MockAuthInvoke {
                 contract: &test.oracle.address,
                 fn_name: "signature_response",
-                args: (oracle_id, signature_response, Some(document_hash.clone()))
+                args: (
+                    oracle_id,
+                    signature_response,
+                    Some(document_hash.clone()),
+                    None::<FailureReason>,
+                )
                     .into_val(&test.env),
                 sub_invokes: &[],
             },
         }])
-        .signature_response(&oracle_id, &signature_response, &document_hash);
+        .signature_response(&oracle_id, &signature_response, &document_hash, &None);
 
     // Check SignatureResponse event
     let event_expected = (
@@ -142,12 +161,17 @@ fn signature_response_only_admin() {
             invoke: &MockAuthInvoke {
                 contract: &test.oracle.address,
                 fn_name: "signature_response",
-                args: (oracle_id, signature_response, Some(document_hash.clone()))
+                args: (
+                    oracle_id,
+                    signature_response,
+                    Some(document_hash.clone()),
+                    None::<FailureReason>,
+                )
                     .into_val(&test.env),
                 sub_invokes: &[],
             },
         }])
-        .try_signature_response(&oracle_id, &signature_response, &document_hash);
+        .try_signature_response(&oracle_id, &signature_response, &document_hash, &None);
 
     assert!(res.is_err(), "callin with non-admin not reverted");
 }
@@ -170,11 +194,22 @@ fn signature_response_inexisting_id() {
             invoke: &MockAuthInvoke {
                 contract: &test.oracle.address,
                 fn_name: "signature_response",
-                args: (oracle_id, signature_response, document_hash.clone()).into_val(&test.env),
+                args: (
+                    oracle_id,
+                    signature_response,
+                    document_hash.clone(),
+                    Some(FailureReason::Expired),
+                )
+                    .into_val(&test.env),
                 sub_invokes: &[],
             },
         }])
-        .try_signature_response(&oracle_id, &signature_response, &document_hash);
+        .try_signature_response(
+            &oracle_id,
+            &signature_response,
+            &document_hash,
+            &Some(FailureReason::Expired),
+        );
 
     assert_eq!(res, Err(Ok(OracleError::ProcessNotFound.into())));
 }
@@ -199,12 +234,58 @@ fn signature_response_missing_doc_hash() {
             invoke: &MockAuthInvoke {
                 contract: &test.oracle.address,
                 fn_name: "signature_response",
-                args: (oracle_id, signature_response, Some(document_hash.clone()))
+                args: (
+                    oracle_id,
+                    signature_response,
+                    Some(document_hash.clone()),
+                    None::<FailureReason>,
+                )
                     .into_val(&test.env),
                 sub_invokes: &[],
             },
         }])
-        .try_signature_response(&oracle_id, &signature_response, &document_hash);
+        .try_signature_response(&oracle_id, &signature_response, &document_hash, &None);
 
     assert_eq!(res, Err(Ok(OracleError::MissingDocHash.into())));
 }
+
+#[test]
+fn signature_response_missing_failure_reason() {
+    let test = OracleTest::setup();
+
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+
+    // Register the process to the Oracle
+    let oracle_id = test.escrow.oracle_register(&signaturit_id);
+
+    let signature_response = false; // FAILED
+    let document_hash: Option<String> = None;
+    let failure_reason: Option<FailureReason> = None;
+
+    //  The Oracle should be trigger with the response
+    let res = test
+        .oracle
+        .mock_auths(&[MockAuth {
+            address: &test.admin,
+            invoke: &MockAuthInvoke {
+                contract: &test.oracle.address,
+                fn_name: "signature_response",
+                args: (
+                    oracle_id,
+                    signature_response,
+                    document_hash.clone(),
+                    failure_reason,
+                )
+                    .into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_signature_response(
+            &oracle_id,
+            &signature_response,
+            &document_hash,
+            &failure_reason,
+        );
+
+    assert_eq!(res, Err(Ok(OracleError::MissingFailureReason.into())));
+}
@@ -0,0 +1,93 @@
+#![cfg(test)]
+
+extern crate std;
+use soroban_sdk::{
+    testutils::{Events, MockAuth, MockAuthInvoke},
+    IntoVal, String,
+};
+use std::string::ToString;
+use uuid::Uuid;
+
+use crate::{
+    events::OracleEvent,
+    test::{
+        oracle::{self, OracleError},
+        OracleTest,
+    },
+};
+
+#[test]
+fn upgrade() {
+    let test = OracleTest::setup();
+
+    // Store a process with the deployed code
+    let signaturit_id = String::from_str(&test.env, &Uuid::new_v4().to_string());
+    test.oracle
+        .mock_all_auths()
+        .register_new_signature_process(&test.alice, &signaturit_id);
+
+    let new_wasm_hash = test.env.deployer().upload_contract_wasm(oracle::WASM);
+
+    test.oracle
+        .mock_auths(&[MockAuth {
+            address: &test.admin,
+            invoke: &MockAuthInvoke {
+                contract: &test.oracle.address,
+                fn_name: "upgrade",
+                args: (new_wasm_hash.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .upgrade(&new_wasm_hash);
+
+    let event_expected = (
+        test.oracle.address.clone(),
+        (OracleEvent::Upgraded(new_wasm_hash.clone()).name(),).into_val(&test.env),
+        (new_wasm_hash,).into_val(&test.env),
+    );
+
+    assert!(
+        test.env.events().all().contains(event_expected),
+        "Upgraded event not present"
+    );
+
+    // The upgraded code reads the stored process
+    assert_eq!(test.oracle.version(), 1);
+    assert_eq!(test.oracle.get_admin(), test.admin);
+
+    let process = test.oracle.get_process_by_signature_id(&signaturit_id);
+
+    assert_eq!(process.send_to, test.alice);
+    assert_eq!(process.cancel_id, None);
+}
+
+#[test]
+fn upgrade_only_admin() {
+    let test = OracleTest::setup();
+
+    let new_wasm_hash = test.env.deployer().upload_contract_wasm(oracle::WASM);
+
+    let res = test
+        .oracle
+        .mock_auths(&[MockAuth {
+            address: &test.alice,
+            invoke: &MockAuthInvoke {
+                contract: &test.oracle.address,
+                fn_name: "upgrade",
+                args: (new_wasm_hash.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_upgrade(&new_wasm_hash);
+
+    assert!(res.is_err(), "calling with non-admin not reverted");
+}
+
+#[test]
+fn migrate_already_migrated() {
+    let test = OracleTest::setup();
+
+    let res = test.oracle.mock_all_auths().try_migrate();
+
+    assert_eq!(res, Err(Ok(OracleError::AlreadyMigrated.into())));
+}
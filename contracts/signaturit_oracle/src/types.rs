@@ -3,9 +3,11 @@ use soroban_sdk::{contracttype, Address, Env, IntoVal, String, TryFromVal, Val};
 #[contracttype]
 pub enum DataKey {
     Admin,
+    SchemaVersion,
     RegisterCounter,
     SignaturitProcess(String),
     OracleProcess(u32),
+    CancelProcess(u32),
 }
 
 impl storage::Storage for DataKey {
@@ -54,6 +56,35 @@ pub struct SignaturitProcess {
     pub send_to: Address,
 
     pub status: SignatureResponse,
+
+    /**
+    Oracle identifier of the cancel request made for this process, if any
+     */
+    pub cancel_id: Option<u32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CancelProcess {
+    /**
+     * Oracle indentifier for this cancel request
+     */
+    pub oracle_id: u32,
+    /**
+     * The signature ID (uuid) from Signaturit that was asked to be canceled
+     */
+    pub signaturit_id: String,
+    /**
+    The address that asked for the cancelation and where the callback
+    response will be sent to.
+     */
+    pub send_to: Address,
+
+    /**
+    `Wait` until the listener answer, `Completed` if the signature process was
+    canceled and `Failed` if it could not be canceled.
+     */
+    pub status: SignatureResponse,
 }
 
 #[contracttype]
@@ -1,5 +1,34 @@
 #![no_std]
-use soroban_sdk::{contractclient, Env, String};
+use soroban_sdk::{contractclient, contracttype, Env, String};
+
+/**
+Why a signature process failed, as reported by the oracle
+*/
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum FailureReason {
+    /**
+    The document was not signed before its expiration
+    */
+    Expired = 0,
+    /**
+    The signature process was canceled (see `request_cancel` on the oracle)
+    */
+    Canceled = 1,
+    /**
+    The party that receives the funds declined to sign
+    */
+    ReceiverDeclined = 2,
+    /**
+    The party that gives the funds declined to sign
+    */
+    SenderDeclined = 3,
+    /**
+    Any other failure of the signature process
+    */
+    Other = 4,
+}
 
 #[contractclient(name = "OracleConsumerClient")]
 pub trait OracleConsumer {
@@ -16,23 +45,28 @@ pub trait OracleConsumer {
 
     /**
     Return a failed response for a given signature process. This means that the
-    signature process was failed (parties declined, process was timeout, etc)
-    */
-    fn failed_signature(env: Env, signaturit_id: String);
-
-    // TODO: Request for cancel signature process
-    // It should ask to the oracle to cancel a specific signaturit ID.
-    // The oracle store this request, emit an event and return an oracle_id for
-    // this request. (finish the transacion here)
-    //
-    // Later, the oracle will wait for the listener (the cronjob) to check/cancel
-    // the signature process using the signaturit API. Then the listener will send
-    // a transaction to cancel the process (calling failed signature).
-    //
-    // There are two types of `oracle_id`. One for the SignaturitProcess and one
-    // for CancelProcess. If the oracle_id does not exist on `SignaturitProcess`
-    // then it is a `CancelProcess`.
-    //
-    // Also, update the function to receive as parameter an optional<DOC_HASH>
-    // which will be the URI for the token
+    signature process was failed (parties declined, process was timeout, etc).
+
+    It is also the callback sent when a cancel request (`request_cancel` on the
+    oracle) was accepted and the signature process was canceled on Signaturit.
+
+    # Arguments
+
+    * `signaturit_id` - The ID of the signature process that will be handled
+    * `reason` - Why the signature process failed
+    */
+    fn failed_signature(env: Env, signaturit_id: String, reason: FailureReason);
+
+    /**
+    Return that a cancel request (`request_cancel` on the oracle) was rejected.
+    The signature process is still waiting for a response, and the cancel can
+    be requested again.
+
+    # Arguments
+
+    * `signaturit_id` - The ID of the signature process that was not canceled
+    */
+    fn rejected_cancel(env: Env, signaturit_id: String);
+
+    // TODO: Receive an optional document hash which will be the URI for the token
 }
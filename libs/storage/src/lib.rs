@@ -71,3 +71,13 @@ impl Temporary {
         env.storage().temporary().remove(key);
     }
 }
+
+/**
+ * Layout version of the records that a contract stores, kept under its own
+ * `key`. A contract bumps its `SCHEMA_VERSION`, and adds the step to its
+ * `migrate`, when a stored type changes. The version is stored on the
+ * initialization, so every deployment that can be upgraded has one.
+ */
+pub fn schema_version<K: Storage>(env: &Env, key: &K) -> u32 {
+    key.get(env).unwrap()
+}